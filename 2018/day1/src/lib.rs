@@ -1,5 +1,6 @@
-use std::io::prelude::*;
-use std::fs::File;
+extern crate aoc_core;
+
+use aoc_core::{parse_number, ParseError, Solution};
 use std::collections::HashSet;
 
 pub struct Device {
@@ -7,48 +8,6 @@ pub struct Device {
 }
 
 impl Device {
-    pub fn from_file(path: &'static str) -> Device {
-        let text = Device::load_to_string(path); // Get original text from file
-        let frequencies = Device::parse_string(text); // Extract integers
-
-        Device { frequencies }
-    }
-
-    fn load_to_string(path: &'static str) -> String {
-        // Open file
-        // If file not found - panic
-        let mut f = match File::open(path) {
-            Ok(file) => file,
-            Err(err) => panic!(err)
-        };
-
-        // Read text to String
-        // If reading goes wrong - panic
-        let mut buffer = String::new();
-        match f.read_to_string(&mut buffer) {
-            Ok(_) => (),
-            Err(err) => panic!(err)
-        };
-
-        buffer
-    }
-
-    fn parse_string(text: String) -> Vec<i32> {
-        let mut frequencies = Vec::new();
-
-        for line in text.lines() {
-            let str_number = line.trim_end_matches(','); // Remove characters from the end of the line
-            let number: i32 = match str_number.parse() {
-                Ok(n) => n,
-                Err(_) => panic!("Could not convert string to i32. File contains invalid formatting or characters.")
-            };
-
-            frequencies.push(number);
-        }
-
-        frequencies
-    }
-
     pub fn get_resulting_frequency(&self) -> i32 {
         self.frequencies.iter().sum()
     }
@@ -71,4 +30,55 @@ impl Device {
 
         current_frequency
     }
-}
\ No newline at end of file
+}
+
+pub fn parse(text: &str) -> Result<Device, ParseError> {
+    let mut frequencies = Vec::new();
+
+    for line in text.lines() {
+        let str_number = line.trim_end_matches(','); // Remove characters from the end of the line
+        frequencies.push(parse_number(text, str_number)?);
+    }
+
+    Ok(Device { frequencies })
+}
+
+pub fn part1(device: &Device) -> i32 {
+    device.get_resulting_frequency()
+}
+
+pub fn part2(device: &Device) -> i32 {
+    device.get_reached_twice()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Device;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Device, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Device) -> i32 {
+        part1(input)
+    }
+
+    fn part2(input: &Device) -> i32 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_malformed_frequency() {
+        let input = "+1\n-2\n+x3\n";
+
+        assert_eq!(Err(ParseError::new(3, 1, "expected a number, found '+x3'")), parse(input).map(|_| ()));
+    }
+}
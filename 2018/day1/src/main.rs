@@ -1,9 +1,12 @@
-mod lib;
+extern crate aoc_core;
+extern crate year2018_day1;
 
-use lib::Device;
+use aoc_core::{load, parse_or_exit};
+use year2018_day1::parse;
 
 fn main() {
-    let device = Device::from_file("input");
+    let input = load(2018, 1);
+    let device = parse_or_exit(&input, parse);
 
     //println!("{:?}", device.frequencies);
     println!("Resulting frequency: {}", device.get_resulting_frequency());
@@ -1,8 +1,11 @@
+extern crate aoc_core;
 extern crate regex;
 
+use aoc_core::{parse_number, Answer, ParseError, Solution};
 use std::fmt;
 use regex::Regex;
 
+#[derive(Clone)]
 struct Position {
     x: i32,
     y: i32
@@ -14,7 +17,7 @@ impl Position {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Velocity {
     x: i32,
     y: i32
@@ -26,6 +29,7 @@ impl Velocity {
     }
 }
 
+#[derive(Clone)]
 struct Star {
     pos: Position,
     vel: Velocity
@@ -53,6 +57,7 @@ impl Star {
     }
 }
 
+#[derive(Clone)]
 pub struct Sky {
     stars: Vec<Star>
 }
@@ -154,7 +159,7 @@ impl fmt::Debug for Sky {
         for (i, cell) in sky.iter().enumerate() {
             sky_map.push(*cell);
 
-            if (i + 1) as usize % width == 0 {
+            if (i + 1) % width == 0 {
                 sky_map.push('\n');
             }
         }
@@ -163,19 +168,82 @@ impl fmt::Debug for Sky {
     }
 }
 
-pub fn parse(text: &str) -> Sky {
+///
+/// Parses "x, y" pair of the field, which is a part of the text.
+///
+fn parse_pair(text: &str, field: &str) -> Result<(i32, i32), ParseError> {
+    let (x, y) = field
+        .split_once(',')
+        .ok_or_else(|| ParseError::at(text, field, format!("expected two numbers separated by a comma, found '{}'", field)))?;
+
+    Ok((parse_number(text, x.trim())?, parse_number(text, y.trim())?))
+}
+
+pub fn parse(text: &str) -> Result<Sky, ParseError> {
     let mut stars = Vec::with_capacity(text.lines().count());
-    let re = Regex::new(r"(?m)position=<(?P<pos>.*?)> velocity=<(?P<vel>.*?)>").unwrap();
+    let re = Regex::new(r"^position=<(?P<pos>[^>]*)> velocity=<(?P<vel>[^>]*)>$").unwrap();
 
-    for caps in re.captures_iter(text) {
-        let p: Vec<i32> = caps["pos"].split(',').map(|x| x.trim().parse::<i32>().unwrap()).collect();
-        let v: Vec<i32> = caps["vel"].split(',').map(|x| x.trim().parse::<i32>().unwrap()).collect();
+    for line in text.lines() {
+        let caps = re.captures(line).ok_or_else(|| {
+            ParseError::at(text, line, format!("expected a star like position=< 9,  1> velocity=< 0,  2>, found '{}'", line))
+        })?;
+        let p = parse_pair(text, caps.name("pos").unwrap().as_str())?;
+        let v = parse_pair(text, caps.name("vel").unwrap().as_str())?;
 
-        let pos = Position::new(p[0], p[1]);
-        let vel = Velocity::new(v[0], v[1]);
+        let pos = Position::new(p.0, p.1);
+        let vel = Velocity::new(v.0, v.1);
         let star = Star::new(pos, vel);
         stars.push(star);
     }
 
-    Sky::new(stars)
-}
\ No newline at end of file
+    if stars.is_empty() {
+        return Err(ParseError::at_offset(text, text.len(), "expected at least one star"));
+    }
+
+    Ok(Sky::new(stars))
+}
+
+pub fn part1(sky: &Sky) -> String {
+    let mut sky = sky.clone();
+    sky.tick_until_smallest_area();
+
+    format!("{:?}", sky)
+}
+
+pub fn part2(sky: &Sky) -> u32 {
+    sky.clone().tick_until_smallest_area()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Sky;
+    type Part1 = Answer;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Sky, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Sky) -> Answer {
+        Answer::grid(&part1(input))
+    }
+
+    fn part2(input: &Sky) -> u32 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_malformed_star() {
+        let text = "position=< 9,  1> velocity=< 0,  2>\nposition=< 7,  0> velocity=<-1  0>\n";
+
+        assert_eq!(Some(ParseError::new(2, 29, "expected two numbers separated by a comma, found '-1  0'")), parse(text).err());
+        assert_eq!(Some(ParseError::new(1, 1, "expected a star like position=< 9,  1> velocity=< 0,  2>, found 'position=< 9,  1>'")), parse("position=< 9,  1>").err());
+        assert_eq!(Some(ParseError::new(1, 1, "expected at least one star")), parse("").err());
+    }
+}
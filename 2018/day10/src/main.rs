@@ -1,10 +1,12 @@
-mod lib;
+extern crate aoc_core;
+extern crate year2018_day10;
 
-use crate::lib::parse;
+use aoc_core::{load, parse_or_exit};
+use year2018_day10::parse;
 
 fn main() {
-    let input = include_str!("../input");
-    let mut sky = parse(input);
+    let input = load(2018, 10);
+    let mut sky = parse_or_exit(&input, parse);
 
     let t = sky.tick_until_smallest_area();
     println!("{:?}", sky);
@@ -1,4 +1,7 @@
-use std::{i32, cmp::min};
+extern crate aoc_core;
+
+use aoc_core::{parse_number, ParseError, Solution};
+use std::cmp::min;
 
 #[derive(Debug, PartialEq)]
 pub struct Position {
@@ -87,7 +90,7 @@ impl Grid {
     /// possible squares, with given size, in the grid.
     ///
     pub fn square_with_largest_power(&self, square_size: usize) -> Square {
-        let n_squares_in_row = (self.grid_size - square_size + 1) as usize;
+        let n_squares_in_row = self.grid_size - square_size + 1 ;
 
         let mut power_level = i32::MIN;
         let mut position = Position::new(0, 0);
@@ -139,10 +142,66 @@ impl Grid {
     }
 }
 
+pub const GRID_SIZE: usize = 300;
+
+/// Serial numbers from here on overflow the power levels.
+const MAX_SERIAL_NUMBER: u32 = 1_000_000;
+
+///
+/// Reads the serial number and builds the grid of GRID_SIZE.
+///
+pub fn parse(text: &str) -> Result<Grid, ParseError> {
+    let field = text.trim();
+    let serial_number = parse_number(text, field)?;
+    if serial_number >= MAX_SERIAL_NUMBER {
+        return Err(ParseError::at(text, field, format!("expected a serial number below {}", MAX_SERIAL_NUMBER)));
+    }
+
+    Ok(Grid::new(serial_number, GRID_SIZE))
+}
+
+pub fn part1(grid: &Grid) -> String {
+    let square = grid.square_with_largest_power(3);
+
+    format!("{},{}", square.top_left.x, square.top_left.y)
+}
+
+pub fn part2(grid: &Grid) -> String {
+    let square = grid.largest_power();
+
+    format!("{},{},{}", square.top_left.x, square.top_left.y, square.size)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Grid;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Grid, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Grid) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Grid) -> String {
+        part2(input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_malformed_serial_number() {
+        assert_eq!(Err(ParseError::new(1, 1, "expected a number, found '-18'")), parse("-18\n").map(|_| ()));
+        assert_eq!(Err(ParseError::new(1, 1, "expected a serial number below 1000000")), parse("1000000\n").map(|_| ()));
+    }
+
     const GRID_SIZE: usize = 300;
     const SQUARE_SIZE: usize = 3;
 
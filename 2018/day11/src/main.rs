@@ -1,13 +1,14 @@
-mod lib;
+extern crate aoc_core;
+extern crate year2018_day11;
 
-use crate::lib::Grid;
+use aoc_core::{load, parse_or_exit};
+use year2018_day11::parse;
 
-const GRID_SIZE: usize = 300;
 const SQUARE_SIZE: usize = 3;
 
 fn main() {
-    let serial_number = 8868;
-    let grid = Grid::new(serial_number, GRID_SIZE);
+    let input = load(2018, 11);
+    let grid = parse_or_exit(&input, parse);
     let square_with_larges_power = grid.square_with_largest_power(SQUARE_SIZE);
 
     println!("Square 3x3 with largest power:");
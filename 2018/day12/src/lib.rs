@@ -1,8 +1,11 @@
+extern crate aoc_core;
+
+use aoc_core::{ParseError, Solution};
 use std::collections::{HashMap, VecDeque};
 
 const DEPTH: usize = 5;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Pots {
     state: VecDeque<char>,
     rules: HashMap<Vec<char>, char>,
@@ -78,15 +81,15 @@ impl Pots {
     }
 
     ///
-    /// Updates pots according to the rules.
+    /// Updates pots according to the rules. Pots no rule matches end up empty.
     /// Extends both sides of the current state to accommodate plant growth.
     ///
     fn tick(&mut self) {
         let mut lol: VecDeque<char> = self.state.clone();
 
-        for i in 2..self.state.len() - 3 {
+        for (i, pot) in lol.iter_mut().enumerate().take(self.state.len() - 3).skip(2) {
             let window = self.get(i);
-            lol[i] = *self.rules.get(&window).unwrap();
+            *pot = self.rules.get(&window).copied().unwrap_or('.');
         }
 
         self.state = lol;
@@ -119,32 +122,99 @@ impl Pots {
     }
 }
 
-fn parse_rules(lines: &VecDeque<&str>) -> HashMap<Vec<char>, char> {
+fn is_pot(c: char) -> bool {
+    c == '#' || c == '.'
+}
+
+fn parse_rules(text: &str, lines: &[&str]) -> Result<HashMap<Vec<char>, char>, ParseError> {
     let mut rules = HashMap::new();
 
     for line in lines {
-        let wtf: Vec<&str> = line.split(" => ").collect();
-        let rule: Vec<char> = wtf[0].chars().collect();
-        let result = wtf[1].chars().next().unwrap();
+        let (rule, result) = match line.split_once(" => ") {
+            Some((rule, result)) if rule.len() == DEPTH && rule.chars().all(is_pot) => (rule, result),
+            _ => return Err(ParseError::at(text, line, format!("expected a rule like ..#.. => #, found '{}'", line))),
+        };
+        let result = match result.chars().collect::<Vec<char>>()[..] {
+            [result] if is_pot(result) => result,
+            _ => return Err(ParseError::at(text, result, format!("expected # or . after =>, found '{}'", result))),
+        };
+
+        rules.insert(rule.chars().collect(), result);
+    }
+
+    Ok(rules)
+}
+
+fn parse_initial_state(text: &str, initial_state_line: &str) -> Result<VecDeque<char>, ParseError> {
+    let state = initial_state_line
+        .strip_prefix("initial state: ")
+        .ok_or_else(|| ParseError::at(text, initial_state_line, "expected 'initial state: ' followed by the pots"))?;
 
-        rules.insert(rule, result);
+    match state.find(|c| !is_pot(c)) {
+        Some(offset) => Err(ParseError::at(text, &state[offset..], "expected # or .")),
+        None if state.len() < DEPTH => Err(ParseError::at(text, state, format!("expected at least {} pots", DEPTH))),
+        None => Ok(state.chars().collect()),
     }
+}
+
+pub fn parse(text: &str) -> Result<Pots, ParseError> {
+    let lines: Vec<&str> = text.lines().collect();
+    if lines.len() < 2 || !lines[1].is_empty() {
+        return Err(ParseError::at(text, lines.get(1).unwrap_or(&""), "expected the initial state and an empty line"));
+    }
+
+    let state = parse_initial_state(text, lines[0])?; // Initial state of the pots
+    let rules = parse_rules(text, &lines[2..])?; // Rules of the plant growth
+
+    Ok(Pots::new(state, rules))
+}
+
+pub fn part1(pots: &Pots) -> i32 {
+    let mut pots = pots.clone();
+    pots.simulate(20);
 
-    rules
+    pots.get_value()
 }
 
-fn parse_initial_state(initial_state_line: &str) -> VecDeque<char> {
-    initial_state_line[15..].chars().collect()
+///
+/// After enough generations the value grows linearly, so it is extrapolated
+/// from generations 1000 and 1001.
+///
+pub fn part2(pots: &Pots) -> i64 {
+    let mut pots = pots.clone();
+
+    pots.simulate(1000);
+    let x1 = 1000i64;
+    let y1 = pots.get_value() as i64;
+    pots.simulate(1);
+    let x2 = 1001i64;
+    let y2 = pots.get_value() as i64;
+
+    let a = (y1 - y2) / (x1 - x2);
+    let b = (y1 * x2 - y2 * x1) / (x2 - x1);
+
+    let x = 50_000_000_000;
+    a * x + b
 }
 
-pub fn parse(text: &str) -> Pots {
-    let mut lines: VecDeque<&str> = text.lines().collect();
+pub struct Solver;
 
-    let state = parse_initial_state(lines.pop_front().unwrap()); // Initial state of the pots
-    lines.pop_front(); // Next line is empty
-    let rules = parse_rules(&mut lines); // Rules of the plant growth
+impl Solution for Solver {
+    type Input<'a> = Pots;
+    type Part1 = i32;
+    type Part2 = i64;
 
-    Pots::new(state, rules)
+    fn parse(input: &str) -> Result<Pots, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Pots) -> i32 {
+        part1(input)
+    }
+
+    fn part2(input: &Pots) -> i64 {
+        part2(input)
+    }
 }
 
 #[cfg(test)]
@@ -155,9 +225,25 @@ mod tests {
 
     #[test]
     fn test_parse() {
-        let mut pots = parse(INPUT);
+        let mut pots = parse(INPUT).unwrap();
         pots.simulate(20);
 
         assert_eq!(325, pots.get_value());
     }
+
+    #[test]
+    fn test_parse_malformed_rule() {
+        let input = "initial state: #..#.\n\n...## => #\n..#.. =>\n";
+
+        assert_eq!(Err(ParseError::new(4, 1, "expected a rule like ..#.. => #, found '..#.. =>'")), parse(input).map(|_| ()));
+        assert_eq!(Err(ParseError::new(1, 16, "expected at least 5 pots")), parse("initial state: #.#\n\n").map(|_| ()));
+    }
+
+    #[test]
+    fn test_missing_rules_empty_pots() {
+        let mut pots = parse("initial state: #..#.\n\n..#.. => #\n").unwrap();
+        pots.simulate(1);
+
+        assert_eq!(3, pots.get_value());
+    }
 }
\ No newline at end of file
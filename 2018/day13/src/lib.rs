@@ -1,3 +1,6 @@
+extern crate aoc_core;
+
+use aoc_core::{Answer, ParseError, Solution};
 use std::iter::Cycle;
 use std::vec::IntoIter;
 use std::fmt;
@@ -66,6 +69,18 @@ impl Position {
     }
 }
 
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl From<Position> for Answer {
+    fn from(position: Position) -> Answer {
+        Answer::Text(position.to_string())
+    }
+}
+
 #[derive(PartialEq, Debug, Copy, Clone)]
 struct Complex {
     real: i32,
@@ -96,6 +111,7 @@ impl MulAssign<Complex> for Complex {
     }
 }
 
+#[derive(Clone)]
 struct Cart {
     position: Position,
     direction: Complex,
@@ -113,8 +129,8 @@ impl Cart {
     /// Moves cart forward according to it's direction.
     ///
     fn move_cart(&mut self) {
-        self.position.x = self.position.x + (self.direction.real * CART_SPEED);
-        self.position.y = self.position.y + (self.direction.imaginary * CART_SPEED);
+        self.position.x += self.direction.real * CART_SPEED ;
+        self.position.y += self.direction.imaginary * CART_SPEED ;
     }
 
 
@@ -193,6 +209,7 @@ impl Cart {
     }
 }
 
+#[derive(Clone)]
 pub struct TrackSystem {
     tracks: Vec<Track>,
     carts: Vec<Cart>,
@@ -232,7 +249,7 @@ impl TrackSystem {
                 for i in 0..carts.len() {
                     for j in i + 1..carts.len() {
                         if carts[i].position == carts[j].position {
-                            pos = carts[i].position.clone();
+                            pos = carts[i].position;
                             break 'outer;
                         }
                     }
@@ -327,15 +344,18 @@ impl fmt::Debug for TrackSystem {
     }
 }
 
-pub fn parse(text: &str) -> TrackSystem {
-    let width = text.lines().map(|f| f.len()).max().unwrap();
+///
+/// Creates the track system from the puzzle input, a map of tracks with at least two carts on them.
+///
+pub fn parse(text: &str) -> Result<TrackSystem, ParseError> {
+    let width = text.lines().map(|f| f.chars().count()).max().unwrap_or(0);
     let height = text.lines().count();
 
     let mut track_parts = Vec::with_capacity(width * height);
     let mut carts = Vec::new();
 
     for (i, line) in text.lines().enumerate() {
-        for (j, c) in line.chars().enumerate() {
+        for (j, (offset, c)) in line.char_indices().enumerate() {
             let pos = Position::new(j as i32, i as i32);
 
             let track_part = match c {
@@ -361,16 +381,48 @@ pub fn parse(text: &str) -> TrackSystem {
                     Track::StraightHorizontal
                 },
                 ' ' => Track::None,
-                _ => panic!("Unknown track parts {}", c)
+                _ => return Err(ParseError::at(text, &line[offset..], "expected a track part or a cart"))
             };
 
             track_parts.push((pos, track_part));
         }
     }
 
+    if carts.len() < 2 {
+        return Err(ParseError::at_offset(text, text.len(), "expected at least two carts"));
+    }
+
     track_parts.shrink_to_fit();
 
-    TrackSystem::new(width, height, track_parts, carts)
+    Ok(TrackSystem::new(width, height, track_parts, carts))
+}
+
+pub fn part1(track_system: &TrackSystem) -> Position {
+    track_system.clone().simulate_until_crash()
+}
+
+pub fn part2(track_system: &TrackSystem) -> Position {
+    track_system.clone().simulate_until_last()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = TrackSystem;
+    type Part1 = Position;
+    type Part2 = Position;
+
+    fn parse(input: &str) -> Result<TrackSystem, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &TrackSystem) -> Position {
+        part1(input)
+    }
+
+    fn part2(input: &TrackSystem) -> Position {
+        part2(input)
+    }
 }
 
 #[cfg(test)]
@@ -391,7 +443,7 @@ mod tests {
     #[test]
     fn test_track_system() {
         let input = include_str!("../test");
-        let mut track_system = parse(input);
+        let mut track_system = parse(input).unwrap();
         let crash_position = track_system.simulate_until_crash();
 
         assert_eq!(Position::new(7, 3), crash_position);
@@ -409,9 +461,15 @@ mod tests {
     #[test]
     fn test_track_system_with_removal() {
         let input = include_str!("../test2");
-        let mut track_system = parse(input);
+        let mut track_system = parse(input).unwrap();
         let last_cart_standing = track_system.simulate_until_last();
 
         assert_eq!(Position::new(6, 4), last_cart_standing);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_parse_malformed_tracks() {
+        assert_eq!(Some(ParseError::new(2, 3, "expected a track part or a cart")), parse("/->-\\\n| x |\n").err());
+        assert_eq!(Some(ParseError::new(3, 1, "expected at least two carts")), parse("/->-\\\n\\---/\n").err());
+    }
+}
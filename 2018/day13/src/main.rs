@@ -1,16 +1,17 @@
-mod lib;
+extern crate aoc_core;
+extern crate year2018_day13;
 
-use crate::lib::parse;
+use aoc_core::{load, parse_or_exit};
+use year2018_day13::{parse, part1, part2};
 
 fn main() {
-    let input = include_str!("../input");
-    let mut track_system = parse(input);
-    let crash = track_system.simulate_until_crash();
+    let input = load(2018, 13);
+    let track_system = parse_or_exit(&input, parse);
+    let crash = part1(&track_system);
 
     println!("Crash: {:?}", crash);
 
-    track_system = parse(input);
-    let last_cart_standing = track_system.simulate_until_last();
+    let last_cart_standing = part2(&track_system);
 
     println!("Last cart: {:?}", last_cart_standing);
 }
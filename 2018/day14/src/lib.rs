@@ -0,0 +1,288 @@
+extern crate aoc_core;
+
+use aoc_core::{parse_number, ParseError, Solution};
+
+///
+/// The puzzle input read both as a number of recipes
+/// and as a sequence of scores.
+///
+pub struct Input {
+    recipes: usize,
+    sequence: Vec<u32>,
+}
+
+pub struct Kitchen {
+    elfs: Vec<usize>, // Elf's chosen recipes.
+    recipes: Vec<u32> // Recipe's scores.
+}
+
+impl Kitchen {
+    pub fn new(elfs: Vec<usize>, recipes: Vec<u32>) -> Self {
+        Kitchen { elfs, recipes }
+    }
+
+    fn move_forward(&mut self, elf: usize, steps: usize) {
+        assert!(elf < self.elfs.len());
+
+        self.elfs[elf] = (self.elfs[elf] + steps) % self.recipes.len();
+    }
+
+    fn create_new_recipes(&self) -> Vec<u32> {
+        let mut total_score = 0;
+
+        for elf in self.elfs.iter() {
+            total_score += self.recipes[*elf];
+        }
+
+        let new_recipies: Vec<_> = total_score.to_string().chars().map(|c| c.to_digit(10).unwrap()).collect();
+
+        new_recipies
+    }
+
+    fn add_recipes(&mut self, mut new_recipes: Vec<u32>) {
+        self.recipes.append(&mut new_recipes);
+    }
+
+    pub fn make_recipes(&mut self, n: usize) {
+        while self.recipes.len() < n {
+            let new_recipes = self.create_new_recipes();
+            self.add_recipes(new_recipes);
+
+            for i in 0..self.elfs.len() {
+                self.move_forward(i, self.recipes[self.elfs[i]] as usize + 1);
+            }
+        }
+    }
+
+    pub fn left_of_sequence(&mut self, sequence: &[u32]) -> usize {
+        loop {
+            let old_len = self.recipes.len();
+            self.make_recipes(self.recipes.len() + 1);
+            let new_len = self.recipes.len();
+            let diff = new_len - old_len;
+
+            if self.recipes.len() < sequence.len() + diff {
+                continue;
+            }
+
+            for i in 0..diff {
+                let hmm = &self.recipes[self.recipes.len()-sequence.len()-i..self.recipes.len() - i];
+                if hmm == sequence {
+                    return self.recipes.len()-sequence.len()-i;
+                }
+            }
+        }
+    }
+}
+
+///
+/// Scores of the 10 recipes made right after the first `n` recipes.
+///
+pub fn scores_after(n: usize) -> String {
+    let mut kitchen = Kitchen::new(vec![0, 1], vec![3, 7]);
+    kitchen.make_recipes(n + 10);
+
+    let mut score = String::new();
+    kitchen.recipes.iter().skip(n).take(10).for_each(|r| score.push_str(&r.to_string()));
+
+    score
+}
+
+pub fn parse(text: &str) -> Result<Input, ParseError> {
+    let field = text.trim();
+    if let Some(offset) = field.find(|c: char| !c.is_ascii_digit()) {
+        return Err(ParseError::at(text, &field[offset..], "expected a digit"));
+    }
+
+    let recipes = parse_number(text, field)?;
+    let sequence = field.chars().filter_map(|c| c.to_digit(10)).collect();
+
+    Ok(Input { recipes, sequence })
+}
+
+pub fn part1(input: &Input) -> String {
+    scores_after(input.recipes)
+}
+
+pub fn part2(input: &Input) -> usize {
+    let mut kitchen = Kitchen::new(vec![0, 1], vec![3, 7]);
+
+    kitchen.left_of_sequence(&input.sequence)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Input;
+    type Part1 = String;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_malformed_input() {
+        assert_eq!(Some(ParseError::new(1, 4, "expected a digit")), parse("515x9\n").err());
+        assert_eq!(Some(ParseError::new(1, 1, "expected a number, found ''")), parse("\n").err());
+    }
+
+    #[test]
+    fn test_create_new_recipe() {    
+        let elfs = vec![0, 1];
+        let recipes = vec![3, 7];
+        let kitchen = Kitchen::new(elfs, recipes);
+
+        let new_recipes = kitchen.create_new_recipes();
+
+        assert_eq!(vec![1, 0], new_recipes);
+    }
+
+    #[test]
+    fn test_move_forward() {
+        let elfs = vec![0, 1];
+        let recipes = vec![3, 7];
+        let mut kitchen = Kitchen::new(elfs, recipes);
+
+        let new_recipes = kitchen.create_new_recipes();
+        kitchen.add_recipes(new_recipes);
+
+        for i in 0..kitchen.elfs.len() {
+            kitchen.move_forward(i, kitchen.recipes[kitchen.elfs[i]] as usize + 1);
+        }
+
+        assert_eq!([0, 1], &kitchen.elfs[..]);
+
+        let new_recipes = kitchen.create_new_recipes();
+        kitchen.add_recipes(new_recipes);
+
+        for i in 0..kitchen.elfs.len() {
+            kitchen.move_forward(i, kitchen.recipes[kitchen.elfs[i]] as usize + 1);
+        }
+
+        assert_eq!([4, 3], &kitchen.elfs[..]);
+    }
+
+    #[test]
+    fn test_make_recipes() {
+        let elfs = vec![0, 1];
+        let recipes = vec![3, 7];
+        let mut kitchen = Kitchen::new(elfs, recipes);
+        kitchen.make_recipes(19);
+
+        assert_eq!([3, 7, 1, 0, 1, 0, 1, 2, 4, 5, 1, 5, 8, 9, 1, 6, 7, 7, 9], &kitchen.recipes[..]);
+    }
+
+    #[test]
+    fn test_after_9() {
+        let n = 9;
+        let elfs = vec![0, 1];
+        let recipes = vec![3, 7];
+        let mut kitchen = Kitchen::new(elfs, recipes);
+        kitchen.make_recipes(n + 10);
+
+        let mut score = String::new();
+        kitchen.recipes.iter().skip(n).take(10).for_each(|r| score.push_str(&r.to_string()));
+        assert_eq!("5158916779", score);
+    }
+
+    #[test]
+    fn test_after_5() {
+        let n = 5;
+        let elfs = vec![0, 1];
+        let recipes = vec![3, 7];
+        let mut kitchen = Kitchen::new(elfs, recipes);
+        kitchen.make_recipes(n + 10);
+
+        let mut score = String::new();
+        kitchen.recipes.iter().skip(n).take(10).for_each(|r| score.push_str(&r.to_string()));
+        assert_eq!("0124515891", score);
+    }
+
+    #[test]
+    fn test_after_18() {
+        let n = 18;
+        let elfs = vec![0, 1];
+        let recipes = vec![3, 7];
+        let mut kitchen = Kitchen::new(elfs, recipes);
+        kitchen.make_recipes(n + 10);
+
+        let mut score = String::new();
+        kitchen.recipes.iter().skip(n).take(10).for_each(|r| score.push_str(&r.to_string()));
+        assert_eq!("9251071085", score);
+    }
+
+    #[test]
+    fn test_after_2018() {
+        let n = 2018;
+        let elfs = vec![0, 1];
+        let recipes = vec![3, 7];
+        let mut kitchen = Kitchen::new(elfs, recipes);
+        kitchen.make_recipes(n + 10);
+
+        let mut score = String::new();
+        kitchen.recipes.iter().skip(n).take(10).for_each(|r| score.push_str(&r.to_string()));
+        assert_eq!("5941429882", score);
+    }
+
+    #[test]
+    fn left_of_sequence_51589() {
+        let sequence = [5, 1, 5, 8, 9];
+        let elfs = vec![0, 1];
+        let recipes = vec![3, 7];
+        let mut kitchen = Kitchen::new(elfs, recipes);
+
+        let n_recipes = kitchen.left_of_sequence(&sequence);
+
+        assert_eq!(9, n_recipes);
+    }
+
+    #[test]
+    fn left_of_sequence_01245() {
+        let sequence = [0, 1, 2, 4, 5];
+        let elfs = vec![0, 1];
+        let recipes = vec![3, 7];
+        let mut kitchen = Kitchen::new(elfs, recipes);
+
+        let n_recipes = kitchen.left_of_sequence(&sequence);
+
+        assert_eq!(5, n_recipes);
+    }
+
+    #[test]
+    fn left_of_sequence_92510() {
+        let sequence = [9, 2, 5, 1, 0];
+        let elfs = vec![0, 1];
+        let recipes = vec![3, 7];
+        let mut kitchen = Kitchen::new(elfs, recipes);
+
+        let n_recipes = kitchen.left_of_sequence(&sequence);
+
+        assert_eq!(18, n_recipes);
+    }
+
+    #[test]
+    fn left_of_sequence_59414() {
+        let sequence = [5, 9, 4, 1, 4];
+        let elfs = vec![0, 1];
+        let recipes = vec![3, 7];
+        let mut kitchen = Kitchen::new(elfs, recipes);
+
+        let n_recipes = kitchen.left_of_sequence(&sequence);
+
+        assert_eq!(2018, n_recipes);
+    }
+}
@@ -1,5 +1,6 @@
-use std::io::prelude::*;
-use std::fs::File;
+extern crate aoc_core;
+
+use aoc_core::{ParseError, Solution};
 use std::collections::HashMap;
 
 static N_APPEARANCES: [u32; 2] = [2, 3];
@@ -9,46 +10,6 @@ pub struct Warehouse {
 }
 
 impl Warehouse {
-    ///
-    /// Reads file from path and creates Warehouse based on it.
-    ///
-    pub fn from_file(path: &'static str) -> Warehouse {
-        let text = Warehouse::load_to_string(path); // Get original text from file
-        let boxes_id = Warehouse::parse_string(text); // Extract id of the boxes
-
-        Warehouse { boxes_id }
-    }
-
-    ///
-    /// Reads whole file to a String.
-    ///
-    fn load_to_string(path: &'static str) -> String {
-        // Open file
-        // If file not found - panic
-        let mut f = match File::open(path) {
-            Ok(file) => file,
-            Err(err) => panic!(err)
-        };
-
-        // Read text to String
-        // If reading goes wrong - panic
-        let mut buffer = String::new();
-        match f.read_to_string(&mut buffer) {
-            Ok(_) => (),
-            Err(err) => panic!(err)
-        };
-
-        buffer
-    }
-
-    ///
-    /// String parser.
-    /// Collects each line to Vec<String>
-    ///
-    fn parse_string(text: String) -> Vec<String> {
-        text.lines().map(|el| el.to_owned()).collect()
-    }
-
     ///
     /// Calculates checksum of the warehouse based on the boxes id.
     ///
@@ -65,7 +26,7 @@ impl Warehouse {
             }
         }
 
-        occurences.values().fold(1, |acc, x| acc * x)
+        occurences.values().product()
     }
 
     ///
@@ -87,17 +48,15 @@ impl Warehouse {
     ///
     /// Returns Vec of words that differ by n characters.
     ///
-    fn get_differing_by_n(words: &Vec<String>, n: u32) -> Vec<String> {
+    fn get_differing_by_n(words: &[String], n: u32) -> Vec<String> {
         let mut differing_by_one: Vec<String> = Vec::new();
 
-        let mut i = 0;
-
-        for first in &words[..] {
+        for (i, first) in words.iter().enumerate() {
             for second in &words[i+1..] {
                 let difference = Warehouse::get_difference(first, second);
 
                 if difference == n {
-                    if !differing_by_one.contains(&first) {
+                    if !differing_by_one.contains(first) {
                         differing_by_one.push(first.clone());
                     }
 
@@ -106,8 +65,6 @@ impl Warehouse {
                     }
                 }
             }
-
-            i += 1;
         }
 
         differing_by_one
@@ -117,7 +74,7 @@ impl Warehouse {
     /// Returns a new String that is created by removing differing letters
     /// between two words.
     ///
-    fn get_common(word1: &String, word2: &String) -> String {
+    fn get_common(word1: &str, word2: &str) -> String {
         if word1.len() != word2.len() {
             panic!("Both words must be of the same length!");
         }
@@ -167,6 +124,55 @@ impl Warehouse {
     }
 }
 
+///
+/// Creates Warehouse from the puzzle input, one box id of lowercase letters per line.
+/// All ids must be of the same length.
+///
+pub fn parse(text: &str) -> Result<Warehouse, ParseError> {
+    let length = text.lines().next().map_or(0, |line| line.len());
+    let mut boxes_id = Vec::new();
+
+    for line in text.lines() {
+        if let Some(offset) = line.find(|c: char| !c.is_ascii_lowercase()) {
+            return Err(ParseError::at(text, &line[offset..], "expected a lowercase letter"));
+        }
+        if line.len() != length {
+            return Err(ParseError::at(text, line, format!("expected an id of {} letters, found '{}'", length, line)));
+        }
+
+        boxes_id.push(line.to_owned());
+    }
+
+    Ok(Warehouse { boxes_id })
+}
+
+pub fn part1(warehouse: &Warehouse) -> u32 {
+    warehouse.get_checksum()
+}
+
+pub fn part2(warehouse: &Warehouse) -> String {
+    warehouse.get_common_letters()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Warehouse;
+    type Part1 = u32;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Warehouse, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Warehouse) -> u32 {
+        part1(input)
+    }
+
+    fn part2(input: &Warehouse) -> String {
+        part2(input)
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -188,4 +194,10 @@ mod tests {
 
         assert_eq!(histogram, Warehouse::get_word_histogram("bababc"));
     }
+
+    #[test]
+    fn test_parse_malformed_id() {
+        assert_eq!(Some(ParseError::new(2, 3, "expected a lowercase letter")), parse("abcde\nfgHij\n").err());
+        assert_eq!(Some(ParseError::new(2, 1, "expected an id of 5 letters, found 'fghi'")), parse("abcde\nfghi\n").err());
+    }
 }
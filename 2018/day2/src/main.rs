@@ -1,9 +1,12 @@
-mod lib;
+extern crate aoc_core;
+extern crate year2018_day2;
 
-use lib::Warehouse;
+use aoc_core::{load, parse_or_exit};
+use year2018_day2::parse;
 
 fn main() {
-    let warehouse = Warehouse::from_file("input");
+    let input = load(2018, 2);
+    let warehouse = parse_or_exit(&input, parse);
     let checksum = warehouse.get_checksum();
 
     println!("Checksum: {}", checksum);
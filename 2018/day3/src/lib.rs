@@ -1,5 +1,7 @@
+extern crate aoc_core;
 extern crate regex;
 
+use aoc_core::{parse_number, ParseError, Solution};
 use self::regex::Regex;
 
 pub const WIDTH: usize = 1000;
@@ -44,23 +46,6 @@ impl Claim {
         Claim { id, margin, size }
     }
 
-    pub fn parse_input(input: &str) -> Vec<Claim> {
-        let re = Regex::new(r"(?m)#(?P<id>[0-9]+) @ (?P<left_margin>[0-9]+),(?P<right_margin>[0-9]+): (?P<width>[0-9]+)x(?P<height>[0-9]+)").unwrap();
-        let mut claims = Vec::new();
-
-        for capture in re.captures_iter(input) {
-            let id = capture["id"].parse().unwrap();
-            let left_margin = capture["left_margin"].parse().unwrap();
-            let right_margin = capture["right_margin"].parse().unwrap();
-            let width = capture["width"].parse().unwrap();
-            let height = capture["height"].parse().unwrap();
-
-            claims.push(Claim::new(id, left_margin, right_margin, width, height));
-        }
-
-        claims
-    }
-
     pub fn get_positions(&self) -> Vec<Position> {
         let mut positions = Vec::with_capacity(self.size.width * self.size.height);
 
@@ -93,7 +78,7 @@ impl Matrix {
     }
 
     pub fn get(&self, y: usize, x: usize) -> u32 {
-        self.data[&self.size.width * y + x]
+        self.data[self.size.width * y + x]
     }
 
     pub fn set(&mut self, y: usize, x: usize, data: u32) {
@@ -180,24 +165,75 @@ impl Fabric {
     }
 }
 
+///
+/// Parses one claim per line into a fabric of WIDTH x HEIGHT.
+/// Claims reaching past the fabric are rejected.
+///
+pub fn parse(input: &str) -> Result<Fabric, ParseError> {
+    let re = Regex::new(r"^#(?P<id>[0-9]+) @ (?P<left_margin>[0-9]+),(?P<top_margin>[0-9]+): (?P<width>[0-9]+)x(?P<height>[0-9]+)$").unwrap();
+    let mut claims = Vec::new();
+
+    for line in input.lines() {
+        let capture = re
+            .captures(line)
+            .ok_or_else(|| ParseError::at(input, line, format!("expected a claim like #1 @ 1,3: 4x4, found '{}'", line)))?;
+        let number = |name: &str| parse_number::<usize>(input, capture.name(name).unwrap().as_str());
+
+        let id = parse_number(input, &capture["id"])?;
+        let (left_margin, top_margin) = (number("left_margin")?, number("top_margin")?);
+        let (width, height) = (number("width")?, number("height")?);
+        if left_margin + width > WIDTH || top_margin + height > HEIGHT {
+            return Err(ParseError::at(input, line, format!("claim does not fit a {}x{} fabric", WIDTH, HEIGHT)));
+        }
+
+        claims.push(Claim::new(id, left_margin, top_margin, width, height));
+    }
+
+    Ok(Fabric::new(WIDTH, HEIGHT, claims))
+}
+
+pub fn part1(fabric: &Fabric) -> usize {
+    fabric.overlap_size(2)
+}
+
+pub fn part2(fabric: &Fabric) -> u32 {
+    fabric.get_not_overlapping_claim().unwrap().id()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Fabric;
+    type Part1 = usize;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Fabric, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Fabric) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Fabric) -> u32 {
+        part2(input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_overlap_size() {
-        let input = include_str!("../test");
-        let claims = Claim::parse_input(input);
-        let fabric = Fabric::new(WIDTH, HEIGHT, claims);
+        let fabric = parse(include_str!("../test")).unwrap();
 
         assert_eq!(4, fabric.overlap_size(2));
     }
 
     #[test]
     fn test_overlap() {
-        let input = include_str!("../test");
-        let claims = Claim::parse_input(input);
-        let fabric = Fabric::new(WIDTH, HEIGHT, claims);
+        let fabric = parse(include_str!("../test")).unwrap();
         let not_overlapped_claim = fabric.get_not_overlapping_claim();
 
         assert_eq!(3, not_overlapped_claim.unwrap().id());
@@ -216,4 +252,12 @@ mod tests {
 
         assert_eq!(true_positions, positions);
     }
+
+    #[test]
+    fn test_parse_malformed_claim() {
+        let input = "#1 @ 1,3: 4x4\n#2 @ 3,1 4x4\n";
+
+        assert_eq!(Err(ParseError::new(2, 1, "expected a claim like #1 @ 1,3: 4x4, found '#2 @ 3,1 4x4'")), parse(input).map(|_| ()));
+        assert_eq!(Err(ParseError::new(1, 1, "claim does not fit a 1000x1000 fabric")), parse("#1 @ 999,3: 4x4\n").map(|_| ()));
+    }
 }
@@ -1,11 +1,12 @@
-mod lib;
+extern crate aoc_core;
+extern crate year2018_day3;
 
-use lib::{Claim, Fabric, WIDTH, HEIGHT};
+use aoc_core::{load, parse_or_exit};
+use year2018_day3::parse;
 
 fn main() {
-    let input = include_str!("../input");
-    let claims = Claim::parse_input(input);
-    let fabric = Fabric::new(WIDTH, HEIGHT, claims);
+    let input = load(2018, 3);
+    let fabric = parse_or_exit(&input, parse);
 
     println!("Overlap by at least 2: {}", fabric.overlap_size(2));
     println!("Not overlapping claim id: {}", fabric.get_not_overlapping_claim().unwrap().id());
@@ -1,10 +1,11 @@
+extern crate aoc_core;
 extern crate regex;
 extern crate chrono;
-extern crate time;
 
+use aoc_core::{parse_number, ParseError, Solution};
 use self::regex::Regex;
 use self::chrono::prelude::*;
-use self::time::Duration;
+use self::chrono::Duration;
 use std::collections::VecDeque;
 use std::collections::HashMap;
 
@@ -82,7 +83,7 @@ impl Shift {
     ///
     fn get_date(start_date_time: &NaiveDateTime) -> NaiveDate {
         let start_time = start_date_time.time();
-        let minimal_start_time = NaiveTime::from_hms(23, 0, 0);
+        let minimal_start_time = NaiveTime::from_hms_opt(23, 0, 0).unwrap();
 
         if start_time > minimal_start_time {
             start_date_time.date() + Duration::days(1)
@@ -93,9 +94,12 @@ impl Shift {
 }
 
 impl TimeTable {
-    pub fn new(text: &str) -> TimeTable {
+    ///
+    /// Splits chronologically ordered entries into shifts.
+    /// The first entry has to begin a shift.
+    ///
+    fn new(mut entries: VecDeque<(NaiveDateTime, GuardAction)>) -> TimeTable {
         let mut shifts = Vec::new();
-        let mut entries = TimeTable::parse(text);
 
         'outer: loop {
             let mut current_entries = VecDeque::new();
@@ -106,9 +110,7 @@ impl TimeTable {
 
                 match temp {
                     Some(v) => {
-                        if v.1 == GuardAction::WakeUp {
-                            current_entries.push_back(v);
-                        } else if v.1 == GuardAction::FallAsleep {
+                        if v.1 == GuardAction::WakeUp || v.1 == GuardAction::FallAsleep {
                             current_entries.push_back(v);
                         } else {
                             entries.push_front(v);
@@ -127,46 +129,6 @@ impl TimeTable {
         TimeTable { shifts }
     }
 
-    ///
-    /// Uses regex to parse text and extract info from each line.
-    /// After extraction, data is sorted by chronological order.
-    ///
-    fn parse(text: &str) -> VecDeque<(NaiveDateTime, GuardAction)> {
-        let date_time_re: Regex = Regex::new(r"(?m)\[(?P<year>\d{4})-(?P<month>\d{2})-(?P<day>\d{2}) (?P<hour>\d{2}):(?P<minute>\d{2})] (?P<data>[a-zA-Z0-9 #]+)").unwrap();
-        let guard_id_re: Regex = Regex::new(r"(?m)#(?P<id>\d+)").unwrap();
-        let mut entries: Vec<(NaiveDateTime, GuardAction)> = Vec::new(); // Container for date, guard action
-
-        for capture in date_time_re.captures_iter(text) {
-            let year: i32 = capture["year"].parse().unwrap();
-            let month: u32 = capture["month"].parse().unwrap();
-            let day: u32 = capture["day"].parse().unwrap();
-
-            let hour: u32 = capture["hour"].parse().unwrap();
-            let min: u32 = capture["minute"].parse().unwrap();
-
-            let date_time = NaiveDate::from_ymd(year, month, day).and_hms(hour, min, 0);//Utc.ymd(year, month, day).and_hms(hour, min, 0);
-            let other_text = &capture["data"];
-            let id_option = guard_id_re.captures(other_text);
-
-            let guard_action = match id_option {
-                Some(id) => GuardAction::BeginShift(id["id"].parse().unwrap()),
-                None => {
-                    if other_text == "falls asleep" {
-                        GuardAction::FallAsleep
-                    } else if other_text == "wakes up" {
-                        GuardAction::WakeUp
-                    } else {
-                        panic!("Unknown text after date-time.");
-                    }
-                }
-            };
-
-            entries.push((date_time, guard_action));
-        }
-        entries.sort_by_key(|k| k.0);
-
-        VecDeque::from(entries)
-    }
 
     ///
     /// Calculates how many minutes, each guard was asleep in total.
@@ -179,7 +141,7 @@ impl TimeTable {
         for shift in &self.shifts {
             let asleep_time = shift.asleep
                 .iter()
-                .filter(|&&v| v == true)
+                .filter(|&&v| v)
                 .count();
 
             *guard_asleep_map.entry(shift.id).or_insert(0) += asleep_time;
@@ -238,7 +200,7 @@ impl TimeTable {
 
         for shift in &self.shifts {
             for (i, v) in shift.asleep.iter().enumerate() {
-                if *v == true {
+                if *v {
                     *data[i].entry(shift.id).or_insert(0) += 1;
                 }
             }
@@ -265,6 +227,83 @@ impl TimeTable {
     }
 }
 
+///
+/// Uses regex to parse text and extract info from each line.
+/// After extraction, data is sorted by chronological order.
+///
+fn parse_entries(text: &str) -> Result<VecDeque<(NaiveDateTime, GuardAction)>, ParseError> {
+    let date_time_re: Regex = Regex::new(r"^\[(?P<year>\d{4})-(?P<month>\d{2})-(?P<day>\d{2}) (?P<hour>\d{2}):(?P<minute>\d{2})] (?P<data>.*)$").unwrap();
+    let guard_id_re: Regex = Regex::new(r"^Guard #(?P<id>\d+) begins shift$").unwrap();
+    let mut entries: Vec<(NaiveDateTime, GuardAction, &str)> = Vec::new(); // Container for date, guard action and the line
+
+    for line in text.lines() {
+        let capture = date_time_re
+            .captures(line)
+            .ok_or_else(|| ParseError::at(text, line, format!("expected an entry like [1518-11-01 00:00] wakes up, found '{}'", line)))?;
+        let number = |name: &str| parse_number::<u32>(text, capture.name(name).unwrap().as_str());
+
+        let year: i32 = parse_number(text, &capture["year"])?;
+        let date_time = NaiveDate::from_ymd_opt(year, number("month")?, number("day")?)
+            .and_then(|date| date.and_hms_opt(number("hour").ok()?, number("minute").ok()?, 0))
+            .ok_or_else(|| ParseError::at(text, line, "invalid date or time"))?;
+        let other_text = capture.name("data").unwrap().as_str();
+
+        let guard_action = match guard_id_re.captures(other_text) {
+            Some(id) => GuardAction::BeginShift(parse_number(text, &id["id"])?),
+            None if other_text == "falls asleep" => GuardAction::FallAsleep,
+            None if other_text == "wakes up" => GuardAction::WakeUp,
+            None => return Err(ParseError::at(text, other_text, format!("expected 'Guard #<id> begins shift', 'falls asleep' or 'wakes up', found '{}'", other_text))),
+        };
+
+        entries.push((date_time, guard_action, line));
+    }
+    entries.sort_by_key(|k| k.0);
+
+    match entries.first() {
+        None => return Err(ParseError::at_offset(text, text.len(), "expected at least one entry")),
+        Some((_, GuardAction::BeginShift(_), _)) => {},
+        Some((_, _, line)) => return Err(ParseError::at(text, line, "the earliest entry has to begin a shift")),
+    }
+
+    Ok(entries.into_iter().map(|(date_time, guard_action, _)| (date_time, guard_action)).collect())
+}
+
+pub fn parse(text: &str) -> Result<TimeTable, ParseError> {
+    Ok(TimeTable::new(parse_entries(text)?))
+}
+
+pub fn part1(time_table: &TimeTable) -> usize {
+    let guard = time_table.most_asleep_guard();
+
+    guard as usize * time_table.most_asleep_minute(guard)
+}
+
+pub fn part2(time_table: &TimeTable) -> usize {
+    let (guard, minute) = time_table.most_frequent_guard_on_minute();
+
+    guard as usize * minute
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = TimeTable;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<TimeTable, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &TimeTable) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &TimeTable) -> usize {
+        part2(input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -272,23 +311,23 @@ mod tests {
     #[test]
     fn test_shift_new() {
         let info1 = (
-            NaiveDate::from_ymd(1518, 11, 01).and_hms(0, 0, 0),
+            NaiveDate::from_ymd_opt(1518, 11, 1).unwrap().and_hms_opt(0, 0, 0).unwrap(),
             GuardAction::BeginShift(10)
         );
         let info2 = (
-            NaiveDate::from_ymd(1518, 11, 01).and_hms(0, 5, 0),
+            NaiveDate::from_ymd_opt(1518, 11, 1).unwrap().and_hms_opt(0, 5, 0).unwrap(),
             GuardAction::FallAsleep
         );
         let info3 = (
-            NaiveDate::from_ymd(1518, 11, 01).and_hms(0, 25, 0),
+            NaiveDate::from_ymd_opt(1518, 11, 1).unwrap().and_hms_opt(0, 25, 0).unwrap(),
             GuardAction::WakeUp
         );
         let info4 = (
-            NaiveDate::from_ymd(1518, 11, 01).and_hms(0, 30, 0),
+            NaiveDate::from_ymd_opt(1518, 11, 1).unwrap().and_hms_opt(0, 30, 0).unwrap(),
             GuardAction::FallAsleep
         );
         let info5 = (
-            NaiveDate::from_ymd(1518, 11, 01).and_hms(0, 55, 0),
+            NaiveDate::from_ymd_opt(1518, 11, 1).unwrap().and_hms_opt(0, 55, 0).unwrap(),
             GuardAction::WakeUp
         );
 
@@ -300,7 +339,7 @@ mod tests {
         asleep.extend(vec![true; 25]);
         asleep.extend(vec![false; 5]);
 
-        assert_eq!(NaiveDate::from_ymd(1518, 11, 01), shift._date);
+        assert_eq!(NaiveDate::from_ymd_opt(1518, 11, 1).unwrap(), shift._date);
         assert_eq!(10, shift.id);
         assert_eq!(asleep, shift.asleep.to_vec());
     }
@@ -311,22 +350,22 @@ mod tests {
     #[test]
     fn test_parse() {
         let text = include_str!("../test");
-        let entries = TimeTable::parse(text);
+        let entries = parse_entries(text).unwrap();
 
         let test1 = (
-            NaiveDate::from_ymd(1518, 11, 01).and_hms(0, 0, 0),
+            NaiveDate::from_ymd_opt(1518, 11, 1).unwrap().and_hms_opt(0, 0, 0).unwrap(),
             GuardAction::BeginShift(10)
         );
         let test2 = (
-            NaiveDate::from_ymd(1518, 11, 02).and_hms(0, 40, 0),
+            NaiveDate::from_ymd_opt(1518, 11, 2).unwrap().and_hms_opt(0, 40, 0).unwrap(),
             GuardAction::FallAsleep
         );
         let test3 = (
-            NaiveDate::from_ymd(1518, 11, 03).and_hms(0, 29, 0),
+            NaiveDate::from_ymd_opt(1518, 11, 3).unwrap().and_hms_opt(0, 29, 0).unwrap(),
             GuardAction::WakeUp
         );
         let test4 = (
-            NaiveDate::from_ymd(1518, 11, 05).and_hms(0, 55, 0),
+            NaiveDate::from_ymd_opt(1518, 11, 5).unwrap().and_hms_opt(0, 55, 0).unwrap(),
             GuardAction::WakeUp
         );
 
@@ -336,10 +375,19 @@ mod tests {
         assert_eq!(test4, entries[16]);
     }
 
+    #[test]
+    fn test_parse_malformed_entry() {
+        let text = "[1518-11-01 00:00] Guard #10 begins shift\n[1518-11-01 00:05] snores\n";
+
+        assert_eq!(Err(ParseError::new(2, 20, "expected 'Guard #<id> begins shift', 'falls asleep' or 'wakes up', found 'snores'")), parse(text).map(|_| ()));
+        assert_eq!(Err(ParseError::new(1, 1, "invalid date or time")), parse("[1518-13-01 00:00] wakes up\n").map(|_| ()));
+        assert_eq!(Err(ParseError::new(2, 1, "the earliest entry has to begin a shift")), parse("[1518-11-01 00:05] Guard #10 begins shift\n[1518-11-01 00:00] wakes up\n").map(|_| ()));
+        assert_eq!(Err(ParseError::new(1, 1, "expected at least one entry")), parse("").map(|_| ()));
+    }
+
     #[test]
     fn test_asleep_times() {
-        let text = include_str!("../test");
-        let time_table = TimeTable::new(text);
+        let time_table = parse(include_str!("../test")).unwrap();
 
         let guard_asleep_times = time_table.guard_asleep_times();
         let mut true_guard_asleep_times: HashMap<u32, usize> = HashMap::new();
@@ -351,8 +399,7 @@ mod tests {
 
     #[test]
     fn test_most_asleep_guard() {
-        let text = include_str!("../test");
-        let time_table = TimeTable::new(text);
+        let time_table = parse(include_str!("../test")).unwrap();
 
         let most_asleep_guard = time_table.most_asleep_guard();
 
@@ -361,8 +408,7 @@ mod tests {
 
     #[test]
     fn test_most_asleep_minute() {
-        let text = include_str!("../test");
-        let time_table = TimeTable::new(text);
+        let time_table = parse(include_str!("../test")).unwrap();
 
         let most_asleep_minute10 = time_table.most_asleep_minute(10);
         let most_asleep_minute99 = time_table.most_asleep_minute(99);
@@ -373,8 +419,7 @@ mod tests {
 
     #[test]
     fn test_most_frequent_guard_on_minute() {
-        let text = include_str!("../test");
-        let time_table = TimeTable::new(text);
+        let time_table = parse(include_str!("../test")).unwrap();
 
         let (guard_id, minute) = time_table.most_frequent_guard_on_minute();
 
@@ -1,10 +1,12 @@
-mod lib;
+extern crate aoc_core;
+extern crate year2018_day4;
 
-use lib::TimeTable;
+use aoc_core::{load, parse_or_exit};
+use year2018_day4::parse;
 
 fn main() {
-    let input = include_str!("../input");
-    let time_table = TimeTable::new(input);
+    let input = load(2018, 4);
+    let time_table = parse_or_exit(&input, parse);
 
     let most_asleep_guard = time_table.most_asleep_guard();
     let most_asleep_minute = time_table.most_asleep_minute(most_asleep_guard);
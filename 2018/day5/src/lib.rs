@@ -1,3 +1,6 @@
+extern crate aoc_core;
+
+use aoc_core::{ParseError, Solution};
 use std::collections::{HashSet, HashMap};
 
 pub struct Polymer {
@@ -5,7 +8,7 @@ pub struct Polymer {
 }
 
 impl Polymer {
-    pub fn new(original_structure: &str) -> Polymer {
+    fn new(original_structure: &str) -> Polymer {
         let structure = String::from(original_structure);
 
         Polymer { structure }
@@ -25,7 +28,6 @@ impl Polymer {
         let mut results = HashMap::new();
 
         for agent in agents {
-            println!("{}", agent);
             let mut new_structure = self.structure.clone();
             new_structure = Polymer::remove_same_type(new_structure, agent);
             new_structure = Polymer::react(&new_structure);
@@ -33,14 +35,14 @@ impl Polymer {
             results.insert(agent, new_structure.len());
         }
 
-        let (_, &smallest_structure_length) = results.iter().min_by_key(|&(&k, &v)| v).unwrap();
+        let (_, &smallest_structure_length) = results.iter().min_by_key(|&(&_k, &v)| v).unwrap();
 
         smallest_structure_length
     }
 
-    fn react(structure: &String) -> String {
+    fn react(structure: &str) -> String {
         let mut change = true;
-        let mut current_structure = structure.clone();
+        let mut current_structure = structure.to_string();
 
         while change {
             change = false;
@@ -89,6 +91,50 @@ impl Polymer {
     }
 }
 
+///
+/// Creates Polymer from the puzzle input, a single line of letters.
+///
+pub fn parse(text: &str) -> Result<Polymer, ParseError> {
+    let structure = text.trim();
+
+    if let Some(offset) = structure.find(|c: char| !c.is_ascii_alphabetic()) {
+        return Err(ParseError::at(text, &structure[offset..], "expected a letter"));
+    }
+    if structure.is_empty() {
+        return Err(ParseError::at(text, structure, "expected at least one unit"));
+    }
+
+    Ok(Polymer::new(structure))
+}
+
+pub fn part1(polymer: &Polymer) -> usize {
+    polymer.trigger().len()
+}
+
+pub fn part2(polymer: &Polymer) -> usize {
+    polymer.triggerv2()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Polymer;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Polymer, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Polymer) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Polymer) -> usize {
+        part2(input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -97,44 +143,44 @@ mod tests {
     fn test_same_type() {
         let a = 'A';
         let b = 'a';
-        assert_eq!(true, Polymer::same_type(&a, &b));
+        assert!(Polymer::same_type(&a, &b));
 
         let a = 'a';
         let b = 'A';
-        assert_eq!(true, Polymer::same_type(&a, &b));
+        assert!(Polymer::same_type(&a, &b));
 
         let a = 'b';
         let b = 'A';
-        assert_eq!(false, Polymer::same_type(&a, &b));
+        assert!(!Polymer::same_type(&a, &b));
     }
 
     #[test]
     fn test_opposite_polarity() {
         let a = 'A';
         let b = 'a';
-        assert_eq!(true, Polymer::opposite_polarity(&a, &b));
+        assert!(Polymer::opposite_polarity(&a, &b));
 
         let a = 'a';
         let b = 'A';
-        assert_eq!(true, Polymer::opposite_polarity(&a, &b));
+        assert!(Polymer::opposite_polarity(&a, &b));
 
         let a = 'a';
         let b = 'a';
-        assert_eq!(false, Polymer::opposite_polarity(&a, &b));
+        assert!(!Polymer::opposite_polarity(&a, &b));
 
         let a = 'A';
         let b = 'A';
-        assert_eq!(false, Polymer::opposite_polarity(&a, &b));
+        assert!(!Polymer::opposite_polarity(&a, &b));
 
         let a = 'b';
         let b = 'a';
-        assert_eq!(false, Polymer::opposite_polarity(&a, &b));
+        assert!(!Polymer::opposite_polarity(&a, &b));
     }
 
     #[test]
     fn test_trigger() {
         let input = include_str!("../test");
-        let mut polymer = Polymer::new(input);
+        let polymer = Polymer::new(input);
         let new_structure = polymer.trigger();
 
         assert_eq!("dabCBAcaDA", &new_structure);
@@ -143,9 +189,15 @@ mod tests {
     #[test]
     fn test_triggerv2() {
         let input = include_str!("../test");
-        let mut polymer = Polymer::new(input);
+        let polymer = Polymer::new(input);
         let new_structure_length = polymer.triggerv2();
 
         assert_eq!(4, new_structure_length);
     }
+
+    #[test]
+    fn test_parse_malformed_polymer() {
+        assert_eq!(Some(ParseError::new(1, 5, "expected a letter")), parse("dabA1cCaCBAcCcaDA\n").err());
+        assert_eq!(Some(ParseError::new(1, 1, "expected at least one unit")), parse("\n").err());
+    }
 }
\ No newline at end of file
@@ -1,10 +1,12 @@
-mod lib;
+extern crate aoc_core;
+extern crate year2018_day5;
 
-use crate::lib::Polymer;
+use aoc_core::{load, parse_or_exit};
+use year2018_day5::parse;
 
 fn main() {
-    let input = include_str!("../input").trim();
-    let mut polymer = Polymer::new(input);
+    let input = load(2018, 5);
+    let polymer = parse_or_exit(&input, parse);
     let new_structure = polymer.trigger();
 
     println!("New polymer length: {}", new_structure.len());
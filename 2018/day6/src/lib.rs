@@ -1,3 +1,6 @@
+extern crate aoc_core;
+
+use aoc_core::{parse_number, ParseError, Solution};
 use std::collections::{HashMap, HashSet};
 use std::iter::FromIterator;
 
@@ -76,13 +79,13 @@ impl Grid {
     fn get_closest_point_matrix(&self) -> Vec<ClosestPoint> {
         let mut distances: Vec<Option<ClosestPoint>> = vec![None; self.height * self.width];
 
-        for i in 0..(self.width * self.height) {
+        for (i, distance) in distances.iter_mut().enumerate() {
             let point = self.get_point(i);
 
             for (given_point_id, given_point) in &self.points {
                 let dist = point.manhattan_distance(given_point);
 
-                match &mut distances[i] {
+                match distance {
                     Some(a) => {
                         if dist < a.distance {
                             a.distance = dist;
@@ -92,7 +95,7 @@ impl Grid {
                             a.double = true;
                         }
                     }
-                    None => distances[i] = Some(ClosestPoint::new(*given_point_id, dist, false))
+                    None => *distance = Some(ClosestPoint::new(*given_point_id, dist, false))
                 }
             }
         }
@@ -107,7 +110,7 @@ impl Grid {
     ///
     pub fn biggest_non_infinite_area(&self) -> usize {
         let distances = self.get_closest_point_matrix(); // Get closest point for each coordinate.
-        let finite_area_ids: HashSet<u32> = HashSet::from_iter(self.points.keys().map(|k| *k)); // Create set of all points id.
+        let finite_area_ids: HashSet<u32> = HashSet::from_iter(self.points.keys().copied()); // Create set of all points id.
         let infinite_area_ids = self.border_points(&distances); // Get set of all points that are on the border of the grid.
 
         // Calculate area size for each finite area.
@@ -123,13 +126,13 @@ impl Grid {
     /// Calculates which points are on the border.
     /// Returns set of ids of these points.
     ///
-    fn border_points(&self, distances: &Vec<ClosestPoint>) -> HashSet<u32> {
+    fn border_points(&self, distances: &[ClosestPoint]) -> HashSet<u32> {
         let mut infinite_area_ids = HashSet::new();
 
         for (i, cp) in distances.iter().enumerate() {
             let point = self.get_point(i);
 
-            if cp.double == false && (point.x == 0 || point.x == (self.width - 1) as i32 || point.y == 0 || point.y == (self.height - 1) as i32) {
+            if !cp.double && (point.x == 0 || point.x == (self.width - 1) as i32 || point.y == 0 || point.y == (self.height - 1) as i32) {
                 infinite_area_ids.insert(cp.id);
             }
         }
@@ -140,9 +143,9 @@ impl Grid {
     ///
     /// Gets size of the area whose coordinates are closest to given point.
     ///
-    fn get_area(&self, distances: &Vec<ClosestPoint>, point_id: u32) -> usize {
+    fn get_area(&self, distances: &[ClosestPoint], point_id: u32) -> usize {
         distances.iter()
-            .filter(|cp| cp.id == point_id && cp.double == false)
+            .filter(|cp| cp.id == point_id && !cp.double)
             .count()
     }
 
@@ -158,7 +161,7 @@ impl Grid {
             let point = self.get_point(i);
             let mut sum_dist = 0;
 
-            for (_, given_point) in &self.points {
+            for given_point in self.points.values() {
                 sum_dist += given_point.manhattan_distance(&point);
             }
 
@@ -172,23 +175,59 @@ impl Grid {
 }
 
 ///
-/// Parses input.
-/// Outputs a HashMap where key is the point id
-/// and the value is the coordinate of that point.
+/// Parses input into a grid of the points, numbered by line.
+/// Coordinates can't be negative, as the grid starts at 0, 0.
 ///
-pub fn parse(text: &str) -> HashMap<u32, Point> {
+pub fn parse(text: &str) -> Result<Grid, ParseError> {
     let mut points = HashMap::new();
 
     for (i, line) in text.lines().enumerate() {
-        let line: Vec<&str> = line.trim().split(", ").collect();
-        let x: i32 = line[0].parse().unwrap();
-        let y: i32 = line[1].parse().unwrap();
-        let point = Point::new(x, y);
+        let (x, y) = line
+            .trim()
+            .split_once(", ")
+            .ok_or_else(|| ParseError::at(text, line, format!("expected a point like 1, 6, found '{}'", line)))?;
+        let coordinate = |field: &str| match parse_number::<i32>(text, field)? {
+            n if n < 0 => Err(ParseError::at(text, field, format!("expected a non-negative coordinate, found {}", n))),
+            n => Ok(n),
+        };
+        let point = Point::new(coordinate(x)?, coordinate(y)?);
 
         points.insert(i as u32, point);
     }
 
-    points
+    if points.is_empty() {
+        return Err(ParseError::at_offset(text, text.len(), "expected at least one point"));
+    }
+
+    Ok(Grid::new(points))
+}
+
+pub fn part1(grid: &Grid) -> usize {
+    grid.biggest_non_infinite_area()
+}
+
+pub fn part2(grid: &Grid) -> usize {
+    grid.area_with_equal_distance(10000)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Grid;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Grid, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Grid) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Grid) -> usize {
+        part2(input)
+    }
 }
 
 #[cfg(test)]
@@ -207,17 +246,22 @@ mod tests {
 
     #[test]
     fn test_largest_area() {
-        let points = parse(INPUT);
-        let grid = Grid::new(points);
+        let grid = parse(INPUT).unwrap();
 
         assert_eq!(17, grid.biggest_non_infinite_area());
     }
 
     #[test]
     fn test_area_with_equal_distance() {
-        let points = parse(INPUT);
-        let grid = Grid::new(points);
+        let grid = parse(INPUT).unwrap();
 
         assert_eq!(16, grid.area_with_equal_distance(32));
     }
+
+    #[test]
+    fn test_parse_malformed_point() {
+        assert_eq!(Err(ParseError::new(2, 1, "expected a point like 1, 6, found '1,6'")), parse("1, 1\n1,6\n").map(|_| ()));
+        assert_eq!(Err(ParseError::new(1, 4, "expected a non-negative coordinate, found -1")), parse("1, -1\n").map(|_| ()));
+        assert_eq!(Err(ParseError::new(1, 1, "expected at least one point")), parse("").map(|_| ()));
+    }
 }
\ No newline at end of file
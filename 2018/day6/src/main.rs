@@ -1,11 +1,12 @@
-mod lib;
+extern crate aoc_core;
+extern crate year2018_day6;
 
-use crate::lib::{parse, Grid};
+use aoc_core::{load, parse_or_exit};
+use year2018_day6::parse;
 
 fn main() {
-    let input = include_str!("../input");
-    let data = parse(input);
-    let grid = Grid::new(data);
+    let input = load(2018, 6);
+    let grid = parse_or_exit(&input, parse);
 
     println!("Largest, non-infinite area: {}", grid.biggest_non_infinite_area());
 
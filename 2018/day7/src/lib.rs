@@ -1,5 +1,7 @@
+extern crate aoc_core;
 extern crate regex;
 
+use aoc_core::{ParseError, Solution};
 use std::collections::{HashMap, HashSet};
 use regex::Regex;
 
@@ -123,10 +125,8 @@ impl WorkerManager {
         let mut jobs_done = Vec::new();
 
         for worker in &mut self.workers {
-            let job_done = worker.tick();
-
-            if job_done.is_some() {
-                jobs_done.push(job_done.unwrap());
+            if let Some(job_done) = worker.tick() {
+                jobs_done.push(job_done);
             }
         }
 
@@ -134,6 +134,7 @@ impl WorkerManager {
     }
 }
 
+#[derive(Clone)]
 pub struct Process {
     steps: HashSet<char>,
     requirements: HashMap<char, HashSet<char>>,
@@ -157,7 +158,7 @@ impl Process {
     fn get_available(&self, finished: &HashSet<char>) -> Vec<char> {
         let mut available = Vec::new();
         for (step, requires) in &self.requirements {
-            if requires.len() == 0 && !finished.contains(step) {
+            if requires.is_empty() && !finished.contains(step) {
                 available.push(*step);
             }
         }
@@ -173,11 +174,29 @@ impl Process {
     fn perform_step(&mut self, step: char, finished: &mut HashSet<char>) {
         finished.insert(step);
 
-        for (_, required_steps) in &mut self.requirements {
+        for required_steps in self.requirements.values_mut() {
             required_steps.remove(&step);
         }
     }
 
+    ///
+    /// Checks if all steps can be completed,
+    /// that is no steps require each other in a cycle.
+    ///
+    fn is_completable(&self) -> bool {
+        let mut process = self.clone();
+        let mut finished = HashSet::new();
+
+        while finished.len() != process.steps.len() {
+            match process.get_available(&finished).first() {
+                Some(&step) => process.perform_step(step, &mut finished),
+                None => return false,
+            }
+        }
+
+        true
+    }
+
     ///
     /// Calculates ordering in which steps should be completed.
     /// If more than one step can be completed at the same time
@@ -204,7 +223,7 @@ impl Process {
     /// with n_workers number of workers. This function requires
     /// another function that calculates required time to perform a step.
     ///
-    pub fn complete_time(&mut self, n_workers: usize, step_time: &Fn(char) -> u8) -> usize {
+    pub fn complete_time(&mut self, n_workers: usize, step_time: &dyn Fn(char) -> u8) -> usize {
         let mut finished = HashSet::new(); // Set of finished steps
         let mut second = 0; // Number of seconds that has passed
         let mut worker_manager = WorkerManager::new(n_workers); // Set of workers
@@ -231,28 +250,73 @@ impl Process {
     }
 }
 
-pub fn parse(text: &str) -> (HashSet<char>, HashMap<char, HashSet<char>>) {
+///
+/// Parses the requirements into a process.
+/// Steps are named by capital letters and can't require each other in a cycle.
+///
+pub fn parse(text: &str) -> Result<Process, ParseError> {
     let mut requirements: HashMap<char, HashSet<char>> = HashMap::new();
     let mut steps = HashSet::new();
-    let re = Regex::new(r"(?m)Step (?P<s1>.) must be finished before step (?P<s2>.)").unwrap();
+    let re = Regex::new(r"^Step (?P<s1>[A-Z]) must be finished before step (?P<s2>[A-Z]) can begin\.$").unwrap();
 
-    for captures in re.captures_iter(text) {
-        let requirement: char = captures["s1"].parse().unwrap();
-        let requires: char = captures["s2"].parse().unwrap();
+    for line in text.lines() {
+        let captures = re.captures(line).ok_or_else(|| {
+            ParseError::at(text, line, format!("expected 'Step A must be finished before step B can begin.', found '{}'", line))
+        })?;
+        let requirement = captures["s1"].chars().next().unwrap();
+        let requires = captures["s2"].chars().next().unwrap();
 
         steps.insert(requirement);
         steps.insert(requires);
 
         requirements.entry(requires)
-            .or_insert(HashSet::new())
+            .or_default()
             .insert(requirement);
     }
 
     for step in &steps {
-        requirements.entry(*step).or_insert(HashSet::new());
+        requirements.entry(*step).or_default();
     }
 
-    (steps, requirements)
+    let process = Process::new(steps, requirements);
+    if !process.is_completable() {
+        return Err(ParseError::at_offset(text, text.len(), "steps require each other in a cycle"));
+    }
+
+    Ok(process)
+}
+
+/// Steps take 60 seconds plus their position in the alphabet.
+pub fn work_time(step: char) -> u8 {
+    step as u8 - 4
+}
+
+pub fn part1(process: &Process) -> String {
+    process.clone().get_ordering().into_iter().collect()
+}
+
+pub fn part2(process: &Process) -> usize {
+    process.clone().complete_time(5, &work_time)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Process;
+    type Part1 = String;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Process, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Process) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Process) -> usize {
+        part2(input)
+    }
 }
 
 #[cfg(test)]
@@ -263,8 +327,7 @@ mod tests {
 
     #[test]
     fn test_ordering() {
-        let (steps, requirements) = parse(INPUT);
-        let mut process = Process::new(steps, requirements);
+        let mut process = parse(INPUT).unwrap();
         let ordering = process.get_ordering();
         let true_ordering = vec!['C', 'A', 'B', 'D', 'F', 'E'];
 
@@ -273,8 +336,7 @@ mod tests {
 
     #[test]
     fn test_get_available() {
-        let (steps, requirements) = parse(INPUT);
-        let process = Process::new(steps, requirements);
+        let process = parse(INPUT).unwrap();
         let available = process.get_available(&HashSet::new());
         let true_available = vec!['C'];
 
@@ -283,12 +345,23 @@ mod tests {
 
     #[test]
     fn test_complete_time() {
-        let (steps, requirements) = parse(INPUT);
-        let mut process = Process::new(steps, requirements);
+        let mut process = parse(INPUT).unwrap();
 
         assert_eq!(15, process.complete_time(2, &work_time));
     }
 
+    #[test]
+    fn test_parse_malformed_step() {
+        let input = "Step C must be finished before step A can begin.\nStep a must be finished before step F can begin.\n";
+        let cycle = "Step A must be finished before step B can begin.\nStep B must be finished before step A can begin.\n";
+
+        assert_eq!(
+            Err(ParseError::new(2, 1, "expected 'Step A must be finished before step B can begin.', found 'Step a must be finished before step F can begin.'")),
+            parse(input).map(|_| ())
+        );
+        assert_eq!(Err(ParseError::new(3, 1, "steps require each other in a cycle")), parse(cycle).map(|_| ()));
+    }
+
     fn work_time(step: char) -> u8 {
         step as u8 - 64
     }
@@ -1,13 +1,13 @@
-mod lib;
+extern crate aoc_core;
+extern crate year2018_day7;
 
-use crate::lib::{Process, parse};
+use aoc_core::{load, parse_or_exit};
+use year2018_day7::{parse, work_time};
 
 fn main() {
-    let input = include_str!("../input");
-    let (steps1, requirements1) = parse(input);
-    let (steps2, requirements2) = (steps1.clone(), requirements1.clone());
-    let mut process1 = Process::new(steps1, requirements1);
-    let mut process2 = Process::new(steps2, requirements2);
+    let input = load(2018, 7);
+    let mut process1 = parse_or_exit(&input, parse);
+    let mut process2 = process1.clone();
 
     let ordering: String = process1.get_ordering().into_iter().collect();
     println!("Ordering: {:?}", ordering);
@@ -16,7 +16,3 @@ fn main() {
     let time = process2.complete_time(n_workers, &work_time);
     println!("Time to complete task with {} workers: {}", n_workers, time);
 }
-
-fn work_time(step: char) -> u8 {
-    step as u8 - 4
-}
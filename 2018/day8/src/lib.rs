@@ -1,3 +1,7 @@
+extern crate aoc_core;
+
+use aoc_core::{parse_number, ParseError, Solution};
+
 ///
 /// Represents a node that has children nodes,
 /// and some metadata as u8 values. Each node
@@ -11,28 +15,21 @@ pub struct Node {
 }
 
 impl Node {
-    ///
-    /// Creates a node based on a vec of u8 values from the input.
-    ///
-    pub fn from_data(mut data: Vec<u8>) -> Node {
-        data.reverse();
-        Node::create_tree(&mut data)
-    }
-
     fn new(children: Vec<Node>, metadata: Vec<u8>) -> Node {
         Node { children, metadata }
     }
 
     ///
     /// Creates a tree of nodes. Returned node is the root of the tree.
+    /// Returns None when the data ends before the tree does.
     ///
-    fn create_tree(data: &mut Vec<u8>) -> Node {
-        let mut n_children = data.pop().unwrap();
-        let n_metadata = data.pop().unwrap();
+    fn create_tree(data: &mut Vec<u8>) -> Option<Node> {
+        let mut n_children = data.pop()?;
+        let n_metadata = data.pop()?;
         let mut children = Vec::with_capacity(n_children as usize);
 
         while n_children > 0 {
-            let node = Node::create_tree(data);
+            let node = Node::create_tree(data)?;
             children.push(node);
 
             n_children -= 1;
@@ -41,10 +38,10 @@ impl Node {
         let mut metadata = Vec::with_capacity(n_metadata as usize);
 
         for _ in 0..n_metadata {
-            metadata.push(data.pop().unwrap());
+            metadata.push(data.pop()?);
         }
 
-        Node::new(children, metadata)
+        Some(Node::new(children, metadata))
     }
 
     ///
@@ -71,7 +68,7 @@ impl Node {
     pub fn get_value(&self) -> u32 {
         let mut value = 0;
 
-        if self.children.len() == 0 {
+        if self.children.is_empty() {
             value = self.metadata.iter().fold(0, |acc: u32, f| acc + *f as u32);
         } else {
             let indexes = self.metadata.iter()
@@ -87,17 +84,55 @@ impl Node {
     }
 }
 
-pub fn parse(text: &str) -> Vec<u8> {
-    let mut data = Vec::with_capacity(text.len() / 2);
+///
+/// Creates the tree from the puzzle input, whitespace separated numbers.
+/// Returns the root node.
+///
+pub fn parse(text: &str) -> Result<Node, ParseError> {
+    let fields: Vec<&str> = text.split_whitespace().collect();
+    let mut data = Vec::with_capacity(fields.len());
 
-    for c in text.split_whitespace() {
-        let value: u8 = c.parse().unwrap();
+    for c in fields.iter().rev() {
+        let value: u8 = parse_number(text, c)?;
         data.push(value);
     }
 
-    data.shrink_to_fit();
+    let root = Node::create_tree(&mut data)
+        .ok_or_else(|| ParseError::at_offset(text, text.len(), "expected more entries of the tree"))?;
+
+    if !data.is_empty() {
+        return Err(ParseError::at(text, fields[fields.len() - data.len()], "expected the end of the tree"));
+    }
+
+    Ok(root)
+}
+
+pub fn part1(root: &Node) -> u32 {
+    root.get_metadata_sum()
+}
 
-    data
+pub fn part2(root: &Node) -> u32 {
+    root.get_value()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Node;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Node, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Node) -> u32 {
+        part1(input)
+    }
+
+    fn part2(input: &Node) -> u32 {
+        part2(input)
+    }
 }
 
 #[cfg(test)]
@@ -108,17 +143,22 @@ mod test {
 
     #[test]
     fn test_metadata_sum() {
-        let data = parse(INPUT);
-        let node = Node::from_data(data);
+        let node = parse(INPUT).unwrap();
 
         assert_eq!(138, node.get_metadata_sum());
     }
 
     #[test]
     fn test_value() {
-        let data = parse(INPUT);
-        let node = Node::from_data(data);
+        let node = parse(INPUT).unwrap();
 
         assert_eq!(66, node.get_value());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_parse_malformed_tree() {
+        assert_eq!(Some(ParseError::new(1, 7, "expected a number, found 'x'")), parse("1 1 0 x 5 2\n").err());
+        assert_eq!(Some(ParseError::new(2, 1, "expected more entries of the tree")), parse("1 1 0 1 5\n").err());
+        assert_eq!(Some(ParseError::new(1, 13, "expected the end of the tree")), parse("1 1 0 1 5 2 7\n").err());
+    }
+}
@@ -1,11 +1,12 @@
-mod lib;
+extern crate aoc_core;
+extern crate year2018_day8;
 
-use crate::lib::{parse, Node};
+use aoc_core::{load, parse_or_exit};
+use year2018_day8::parse;
 
 fn main() {
-    let input = include_str!("../input");
-    let data = parse(input);
-    let node = Node::from_data(data);
+    let input = load(2018, 8);
+    let node = parse_or_exit(&input, parse);
 
     println!("Sum of all metadata entries is: {}", node.get_metadata_sum());
     println!("Value of the root node is: {}", node.get_value());
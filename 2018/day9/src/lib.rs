@@ -1,3 +1,6 @@
+extern crate aoc_core;
+
+use aoc_core::{parse_number, ParseError, Solution};
 use std::collections::VecDeque;
 use std::mem;
 
@@ -126,11 +129,70 @@ impl MarbleGame {
     }
 }
 
+///
+/// Reads number of players and value of the last marble from the puzzle input.
+/// There has to be at least one player.
+///
+pub fn parse(text: &str) -> Result<(u32, usize), ParseError> {
+    let line = text.trim_end();
+    let (players, marbles) = line
+        .strip_suffix(" points")
+        .and_then(|line| line.split_once(" players; last marble is worth "))
+        .ok_or_else(|| ParseError::at(text, line, format!("expected '<n> players; last marble is worth <n> points', found '{}'", line)))?;
+
+    let n_players = parse_number(text, players)?;
+    if n_players == 0 {
+        return Err(ParseError::at(text, players, "expected at least one player"));
+    }
+
+    Ok((n_players, parse_number(text, marbles)?))
+}
+
+pub fn part1(&(n_players, n_marbles): &(u32, usize)) -> u32 {
+    let mut marble_game = MarbleGame::new(n_players, n_marbles);
+    marble_game.simulate();
+
+    marble_game.get_high_score()
+}
+
+pub fn part2(&(n_players, n_marbles): &(u32, usize)) -> u32 {
+    let mut marble_game = MarbleGame::new(n_players, n_marbles * 100);
+    marble_game.simulate();
+
+    marble_game.get_high_score()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = (u32, usize);
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<(u32, usize), ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &(u32, usize)) -> u32 {
+        part1(input)
+    }
+
+    fn part2(input: &(u32, usize)) -> u32 {
+        part2(input)
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_malformed_game() {
+        assert_eq!(Ok((10, 1618)), parse("10 players; last marble is worth 1618 points\n"));
+        assert_eq!(Err(ParseError::new(1, 1, "expected '<n> players; last marble is worth <n> points', found '10 players'")), parse("10 players\n"));
+        assert_eq!(Err(ParseError::new(1, 1, "expected at least one player")), parse("0 players; last marble is worth 1618 points"));
+    }
+
     #[test]
     fn test_10_players_1618_marbles() {
         let mut marble_game = MarbleGame::new(10, 1618);
@@ -1,20 +1,22 @@
-mod lib;
+extern crate aoc_core;
+extern crate year2018_day9;
 
-use crate::lib::MarbleGame;
+use aoc_core::{load, parse_or_exit};
+use year2018_day9::{parse, MarbleGame};
 
 fn main() {
-    const N_PLAYERS: u32 = 413;
-    const N_MARBLES: usize = 71082;
+    let input = load(2018, 9);
+    let (n_players, n_marbles) = parse_or_exit(&input, parse);
 
-    let mut marble_game = MarbleGame::new(N_PLAYERS, N_MARBLES);
+    let mut marble_game = MarbleGame::new(n_players, n_marbles);
     marble_game.simulate();
     let high_score = marble_game.get_high_score();
 
-    println!("High score with {} players and {} marbles is: {}", N_PLAYERS, N_MARBLES, high_score);
+    println!("High score with {} players and {} marbles is: {}", n_players, n_marbles, high_score);
 
-    let mut marble_game = MarbleGame::new(N_PLAYERS, N_MARBLES * 100);
+    let mut marble_game = MarbleGame::new(n_players, n_marbles * 100);
     marble_game.simulate();
     let high_score = marble_game.get_high_score();
 
-    println!("High score with {} players and {} marbles is: {}", N_PLAYERS, N_MARBLES * 100, high_score);
+    println!("High score with {} players and {} marbles is: {}", n_players, n_marbles * 100, high_score);
 }
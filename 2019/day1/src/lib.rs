@@ -0,0 +1,62 @@
+use aoc_core::{parse_number, ParseError, Solution};
+
+pub fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+    input
+        .lines()
+        .map(|line| parse_number(input, line))
+        .collect()
+}
+
+fn fuel(mass: i32) -> i32 {
+    (mass / 3) - 2
+}
+
+pub fn part1(masses: &[i32]) -> i32 {
+    masses.iter().copied().map(fuel).sum()
+}
+
+/// Fuel needs fuel as well, until the extra mass needs none.
+pub fn part2(masses: &[i32]) -> i32 {
+    let mut total_fuel = 0;
+
+    for &v in masses {
+        let mut v = fuel(v);
+
+        while v > 0 {
+            total_fuel += v;
+            v = fuel(v);
+        }
+    }
+
+    total_fuel
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Vec<i32>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<i32>) -> i32 {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<i32>) -> i32 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed_mass() {
+        assert_eq!(Some(ParseError::new(2, 1, "expected a number, found '12 '")), parse("1969\n12 \n").err());
+    }
+}
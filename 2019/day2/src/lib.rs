@@ -0,0 +1,86 @@
+use aoc_core::{ParseError, Solution};
+use intcode::search::{self, Search};
+use intcode::CPU;
+
+const TARGET: i64 = 19690720;
+
+fn run(cpu: &mut CPU, rom: &[i64], noun: i64, verb: i64) -> i64 {
+    cpu.load_rom(rom.to_vec());
+    cpu.write(1, noun).unwrap();
+    cpu.write(2, verb).unwrap();
+    cpu.run().unwrap();
+
+    cpu.read(0)
+}
+
+pub fn part1(rom: &[i64]) -> i64 {
+    run(&mut CPU::new(), rom, 12, 2)
+}
+
+/// Noun and verb for which the program produces `TARGET`.
+pub fn noun_verb(rom: &[i64]) -> (i64, i64) {
+    let candidates = search::patches(&[1, 2], vec![0..100, 0..100]).unwrap();
+    let result = Search::new(rom).run(candidates, |candidate, cpu| {
+        if cpu.read(0) == TARGET {
+            Some((candidate.patches[0].1, candidate.patches[1].1))
+        } else {
+            None
+        }
+    });
+
+    match result.matches.first() {
+        Some((_, noun_verb)) => *noun_verb,
+        None => panic!("no noun and verb produce {} in {} runs", TARGET, result.runs),
+    }
+}
+
+pub fn part2(rom: &[i64]) -> i64 {
+    let (noun, verb) = noun_verb(rom);
+
+    100 * noun + verb
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        intcode::parse(input)
+    }
+
+    fn part1(rom: &Vec<i64>) -> i64 {
+        part1(rom)
+    }
+
+    fn part2(rom: &Vec<i64>) -> i64 {
+        part2(rom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_answer() {
+        let rom = intcode::parse(include_str!("../../../inputs/2019/02.txt")).unwrap();
+
+        assert_eq!(5534943, part1(&rom));
+    }
+
+    #[test]
+    fn part2_answer() {
+        let rom = intcode::parse(include_str!("../../../inputs/2019/02.txt")).unwrap();
+
+        assert_eq!((76, 3), noun_verb(&rom));
+        assert_eq!(7603, part2(&rom));
+    }
+
+    #[test]
+    fn malformed_rom() {
+        assert_eq!(Some(ParseError::new(1, 3, "expected a number, found ''")), Solver::parse("1,,0,99").err());
+    }
+}
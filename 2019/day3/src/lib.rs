@@ -0,0 +1,171 @@
+use aoc_core::{parse_number, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+use std::iter::FromIterator;
+
+pub fn solve((a, b): &(Wire, Wire)) -> (i32, usize) {
+    // Point to wire length map
+    let a_points = generate_points(a);
+    let b_points = generate_points(b);
+
+    // Set of unique points
+    let unique_a_points: HashSet<(i32, i32)> = HashSet::from_iter(a_points.keys().cloned());
+    let unique_b_points: HashSet<(i32, i32)> = HashSet::from_iter(b_points.keys().cloned());
+
+    // Get unqiue intersections between two wires
+    let intersections = unique_a_points.intersection(&unique_b_points);
+
+    // Get intersection closest to 0,0
+    let min_dist = intersections
+        .clone()
+        .min_by_key(|p| manhattan_distance(p))
+        .unwrap();
+
+    // Get intersection with least total wire length
+    let min_length = intersections
+        .min_by_key(|k| a_points[k] + b_points[k])
+        .unwrap();
+
+    (
+        manhattan_distance(min_dist),
+        a_points[min_length] + b_points[min_length],
+    )
+}
+
+fn manhattan_distance(p: &(i32, i32)) -> i32 {
+    i32::abs(p.0) + i32::abs(p.1)
+}
+
+/// Moves making up a wire, as direction and length.
+pub type Wire = Vec<(char, i32)>;
+
+/// Two lines of comma separated moves, like R8,U5,L5,D3.
+pub fn parse(input: &str) -> Result<(Wire, Wire), ParseError> {
+    let mut iter = input.lines();
+    let mut next_wire = || match iter.next() {
+        Some(line) => parse_line(input, line),
+        None => Err(ParseError::at_offset(input, input.len(), "expected two wires")),
+    };
+    let a = next_wire()?;
+    let b = next_wire()?;
+
+    Ok((a, b))
+}
+
+fn parse_line(input: &str, line: &str) -> Result<Wire, ParseError> {
+    line.split(',')
+        .map(|v| {
+            if !v.starts_with(|c| "LDUR".contains(c)) {
+                return Err(ParseError::at(input, v, "expected one of L D U R"));
+            }
+            let (dir, len) = v.split_at(1);
+            Ok((dir.chars().next().unwrap(), parse_number(input, len)?))
+        })
+        .collect()
+}
+
+fn generate_points(lines: &Wire) -> HashMap<(i32, i32), usize> {
+    let mut points = HashMap::new();
+    let (mut x, mut y) = (0, 0);
+    let mut n = 0;
+    for &(direction, length) in lines {
+        let (x_move, y_move);
+        match direction {
+            'L' => {
+                x_move = -1;
+                y_move = 0;
+            }
+            'D' => {
+                x_move = 0;
+                y_move = -1;
+            }
+            'U' => {
+                x_move = 0;
+                y_move = 1;
+            }
+            'R' => {
+                x_move = 1;
+                y_move = 0;
+            }
+            _ => panic!("unknown direction"),
+        }
+
+        for _ in 0..length {
+            x += x_move;
+            y += y_move;
+            n += 1;
+            points.insert((x, y), n);
+        }
+    }
+
+    points
+}
+
+pub fn part1(wires: &(Wire, Wire)) -> i32 {
+    solve(wires).0
+}
+
+pub fn part2(wires: &(Wire, Wire)) -> usize {
+    solve(wires).1
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = (Wire, Wire);
+    type Part1 = i32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<(Wire, Wire), ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &(Wire, Wire)) -> i32 {
+        part1(input)
+    }
+
+    fn part2(input: &(Wire, Wire)) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+
+    const WIRES_1: &str = "R8,U5,L5,D3\nU7,R6,D4,L4";
+    const WIRES_2: &str = "R75,D30,R83,U83,L12,D49,R71,U7,L72\nU62,R66,U55,R34,D71,R55,D58,R83";
+    const WIRES_3: &str =
+        "R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51\nU98,R91,D20,R16,D67,R40,U7,R15,U6,R7";
+
+    #[test]
+    fn wire_1() {
+        let (min_dist, min_length) = solve(&parse(WIRES_1).unwrap());
+
+        assert_eq!(6, min_dist);
+        assert_eq!(30, min_length);
+    }
+
+    #[test]
+    fn wire_2() {
+        let (min_dist, min_length) = solve(&parse(WIRES_2).unwrap());
+
+        assert_eq!(159, min_dist);
+        assert_eq!(610, min_length);
+    }
+
+    #[test]
+    fn wire_3() {
+        let (min_dist, min_length) = solve(&parse(WIRES_3).unwrap());
+
+        assert_eq!(135, min_dist);
+        assert_eq!(410, min_length);
+    }
+
+    #[test]
+    fn malformed_wire() {
+        assert_eq!(Some(ParseError::new(1, 7, "expected one of L D U R")), parse("R8,U5,X5,D3\nU7").err());
+        assert_eq!(Some(ParseError::new(2, 5, "expected a number, found '6x'")), parse("R8\nU7,R6x").err());
+        assert_eq!(Some(ParseError::new(1, 3, "expected two wires")), parse("R8").err());
+    }
+}
@@ -0,0 +1,110 @@
+use aoc_core::{parse_number, ParseError, Solution};
+
+fn split_digits(mut n: u32) -> Vec<u32> {
+    let mut digits = Vec::new();
+
+    while n > 0 {
+        digits.push(n % 10);
+        n /= 10;
+    }
+    digits.reverse();
+
+    digits
+}
+
+fn check_part1<'a>(mut digits: impl Iterator<Item = &'a u32>) -> bool {
+    let mut prev = digits.next().unwrap();
+    let mut stayed_same = false; // Check it at least once the value didnt increase
+
+    for d in digits {
+        if d < prev {
+            return false;
+        } else if d == prev {
+            stayed_same = true;
+        }
+
+        prev = d;
+    }
+
+    stayed_same
+}
+
+fn check_part2<'a>(mut digits: impl Iterator<Item = &'a u32>) -> bool {
+    let mut prev = digits.next().unwrap();
+    let mut stayed_same = false; // Check it at least once the value didnt increase
+    let mut counter = 0;
+
+    for d in digits {
+        if d < prev {
+            return false;
+        }
+
+        if d == prev && !stayed_same {
+            counter += 1;
+        } else {
+            if counter == 1 {
+                stayed_same = true;
+            } else {
+                counter = 0;
+            }
+        }
+
+        prev = d;
+    }
+
+    stayed_same | (counter == 1)
+}
+
+/// The puzzle input range, given as `first-last`.
+pub fn parse(input: &str) -> Result<(u32, u32), ParseError> {
+    let range = input.trim();
+    let (first, last) = range
+        .split_once('-')
+        .ok_or_else(|| ParseError::at(input, range, "expected a range like 111111-999999"))?;
+
+    Ok((parse_number(input, first)?, parse_number(input, last)?))
+}
+
+/// Passwords in the puzzle input range.
+fn candidates(&(first, last): &(u32, u32)) -> impl Iterator<Item = Vec<u32>> {
+    (first..=last).map(split_digits)
+}
+
+pub fn part1(range: &(u32, u32)) -> usize {
+    candidates(range).filter(|digits| check_part1(digits.iter())).count()
+}
+
+pub fn part2(range: &(u32, u32)) -> usize {
+    candidates(range).filter(|digits| check_part2(digits.iter())).count()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = (u32, u32);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<(u32, u32), ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &(u32, u32)) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &(u32, u32)) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed_range() {
+        assert_eq!(Some(ParseError::new(1, 1, "expected a range like 111111-999999")), parse("307237\n").err());
+        assert_eq!(Some(ParseError::new(1, 8, "expected a number, found '76905x'")), parse("307237-76905x\n").err());
+    }
+}
@@ -0,0 +1,62 @@
+use aoc_core::{ParseError, Solution};
+use intcode::CPU;
+
+fn run(rom: &[i64], input: i64) -> Option<i64> {
+    let mut cpu = CPU::new();
+    cpu.load_rom(rom.to_vec());
+    cpu.push_input(input);
+
+    cpu.run_to_completion().unwrap().last().copied()
+}
+
+pub fn part1(rom: &[i64]) -> i64 {
+    run(rom, 1).unwrap()
+}
+
+pub fn part2(rom: &[i64]) -> i64 {
+    run(rom, 5).unwrap()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        intcode::parse(input)
+    }
+
+    fn part1(rom: &Vec<i64>) -> i64 {
+        part1(rom)
+    }
+
+    fn part2(rom: &Vec<i64>) -> i64 {
+        part2(rom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_answer() {
+        let rom = intcode::parse(include_str!("../../../inputs/2019/05.txt")).unwrap();
+
+        assert_eq!(Some(13978427), run(&rom, 1));
+    }
+
+    #[test]
+    fn part2_answer() {
+        let rom = intcode::parse(include_str!("../../../inputs/2019/05.txt")).unwrap();
+
+        assert_eq!(Some(11189491), run(&rom, 5));
+    }
+
+    #[test]
+    fn malformed_rom() {
+        assert_eq!(Some(ParseError::new(1, 7, "expected a number, found '0;4'")), Solver::parse("3,0,4,0;4,99").err());
+    }
+}
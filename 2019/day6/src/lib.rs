@@ -0,0 +1,116 @@
+use aoc_core::{ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+/// Counts number of orbital transfers needed between "from" and "to".
+fn count_orbital_transfers(star_map: &HashMap<&str, &str>, from: &str, to: &str) -> usize {
+    let mut current = star_map[from];
+    let mut road_to_com = HashSet::new();
+    road_to_com.insert(current);
+
+    while let Some(&next) = star_map.get(current) {
+        road_to_com.insert(next);
+        current = next;
+    }
+
+    let mut common = star_map[to];
+
+    loop {
+        if road_to_com.contains(&common) {
+            break;
+        } else {
+            common = star_map[common];
+        }
+    }
+
+    distance(star_map, "YOU", common) + distance(star_map, "SAN", common)
+}
+
+/// Counts how many orbital transfers are needed by two objects.
+/// "from" must orbit "to" at least indirectly.
+fn distance(star_map: &HashMap<&str, &str>, from: &str, to: &str) -> usize {
+    let mut dist = 0;
+    let mut current = from;
+
+    while let Some(&next) = star_map.get(current) {
+        if next == to {
+            return dist;
+        } else {
+            current = next;
+            dist += 1;
+        }
+    }
+
+    panic!("from doesn't orbit to");
+}
+
+/// Counts total number of direct and indirect orbits for each planet.
+fn count_number_of_orbits(star_map: &HashMap<&str, &str>) -> usize {
+    let mut counter = 0;
+
+    for &planet in star_map.keys() {
+        counter += number_of_orbits(star_map, planet);
+    }
+
+    counter
+}
+
+/// Counts how many objects "planet" orbits (directly + indirectly)
+fn number_of_orbits(star_map: &HashMap<&str, &str>, planet: &str) -> usize {
+    if planet == "COM" {
+        0
+    } else {
+        1 + number_of_orbits(star_map, star_map[planet])
+    }
+}
+
+/// Map of each object to the one it orbits, given as lines like `COM)B`.
+pub fn parse(input: &str) -> Result<HashMap<&str, &str>, ParseError> {
+    let mut orbits = HashMap::new();
+    for line in input.lines() {
+        match line.split_once(')') {
+            Some((center, object)) if !center.is_empty() && !object.is_empty() => {
+                orbits.insert(object, center);
+            }
+            _ => return Err(ParseError::at(input, line, format!("expected an orbit like COM)B, found '{}'", line))),
+        }
+    }
+    Ok(orbits)
+}
+
+pub fn part1(star_map: &HashMap<&str, &str>) -> usize {
+    count_number_of_orbits(star_map)
+}
+
+pub fn part2(star_map: &HashMap<&str, &str>) -> usize {
+    count_orbital_transfers(star_map, "YOU", "SAN")
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = HashMap<&'a str, &'a str>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<HashMap<&str, &str>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &HashMap<&str, &str>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &HashMap<&str, &str>) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed_orbit() {
+        assert_eq!(Some(ParseError::new(2, 1, "expected an orbit like COM)B, found 'B-C'")), parse("COM)B\nB-C\n").err());
+    }
+}
@@ -0,0 +1,75 @@
+use aoc_core::{ParseError, Solution};
+use intcode::network::Network;
+use itertools::Itertools;
+
+pub fn part2(rom: &[i64]) -> i64 {
+    (5..10)
+        .permutations(5)
+        .map(|phase| amplify(Network::ring(rom, 5), &phase))
+        .max()
+        .unwrap()
+}
+
+pub fn part1(rom: &[i64]) -> i64 {
+    (0..5)
+        .permutations(5)
+        .map(|phase| amplify(Network::chain(rom, 5), &phase))
+        .max()
+        .unwrap()
+}
+
+/// Feeds each amplifier its phase, sends the initial signal 0 to the first
+/// one and returns the last signal produced by the last amplifier.
+fn amplify(mut network: Network, phase: &[i64]) -> i64 {
+    for (i, ph) in phase.iter().enumerate() {
+        network.push_input(i, *ph);
+    }
+    network.push_input(0, 0);
+    network.run().unwrap();
+
+    *network.outputs(phase.len() - 1).last().unwrap()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        intcode::parse(input)
+    }
+
+    fn part1(rom: &Vec<i64>) -> i64 {
+        part1(rom)
+    }
+
+    fn part2(rom: &Vec<i64>) -> i64 {
+        part2(rom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_answer() {
+        let rom = intcode::parse(include_str!("../../../inputs/2019/07.txt")).unwrap();
+
+        assert_eq!(38834, part1(&rom));
+    }
+
+    #[test]
+    fn part2_answer() {
+        let rom = intcode::parse(include_str!("../../../inputs/2019/07.txt")).unwrap();
+
+        assert_eq!(69113332, part2(&rom));
+    }
+
+    #[test]
+    fn malformed_rom() {
+        assert_eq!(Some(ParseError::new(1, 6, "expected a number, found '9 9'")), Solver::parse("3,15,9 9").err());
+    }
+}
@@ -0,0 +1,112 @@
+use aoc_core::{Answer, ParseError, Solution};
+
+fn render(layers: &[Vec<u32>], width: usize, height: usize) -> String {
+    let mut pixels = vec![2u32; width * height];
+
+    for layer in layers.iter().rev() {
+        for (dest, source) in pixels.iter_mut().zip(layer) {
+            if *source != 2 {
+                *dest = *source;
+            }
+        }
+    }
+
+    let mut image = String::new();
+
+    for pixel_line in pixels.chunks(width) {
+        for pixel in pixel_line {
+            let symbol = match *pixel {
+                0 => '\u{25A0}',
+                1 => '\u{25A1}',
+                2 => '\u{2205}',
+                _ => panic!("unknown pixel")
+            };
+
+            image.push(symbol);
+        }
+
+        image.push('\n');
+    }
+
+    image
+}
+
+fn checksum(layers: &[Vec<u32>]) -> usize {
+    let layer = layers
+        .iter()
+        .min_by_key(|v| v.iter().filter(|v| **v == 0).count())
+        .unwrap();
+
+    let ones = layer.iter().filter(|v| **v == 1).count();
+    let twos = layer.iter().filter(|v| **v == 2).count();
+
+    ones * twos
+}
+
+/// Splits `data`, a slice of `input`, into whole layers of 0, 1 and 2 pixels.
+fn get_layers(input: &str, data: &str, width: usize, height: usize) -> Result<Vec<Vec<u32>>, ParseError> {
+    if let Some(offset) = data.find(|c| !matches!(c, '0'..='2')) {
+        return Err(ParseError::at(input, &data[offset..], "expected a pixel 0, 1 or 2"));
+    }
+    if data.is_empty() || !data.len().is_multiple_of(width * height) {
+        let message = format!("expected whole layers of {}x{} pixels, found {} pixels", width, height, data.len());
+        return Err(ParseError::at(input, &data[data.len()..], message));
+    }
+
+    let data: Vec<u32> = data.chars().filter_map(|v| v.to_digit(10)).collect();
+    let mut layers = Vec::new();
+    for chunk in data.chunks(width * height) {
+        layers.push(chunk.to_vec());
+    }
+
+    Ok(layers)
+}
+
+const WIDTH: usize = 25;
+const HEIGHT: usize = 6;
+
+pub fn parse(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    get_layers(input, input.trim(), WIDTH, HEIGHT)
+}
+
+pub fn part1(layers: &[Vec<u32>]) -> usize {
+    checksum(layers)
+}
+
+pub fn part2(layers: &[Vec<u32>]) -> String {
+    render(layers, WIDTH, HEIGHT)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Vec<Vec<u32>>;
+    type Part1 = usize;
+    type Part2 = Answer;
+
+    fn parse(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<Vec<u32>>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<Vec<u32>>) -> Answer {
+        Answer::grid(&part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed_image() {
+        let layers = |input: &str, width, height| get_layers(input, input.trim(), width, height);
+
+        assert_eq!(Ok(vec![vec![1, 2, 2, 1], vec![0, 0, 1, 2]]), layers("12210012\n", 2, 2));
+        assert_eq!(Some(ParseError::new(1, 3, "expected a pixel 0, 1 or 2")), layers("123", 1, 1).err());
+        assert_eq!(Some(ParseError::new(1, 4, "expected whole layers of 2x2 pixels, found 3 pixels")), layers("122\n", 2, 2).err());
+    }
+}
@@ -0,0 +1,70 @@
+//! Compares the plain interpreter with the decode cache.
+//!
+//! Run with `cargo bench`.
+
+use std::time::{Duration, Instant};
+
+use intcode::asm::assemble;
+use intcode::{parse, CPU};
+
+/// Counts down from the input, then outputs 0.
+const COUNTDOWN: &str = "
+          in [n]
+    loop: add [n], #-1, [n]
+          jt [n], #loop
+          out [n]
+          hlt
+    n:    data 0
+";
+
+fn time<F: FnMut()>(mut f: F) -> Duration {
+    let start = Instant::now();
+    f();
+    start.elapsed()
+}
+
+fn compare(name: &str, mut run: impl FnMut(&mut CPU)) {
+    let mut plain = CPU::new();
+    let mut cached = CPU::new();
+    cached.set_decode_cache(true);
+
+    // Warm up both, so neither pays for the first allocations.
+    run(&mut plain);
+    run(&mut cached);
+
+    let plain = time(|| run(&mut plain));
+    let cached = time(|| run(&mut cached));
+    let speedup = plain.as_secs_f64() / cached.as_secs_f64();
+
+    println!("{:<12} plain {:>10.2?}  cached {:>10.2?}  x{:.2}", name, plain, cached, speedup);
+}
+
+fn main() {
+    let countdown = assemble(COUNTDOWN).unwrap();
+    compare("countdown", |cpu| {
+        cpu.reload(&countdown);
+        cpu.push_input(5_000_000);
+        assert_eq!(Ok(vec![0]), cpu.run_to_completion());
+    });
+
+    let day5 = parse(include_str!("../../../inputs/2019/05.txt")).unwrap();
+    compare("day5", |cpu| {
+        for _ in 0..1000 {
+            cpu.reload(&day5);
+            cpu.push_input(5);
+            cpu.run_to_completion().unwrap();
+        }
+    });
+
+    let day2 = parse(include_str!("../../../inputs/2019/02.txt")).unwrap();
+    compare("day2", |cpu| {
+        for noun in 0..100 {
+            for verb in 0..100 {
+                cpu.reload(&day2);
+                cpu.write(1, noun).unwrap();
+                cpu.write(2, verb).unwrap();
+                cpu.run().unwrap();
+            }
+        }
+    });
+}
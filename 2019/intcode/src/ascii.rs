@@ -0,0 +1,185 @@
+//! Text interface for Intcode programs talking in ASCII.
+//!
+//! [`Ascii`] turns lines of text into input values and splits output into
+//! printable text and the values which are not ASCII, which is how the text
+//! based puzzles report their answers.
+
+use std::io::{self, BufRead, Write};
+
+use crate::{Error, CPU};
+
+/// Output gathered during a single run of the machine.
+#[derive(PartialEq, Debug, Default)]
+pub struct Reply {
+    pub text: String,
+    /// Output values outside of the ASCII range, in order of appearance.
+    pub values: Vec<i64>,
+}
+
+impl Reply {
+    fn from_output(output: Vec<i64>) -> Reply {
+        let mut reply = Reply::default();
+
+        for value in output {
+            if (0..128).contains(&value) {
+                reply.text.push(value as u8 as char);
+            } else {
+                reply.values.push(value);
+            }
+        }
+
+        reply
+    }
+}
+
+pub struct Ascii {
+    cpu: CPU,
+}
+
+impl Ascii {
+    pub fn new(cpu: CPU) -> Ascii {
+        Ascii { cpu }
+    }
+
+    pub fn cpu(&self) -> &CPU {
+        &self.cpu
+    }
+
+    pub fn into_inner(self) -> CPU {
+        self.cpu
+    }
+
+    /// Queues `line` followed by a newline. Non ASCII characters are
+    /// replaced with `?`, since the program couldn't make sense of them.
+    pub fn send_line(&mut self, line: &str) {
+        for c in line.chars() {
+            let c = if c.is_ascii() { c } else { '?' };
+            self.cpu.push_input(c as i64);
+        }
+
+        self.cpu.push_input('\n' as i64);
+    }
+
+    /// Runs the machine until it waits for the next line or terminates.
+    pub fn run(&mut self) -> Result<Reply, Error> {
+        self.cpu.run()?;
+
+        Ok(Reply::from_output(self.cpu.drain_output()))
+    }
+
+    /// Runs the machine, then sends it the lines of `script` one at a time,
+    /// waiting for the machine to ask for each of them. Lines are sent as
+    /// they are, empty ones included, without their line ending. Returns the
+    /// reply to the initial run followed by the reply to every line sent.
+    pub fn run_script(&mut self, script: &str) -> Result<Vec<Reply>, Error> {
+        let mut replies = vec![self.run()?];
+
+        for line in script.lines() {
+            if self.cpu.terminated() {
+                break;
+            }
+
+            self.send_line(line);
+            replies.push(self.run()?);
+        }
+
+        Ok(replies)
+    }
+
+    /// Plays the program on a terminal: prints its text to `output` and
+    /// sends it lines read from `input` until it terminates or `input` ends.
+    /// Returns every non ASCII value the program produced.
+    pub fn interact<R: BufRead, W: Write>(&mut self, input: R, mut output: W) -> io::Result<Vec<i64>> {
+        let mut values = Vec::new();
+        let mut lines = input.lines();
+
+        loop {
+            let reply = self.run().map_err(io::Error::other)?;
+            write!(output, "{}", reply.text)?;
+            for value in &reply.values {
+                writeln!(output, "[{}]", value)?;
+            }
+            output.flush()?;
+            values.extend(reply.values);
+
+            if self.cpu.terminated() {
+                return Ok(values);
+            }
+
+            match lines.next() {
+                Some(line) => self.send_line(&line?),
+                None => return Ok(values),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asm::assemble;
+
+    /// Prints a prompt, then echoes every line it reads upper cased, until it
+    /// reads an empty line. Finishes by outputting 1000.
+    const SHOUT: &str = "
+              out #62
+              out #10
+        loop: in [c]
+              eq [c], #10, [flag]
+              jt [flag], #newline
+              lt [c], #97, [flag]
+              jt [flag], #print
+              add [c], #-32, [c]
+        print: out [c]
+              add #0, #1, [seen]
+              jt #1, #loop
+        newline: jf [seen], #end
+              out #10
+              add #0, #0, [seen]
+              jt #1, #loop
+        end:  out #1000
+              hlt
+        c: data 0
+        flag: data 0
+        seen: data 0
+    ";
+
+    fn shout() -> Ascii {
+        let mut cpu = CPU::new();
+        cpu.load_rom(assemble(SHOUT).unwrap());
+        Ascii::new(cpu)
+    }
+
+    #[test]
+    fn send_and_receive() {
+        let mut ascii = shout();
+
+        assert_eq!(Reply { text: ">\n".to_string(), values: vec![] }, ascii.run().unwrap());
+        ascii.send_line("hello");
+        assert_eq!(Reply { text: "HELLO\n".to_string(), values: vec![] }, ascii.run().unwrap());
+        ascii.send_line("");
+        assert_eq!(Reply { text: String::new(), values: vec![1000] }, ascii.run().unwrap());
+        assert!(ascii.cpu().terminated());
+    }
+
+    #[test]
+    fn script() {
+        let mut ascii = shout();
+        let replies = ascii.run_script(" hi there\r\n\nbye\n").unwrap();
+
+        assert_eq!(3, replies.len());
+        assert_eq!(" HI THERE\n", replies[1].text);
+        assert_eq!(Reply { text: String::new(), values: vec![1000] }, replies[2]);
+        assert!(ascii.cpu().terminated());
+    }
+
+    #[test]
+    fn interactive() {
+        let mut ascii = shout();
+        let mut output = Vec::new();
+        let values = ascii.interact("abc\nd\n\n".as_bytes(), &mut output).unwrap();
+
+        assert_eq!(vec![1000], values);
+        assert_eq!(">\nABC\nD\n[1000]\n", String::from_utf8(output).unwrap());
+    }
+}
@@ -0,0 +1,459 @@
+//! Disassembler and assembler for Intcode programs.
+//!
+//! Listing produced by [`disassemble`] is valid input for [`assemble`], so a
+//! rom survives the round trip unchanged. Syntax of a single line is:
+//!
+//! ```text
+//! [address:] [label:] mnemonic operand, operand ; comment
+//! ```
+//!
+//! Operands are written as `[addr]` for position mode, `#value` for immediate
+//! mode and `[rb+offset]` for relative mode. Anywhere a number is expected a
+//! label can be used instead. Cells which do not decode into an instruction
+//! are written with the `data` directive, which takes any number of values.
+
+use std::collections::HashMap;
+use std::error;
+use std::fmt;
+
+use crate::split_opcode;
+
+/// Static description of an Intcode instruction.
+#[derive(PartialEq, Debug)]
+pub struct Op {
+    pub code: i64,
+    pub mnemonic: &'static str,
+    pub params: usize,
+    /// Whether the last parameter is an address the instruction writes to.
+    pub writes: bool,
+}
+
+pub const OPS: [Op; 10] = [
+    Op { code: 1, mnemonic: "add", params: 3, writes: true },
+    Op { code: 2, mnemonic: "mul", params: 3, writes: true },
+    Op { code: 3, mnemonic: "in", params: 1, writes: true },
+    Op { code: 4, mnemonic: "out", params: 1, writes: false },
+    Op { code: 5, mnemonic: "jt", params: 2, writes: false },
+    Op { code: 6, mnemonic: "jf", params: 2, writes: false },
+    Op { code: 7, mnemonic: "lt", params: 3, writes: true },
+    Op { code: 8, mnemonic: "eq", params: 3, writes: true },
+    Op { code: 9, mnemonic: "arb", params: 1, writes: false },
+    Op { code: 99, mnemonic: "hlt", params: 0, writes: false },
+];
+
+pub fn op_by_code(code: i64) -> Option<&'static Op> {
+    OPS.iter().find(|op| op.code == code)
+}
+
+pub fn op_by_mnemonic(mnemonic: &str) -> Option<&'static Op> {
+    OPS.iter().find(|op| op.mnemonic == mnemonic)
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Param {
+    Position(i64),
+    Immediate(i64),
+    Relative(i64),
+}
+
+impl Param {
+    fn mode(&self) -> i64 {
+        match self {
+            Param::Position(_) => 0,
+            Param::Immediate(_) => 1,
+            Param::Relative(_) => 2,
+        }
+    }
+
+    fn value(&self) -> i64 {
+        match *self {
+            Param::Position(v) | Param::Immediate(v) | Param::Relative(v) => v,
+        }
+    }
+}
+
+impl fmt::Display for Param {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Param::Position(addr) => write!(f, "[{}]", addr),
+            Param::Immediate(value) => write!(f, "#{}", value),
+            Param::Relative(offset) if offset < 0 => write!(f, "[rb{}]", offset),
+            Param::Relative(offset) => write!(f, "[rb+{}]", offset),
+        }
+    }
+}
+
+/// Single decoded instruction.
+#[derive(PartialEq, Debug)]
+pub struct Instruction {
+    pub op: &'static Op,
+    pub params: Vec<Param>,
+}
+
+impl Instruction {
+    /// Number of memory cells taken by the instruction.
+    pub fn size(&self) -> usize {
+        self.params.len() + 1
+    }
+
+    pub fn encode(&self) -> Vec<i64> {
+        let mut opcode = self.op.code;
+        let mut factor = 100;
+
+        for param in &self.params {
+            opcode += param.mode() * factor;
+            factor *= 10;
+        }
+
+        let mut cells = vec![opcode];
+        cells.extend(self.params.iter().map(|p| p.value()));
+
+        cells
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.op.mnemonic)?;
+
+        for (i, param) in self.params.iter().enumerate() {
+            let separator = if i == 0 { " " } else { ", " };
+            write!(f, "{}{}", separator, param)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Decodes instruction at `addr`. Returns `None` when the cell is not a valid
+/// opcode, including opcodes with modes set for parameters they don't have,
+/// so that decoded instructions always encode back into the same cells.
+pub fn decode(memory: &[i64], addr: usize) -> Option<Instruction> {
+    let opcode = *memory.get(addr)?;
+    if !(0..100000).contains(&opcode) {
+        return None;
+    }
+
+    let (third, second, first, code) = split_opcode(opcode);
+    let op = op_by_code(code)?;
+    let modes = [first, second, third];
+
+    if modes[op.params..].iter().any(|m| *m != 0) {
+        return None;
+    }
+
+    let mut params = Vec::with_capacity(op.params);
+    for (i, mode) in modes[..op.params].iter().enumerate() {
+        let value = *memory.get(addr + i + 1)?;
+        let param = match mode {
+            0 => Param::Position(value),
+            1 if !(op.writes && i == op.params - 1) => Param::Immediate(value),
+            2 => Param::Relative(value),
+            _ => return None,
+        };
+
+        params.push(param);
+    }
+
+    Some(Instruction { op, params })
+}
+
+/// Turns a rom into a listing with one instruction per line.
+pub fn disassemble(rom: &[i64]) -> String {
+    let mut listing = String::new();
+    let mut addr = 0;
+
+    while addr < rom.len() {
+        let (text, len) = match decode(rom, addr) {
+            Some(instruction) => (instruction.to_string(), instruction.size()),
+            None => (format!("data {}", rom[addr]), 1),
+        };
+
+        let raw: Vec<String> = rom[addr..addr + len].iter().map(|v| v.to_string()).collect();
+        listing.push_str(&format!("{:04}: {:<32} ; {}\n", addr, text, raw.join(",")));
+
+        addr += len;
+    }
+
+    listing
+}
+
+/// Joins rom into the comma separated form used by puzzle inputs.
+pub fn format_rom(rom: &[i64]) -> String {
+    let cells: Vec<String> = rom.iter().map(|v| v.to_string()).collect();
+    cells.join(",")
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct AsmError {
+    /// One based line number in the assembled source.
+    pub line: usize,
+    pub reason: String,
+}
+
+impl fmt::Display for AsmError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.reason)
+    }
+}
+
+impl error::Error for AsmError {}
+
+/// Operand whose value may still refer to a label.
+enum Operand<'a> {
+    Position(&'a str),
+    Immediate(&'a str),
+    Relative(i64, &'a str),
+}
+
+enum Statement<'a> {
+    Instruction(&'static Op, Vec<Operand<'a>>),
+    Data(Vec<&'a str>),
+}
+
+impl Statement<'_> {
+    fn len(&self) -> usize {
+        match self {
+            Statement::Instruction(op, _) => op.params + 1,
+            Statement::Data(values) => values.len(),
+        }
+    }
+}
+
+fn is_label(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {}
+        _ => return false,
+    }
+
+    chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn parse_operand(text: &str) -> Result<Operand<'_>, String> {
+    if let Some(value) = text.strip_prefix('#') {
+        return Ok(Operand::Immediate(value.trim()));
+    }
+
+    let inner = text
+        .strip_prefix('[')
+        .and_then(|t| t.strip_suffix(']'))
+        .ok_or_else(|| format!("malformed operand '{}'", text))?
+        .trim();
+
+    if let Some(offset) = inner.strip_prefix("rb") {
+        let offset = offset.trim();
+        if let Some(value) = offset.strip_prefix('+') {
+            return Ok(Operand::Relative(1, value.trim()));
+        } else if let Some(value) = offset.strip_prefix('-') {
+            return Ok(Operand::Relative(-1, value.trim()));
+        } else if offset.is_empty() {
+            return Ok(Operand::Relative(1, "0"));
+        }
+    }
+
+    Ok(Operand::Position(inner))
+}
+
+fn parse_statement(text: &str) -> Result<Statement<'_>, String> {
+    let (mnemonic, rest) = match text.find(char::is_whitespace) {
+        Some(i) => (&text[..i], text[i..].trim()),
+        None => (text, ""),
+    };
+
+    let operands: Vec<&str> = if rest.is_empty() {
+        Vec::new()
+    } else {
+        rest.split(',').map(|v| v.trim()).collect()
+    };
+
+    if mnemonic == "data" {
+        if operands.is_empty() {
+            return Err("data without values".to_string());
+        }
+        return Ok(Statement::Data(operands));
+    }
+
+    let op = op_by_mnemonic(mnemonic).ok_or_else(|| format!("unknown mnemonic '{}'", mnemonic))?;
+    if operands.len() != op.params {
+        return Err(format!(
+            "'{}' takes {} operands, got {}",
+            mnemonic,
+            op.params,
+            operands.len()
+        ));
+    }
+
+    let operands = operands
+        .into_iter()
+        .map(parse_operand)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if op.writes {
+        if let Some(Operand::Immediate(_)) = operands.last() {
+            return Err(format!("'{}' can't write to an immediate operand", mnemonic));
+        }
+    }
+
+    Ok(Statement::Instruction(op, operands))
+}
+
+fn resolve(value: &str, labels: &HashMap<&str, usize>) -> Result<i64, String> {
+    if is_label(value) {
+        return labels
+            .get(value)
+            .map(|addr| *addr as i64)
+            .ok_or_else(|| format!("undefined label '{}'", value));
+    }
+
+    value.parse().map_err(|_| format!("invalid value '{}'", value))
+}
+
+/// Turns a listing back into a rom. Labels are resolved in a second pass, so
+/// they can be referenced before they are defined.
+pub fn assemble(source: &str) -> Result<Vec<i64>, AsmError> {
+    let mut labels = HashMap::new();
+    let mut statements = Vec::new();
+    let mut addr = 0;
+
+    for (i, line) in source.lines().enumerate() {
+        let error = |reason| AsmError { line: i + 1, reason };
+        let mut text = line.split(';').next().unwrap().trim();
+
+        while let Some(colon) = text.find(':') {
+            let name = text[..colon].trim();
+
+            if is_label(name) {
+                if labels.insert(name, addr).is_some() {
+                    return Err(error(format!("label '{}' defined twice", name)));
+                }
+            } else if name.parse::<usize>().is_err() {
+                return Err(error(format!("invalid label '{}'", name)));
+            }
+
+            text = text[colon + 1..].trim();
+        }
+
+        if text.is_empty() {
+            continue;
+        }
+
+        let statement = parse_statement(text).map_err(error)?;
+        addr += statement.len();
+        statements.push((i + 1, statement));
+    }
+
+    let mut rom = Vec::with_capacity(addr);
+    for (line, statement) in statements {
+        let error = |reason| AsmError { line, reason };
+
+        match statement {
+            Statement::Instruction(op, operands) => {
+                let mut params = Vec::with_capacity(operands.len());
+
+                for operand in operands {
+                    let param = match operand {
+                        Operand::Position(v) => Param::Position(resolve(v, &labels).map_err(error)?),
+                        Operand::Immediate(v) => Param::Immediate(resolve(v, &labels).map_err(error)?),
+                        Operand::Relative(sign, v) => {
+                            Param::Relative(sign * resolve(v, &labels).map_err(error)?)
+                        }
+                    };
+
+                    params.push(param);
+                }
+
+                rom.extend(Instruction { op, params }.encode());
+            }
+            Statement::Data(values) => {
+                for value in values {
+                    rom.push(resolve(value, &labels).map_err(error)?);
+                }
+            }
+        }
+    }
+
+    Ok(rom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disassemble_listing() {
+        let rom = vec![1002, 4, 3, 4, 33, 109, -2, 99];
+        let listing = disassemble(&rom);
+        let lines: Vec<&str> = listing.lines().map(|l| l.split(';').next().unwrap().trim()).collect();
+
+        assert_eq!(
+            vec!["0000: mul [4], #3, [4]", "0004: data 33", "0005: arb #-2", "0007: hlt"],
+            lines
+        );
+    }
+
+    #[test]
+    fn disassemble_relative() {
+        let rom = vec![21201, -3, 7, 1];
+
+        assert_eq!("add [rb-3], #7, [rb+1]", decode(&rom, 0).unwrap().to_string());
+    }
+
+    #[test]
+    fn immediate_write_is_data() {
+        assert_eq!(None, decode(&[11101, 1, 1, 1], 0));
+    }
+
+    #[test]
+    fn unused_mode_is_data() {
+        assert_eq!(None, decode(&[1199], 0));
+    }
+
+    #[test]
+    fn round_trip() {
+        let rom = crate::parse(include_str!("../../../inputs/2019/05.txt")).unwrap();
+        let listing = disassemble(&rom);
+
+        assert_eq!(Ok(rom), assemble(&listing));
+    }
+
+    #[test]
+    fn assemble_with_labels() {
+        let source = "
+            ; echoes input until it reads a zero
+            loop:   in [value]
+                    jf [value], #end
+                    out [value]
+                    jt #1, #loop
+            end:    hlt
+            value:  data 0
+        ";
+        let rom = assemble(source).unwrap();
+
+        assert_eq!("3,11,1006,11,10,4,11,1105,1,0,99,0", format_rom(&rom));
+    }
+
+    #[test]
+    fn assemble_runs() {
+        let rom = assemble("in [rb+10]\nmul [rb+10], #2, [rb]\nout [rb]\nhlt").unwrap();
+        let mut cpu = crate::CPU::new();
+        cpu.load_rom(rom);
+        cpu.push_input(21);
+
+        assert_eq!(Ok(vec![42]), cpu.run_to_completion());
+    }
+
+    #[test]
+    fn undefined_label() {
+        let err = assemble("hlt\njt #1, #nowhere").unwrap_err();
+
+        assert_eq!(AsmError { line: 2, reason: "undefined label 'nowhere'".to_string() }, err);
+    }
+
+    #[test]
+    fn immediate_write() {
+        let err = assemble("add #1, #2, #3").unwrap_err();
+
+        assert_eq!(1, err.line);
+    }
+}
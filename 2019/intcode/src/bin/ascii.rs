@@ -0,0 +1,53 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::process;
+
+use aoc_core::parse_or_exit;
+use intcode::ascii::Ascii;
+use intcode::CPU;
+
+fn read(path: &str) -> String {
+    fs::read_to_string(path).unwrap_or_else(|e| {
+        eprintln!("can't read {}: {}", path, e);
+        process::exit(1);
+    })
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.is_empty() || args.len() > 2 {
+        eprintln!("usage: ascii <rom> [script]");
+        process::exit(1);
+    }
+
+    let mut cpu = CPU::new();
+    let rom = read(&args[0]);
+    cpu.load_rom(parse_or_exit(&rom, intcode::parse));
+    let mut ascii = Ascii::new(cpu);
+
+    // Replay the script first, then hand over to the terminal.
+    if let Some(path) = args.get(1) {
+        let replies = ascii.run_script(&read(path)).unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            process::exit(1);
+        });
+
+        for reply in replies {
+            print!("{}", reply.text);
+            for value in reply.values {
+                println!("[{}]", value);
+            }
+        }
+
+        if ascii.cpu().terminated() {
+            return;
+        }
+    }
+
+    let stdin = io::stdin();
+    if let Err(e) = ascii.interact(stdin.lock(), io::stdout()) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
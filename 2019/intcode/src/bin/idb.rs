@@ -0,0 +1,29 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::process;
+
+use aoc_core::parse_or_exit;
+use intcode::debugger::Debugger;
+use intcode::CPU;
+
+fn main() {
+    let path = match env::args().nth(1) {
+        Some(path) => path,
+        None => {
+            eprintln!("usage: idb <rom>");
+            process::exit(1);
+        }
+    };
+
+    let rom = fs::read_to_string(&path).unwrap_or_else(|e| {
+        eprintln!("can't read {}: {}", path, e);
+        process::exit(1);
+    });
+
+    let mut cpu = CPU::new();
+    cpu.load_rom(parse_or_exit(&rom, intcode::parse));
+
+    let stdin = io::stdin();
+    Debugger::new(cpu).repl(stdin.lock(), io::stdout()).unwrap();
+}
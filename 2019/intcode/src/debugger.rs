@@ -0,0 +1,517 @@
+//! Debugger driving a [`CPU`] one instruction at a time.
+//!
+//! [`Debugger`] can be used programmatically or through [`Debugger::repl`],
+//! which reads gdb-like commands line by line. Output values produced by the
+//! program are moved out of the machine's output queue after every step and
+//! can be collected with [`Debugger::take_outputs`].
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::io::{self, BufRead, Write};
+
+use crate::asm::{decode, op_by_mnemonic};
+use crate::{split_opcode, Error, CPU, MEMORY_LIMIT};
+
+/// Reason for which the debugger gave control back.
+#[derive(PartialEq, Debug)]
+pub enum Stop {
+    /// Instruction pointer reached a breakpoint set with [`Debugger::break_at`].
+    Breakpoint(usize),
+    /// Instruction about to be executed has an opcode set with
+    /// [`Debugger::break_on_opcode`].
+    Opcode { ip: usize, opcode: i64 },
+    /// Watched memory cell changed its value.
+    Watch { addr: usize, old: i64, new: i64 },
+    /// Program needs input. Push some to the machine and continue.
+    WaitingForInput,
+    /// A single step finished without hitting anything else.
+    Step,
+    Terminated,
+}
+
+pub struct Debugger {
+    cpu: CPU,
+    breakpoints: HashSet<usize>,
+    opcode_breakpoints: HashSet<i64>,
+    watches: HashMap<usize, i64>,
+    outputs: Vec<i64>,
+}
+
+impl Debugger {
+    pub fn new(cpu: CPU) -> Debugger {
+        Debugger {
+            cpu,
+            breakpoints: HashSet::new(),
+            opcode_breakpoints: HashSet::new(),
+            watches: HashMap::new(),
+            outputs: Vec::new(),
+        }
+    }
+
+    pub fn cpu(&self) -> &CPU {
+        &self.cpu
+    }
+
+    pub fn cpu_mut(&mut self) -> &mut CPU {
+        &mut self.cpu
+    }
+
+    pub fn into_inner(self) -> CPU {
+        self.cpu
+    }
+
+    pub fn break_at(&mut self, addr: usize) {
+        self.breakpoints.insert(addr);
+    }
+
+    /// Breaks before executing any instruction with given opcode, regardless
+    /// of its parameter modes.
+    pub fn break_on_opcode(&mut self, opcode: i64) {
+        self.opcode_breakpoints.insert(opcode);
+    }
+
+    pub fn remove_break_at(&mut self, addr: usize) -> bool {
+        self.breakpoints.remove(&addr)
+    }
+
+    pub fn remove_break_on_opcode(&mut self, opcode: i64) -> bool {
+        self.opcode_breakpoints.remove(&opcode)
+    }
+
+    pub fn watch(&mut self, addr: usize) {
+        let value = self.cpu.read(addr);
+        self.watches.insert(addr, value);
+    }
+
+    pub fn unwatch(&mut self, addr: usize) -> bool {
+        self.watches.remove(&addr).is_some()
+    }
+
+    pub fn peek(&self, addr: usize) -> i64 {
+        self.cpu.read(addr)
+    }
+
+    /// Patches memory. Watched cells are updated without reporting a change.
+    pub fn poke(&mut self, addr: usize, value: i64) -> Result<(), Error> {
+        self.cpu.write(addr, value)?;
+
+        if let Some(watched) = self.watches.get_mut(&addr) {
+            *watched = value;
+        }
+
+        Ok(())
+    }
+
+    pub fn input(&self) -> &VecDeque<i64> {
+        &self.cpu.input
+    }
+
+    /// Output values produced since the last call.
+    pub fn take_outputs(&mut self) -> Vec<i64> {
+        std::mem::take(&mut self.outputs)
+    }
+
+    /// Executes a single instruction, ignoring breakpoints.
+    pub fn step(&mut self) -> Result<Stop, Error> {
+        if self.cpu.terminated() {
+            return Ok(Stop::Terminated);
+        }
+
+        self.cpu.cycle()?;
+        self.outputs.extend(self.cpu.drain_output());
+
+        if let Some(stop) = self.check_watches() {
+            return Ok(stop);
+        }
+
+        if self.cpu.terminated() {
+            Ok(Stop::Terminated)
+        } else if self.cpu.waiting() {
+            Ok(Stop::WaitingForInput)
+        } else {
+            Ok(Stop::Step)
+        }
+    }
+
+    /// Runs until a breakpoint, a watch or termination. Breakpoint at the
+    /// current instruction is stepped over, so continuing after a break makes
+    /// progress.
+    pub fn cont(&mut self) -> Result<Stop, Error> {
+        let mut first = true;
+
+        loop {
+            if !first {
+                if let Some(stop) = self.check_breakpoints() {
+                    return Ok(stop);
+                }
+            }
+            first = false;
+
+            match self.step()? {
+                Stop::Step => continue,
+                stop => return Ok(stop),
+            }
+        }
+    }
+
+    fn check_breakpoints(&self) -> Option<Stop> {
+        let ip = self.cpu.ip();
+        if self.breakpoints.contains(&ip) {
+            return Some(Stop::Breakpoint(ip));
+        }
+
+        let opcode = self.cpu.read(ip);
+        let (_, _, _, instruction) = split_opcode(opcode);
+        if self.opcode_breakpoints.contains(&instruction) {
+            return Some(Stop::Opcode { ip, opcode });
+        }
+
+        None
+    }
+
+    fn check_watches(&mut self) -> Option<Stop> {
+        for (addr, old) in self.watches.iter_mut() {
+            let new = self.cpu.read(*addr);
+            if new != *old {
+                let stop = Stop::Watch { addr: *addr, old: *old, new };
+                *old = new;
+                return Some(stop);
+            }
+        }
+
+        None
+    }
+
+    /// Disassembles `count` instructions starting at `addr`.
+    pub fn list(&self, addr: usize, count: usize) -> Vec<String> {
+        let memory = self.cpu.memory();
+        let mut lines = Vec::new();
+        let mut addr = addr;
+
+        for _ in 0..count {
+            if addr >= memory.len() {
+                break;
+            }
+
+            let marker = if addr == self.cpu.ip() { "=>" } else { "  " };
+            let (text, size) = match decode(memory, addr) {
+                Some(instruction) => (instruction.to_string(), instruction.size()),
+                None => (format!("data {}", memory[addr]), 1),
+            };
+
+            lines.push(format!("{} {:04}: {}", marker, addr, text));
+            addr += size;
+        }
+
+        lines
+    }
+
+    /// Interactive command loop. Reads commands from `input` until `quit` or
+    /// end of input, writing results to `output`. Type `help` for the list of
+    /// commands.
+    pub fn repl<R: BufRead, W: Write>(&mut self, input: R, mut output: W) -> io::Result<()> {
+        write!(output, "(idb) ")?;
+        output.flush()?;
+
+        for line in input.lines() {
+            let line = line?;
+            let words: Vec<&str> = line.split_whitespace().collect();
+
+            if let Some(&"q") | Some(&"quit") = words.first() {
+                break;
+            }
+
+            if let Err(message) = self.command(&words, &mut output)? {
+                writeln!(output, "error: {}", message)?;
+            }
+
+            write!(output, "(idb) ")?;
+            output.flush()?;
+        }
+
+        writeln!(output)
+    }
+
+    fn command<W: Write>(&mut self, words: &[&str], output: &mut W) -> io::Result<Result<(), String>> {
+        let (command, args) = match words.split_first() {
+            Some((command, args)) => (*command, args),
+            None => return Ok(Ok(())),
+        };
+
+        let stop = match command {
+            "s" | "step" => {
+                let count = match parse_arg(args.first(), 1) {
+                    Ok(count) => count,
+                    Err(e) => return Ok(Err(e)),
+                };
+
+                let mut stop = Ok(Stop::Step);
+                for _ in 0..count {
+                    stop = self.step();
+                    if stop != Ok(Stop::Step) {
+                        break;
+                    }
+                }
+
+                stop
+            }
+            "c" | "continue" => self.cont(),
+            "b" | "break" => return Ok(self.set_breakpoint(args, true)),
+            "d" | "delete" => return Ok(self.set_breakpoint(args, false)),
+            "w" | "watch" | "unwatch" => {
+                let addr = match parse_arg(args.first(), None) {
+                    Ok(addr) => addr,
+                    Err(e) => return Ok(Err(e)),
+                };
+
+                if command == "unwatch" {
+                    self.unwatch(addr);
+                } else {
+                    self.watch(addr);
+                }
+
+                return Ok(Ok(()));
+            }
+            "x" | "examine" => {
+                let (addr, count): (usize, usize) = match (parse_arg(args.first(), None), parse_arg(args.get(1), 1)) {
+                    (Ok(addr), Ok(count)) => (addr, count),
+                    (Err(e), _) | (_, Err(e)) => return Ok(Err(e)),
+                };
+
+                let end = match addr.checked_add(count).filter(|&end| end <= MEMORY_LIMIT) {
+                    Some(end) => end,
+                    None => return Ok(Err(format!("memory ends at {}", MEMORY_LIMIT))),
+                };
+
+                for addr in addr..end {
+                    writeln!(output, "{:04}: {}", addr, self.peek(addr))?;
+                }
+
+                return Ok(Ok(()));
+            }
+            "set" => {
+                let (addr, value) = match (parse_arg(args.first(), None), parse_arg(args.get(1), None)) {
+                    (Ok(addr), Ok(value)) => (addr, value),
+                    (Err(e), _) | (_, Err(e)) => return Ok(Err(e)),
+                };
+
+                return Ok(self.poke(addr, value).map_err(|e| match e {
+                    Error::InvalidAddress { .. } => format!("invalid address {}, memory ends at {}", addr, MEMORY_LIMIT),
+                    e => e.to_string(),
+                }));
+            }
+            "push" => {
+                let values: Result<Vec<i64>, _> = args.iter().map(|v| v.parse()).collect();
+                match values {
+                    Ok(values) => self.cpu.input.extend(values),
+                    Err(_) => return Ok(Err("push takes a list of numbers".to_string())),
+                }
+
+                return Ok(Ok(()));
+            }
+            "i" | "input" => {
+                let values: Vec<String> = self.input().iter().map(|v| v.to_string()).collect();
+                writeln!(output, "input: [{}]", values.join(", "))?;
+                return Ok(Ok(()));
+            }
+            "l" | "list" => {
+                let (addr, count) = match (parse_arg(args.first(), self.cpu.ip()), parse_arg(args.get(1), 10)) {
+                    (Ok(addr), Ok(count)) => (addr, count),
+                    (Err(e), _) | (_, Err(e)) => return Ok(Err(e)),
+                };
+
+                for line in self.list(addr, count) {
+                    writeln!(output, "{}", line)?;
+                }
+
+                return Ok(Ok(()));
+            }
+            "r" | "regs" => {
+                writeln!(
+                    output,
+                    "ip: {} rb: {} state: {:?}",
+                    self.cpu.ip(),
+                    self.cpu.relative_base(),
+                    self.cpu.state()
+                )?;
+                return Ok(Ok(()));
+            }
+            "h" | "help" => {
+                writeln!(output, "{}", HELP)?;
+                return Ok(Ok(()));
+            }
+            _ => return Ok(Err(format!("unknown command '{}'", command))),
+        };
+
+        for value in self.take_outputs() {
+            writeln!(output, "output: {}", value)?;
+        }
+
+        match stop {
+            Ok(Stop::Breakpoint(addr)) => writeln!(output, "breakpoint at {}", addr)?,
+            Ok(Stop::Opcode { ip, opcode }) => writeln!(output, "opcode {} at {}", opcode, ip)?,
+            Ok(Stop::Watch { addr, old, new }) => {
+                writeln!(output, "watch {}: {} -> {}", addr, old, new)?
+            }
+            Ok(Stop::WaitingForInput) => writeln!(output, "waiting for input")?,
+            Ok(Stop::Step) => {}
+            Ok(Stop::Terminated) => writeln!(output, "terminated")?,
+            Err(e) => writeln!(output, "fault: {}", e)?,
+        }
+
+        for line in self.list(self.cpu.ip(), 1) {
+            writeln!(output, "{}", line)?;
+        }
+
+        Ok(Ok(()))
+    }
+
+    fn set_breakpoint(&mut self, args: &[&str], set: bool) -> Result<(), String> {
+        let arg = args.first().ok_or("missing address or mnemonic")?;
+
+        if let Ok(addr) = arg.parse() {
+            if set {
+                self.break_at(addr);
+            } else {
+                self.remove_break_at(addr);
+            }
+        } else {
+            let op = op_by_mnemonic(arg).ok_or_else(|| format!("unknown mnemonic '{}'", arg))?;
+            if set {
+                self.break_on_opcode(op.code);
+            } else {
+                self.remove_break_on_opcode(op.code);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_arg<T, D>(arg: Option<&&str>, default: D) -> Result<T, String>
+where
+    T: std::str::FromStr,
+    D: Into<Option<T>>,
+{
+    match (arg, default.into()) {
+        (Some(arg), _) => arg.parse().map_err(|_| format!("invalid argument '{}'", arg)),
+        (None, Some(default)) => Ok(default),
+        (None, None) => Err("missing argument".to_string()),
+    }
+}
+
+const HELP: &str = "\
+s, step [n]          execute n instructions (default 1)
+c, continue          run until breakpoint, watch or termination
+b, break <addr|op>   break at address or on mnemonic (e.g. `b 12`, `b out`)
+d, delete <addr|op>  remove breakpoint
+w, watch <addr>      stop when memory cell changes
+unwatch <addr>       stop watching memory cell
+x, examine <a> [n]   print n memory cells starting at a
+set <addr> <value>   patch memory cell
+push <values...>     append values to the input queue
+i, input             show the input queue
+l, list [addr] [n]   disassemble n instructions (default from ip)
+r, regs              show ip, relative base and state
+q, quit              leave the debugger";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asm::assemble;
+
+    fn debugger(source: &str) -> Debugger {
+        let mut cpu = CPU::new();
+        cpu.load_rom(assemble(source).unwrap());
+        Debugger::new(cpu)
+    }
+
+    const COUNTER: &str = "
+        loop: add [counter], #1, [counter]
+              out [counter]
+              lt [counter], #3, [flag]
+              jt [flag], #loop
+              hlt
+        counter: data 0
+        flag: data 0
+    ";
+
+    #[test]
+    fn step_collects_outputs() {
+        let mut dbg = debugger(COUNTER);
+
+        assert_eq!(Ok(Stop::Step), dbg.step());
+        assert_eq!(Ok(Stop::Step), dbg.step());
+        assert_eq!(vec![1], dbg.take_outputs());
+    }
+
+    #[test]
+    fn breakpoint_at_address() {
+        let mut dbg = debugger(COUNTER);
+        dbg.break_at(0);
+
+        assert_eq!(Ok(Stop::Breakpoint(0)), dbg.cont());
+        assert_eq!(Ok(Stop::Breakpoint(0)), dbg.cont());
+        dbg.remove_break_at(0);
+        assert_eq!(Ok(Stop::Terminated), dbg.cont());
+        assert_eq!(vec![1, 2, 3], dbg.take_outputs());
+    }
+
+    #[test]
+    fn breakpoint_on_opcode() {
+        let mut dbg = debugger(COUNTER);
+        dbg.break_on_opcode(4);
+
+        assert_eq!(Ok(Stop::Opcode { ip: 4, opcode: 4 }), dbg.cont());
+    }
+
+    #[test]
+    fn watch_reports_change() {
+        let mut dbg = debugger(COUNTER);
+        dbg.watch(14);
+
+        assert_eq!(Ok(Stop::Watch { addr: 14, old: 0, new: 1 }), dbg.cont());
+        dbg.unwatch(14);
+        dbg.poke(14, 10).unwrap();
+        assert_eq!(Ok(Stop::Terminated), dbg.cont());
+        assert_eq!(vec![10], dbg.take_outputs());
+    }
+
+    #[test]
+    fn waiting_for_input() {
+        let mut dbg = debugger("in [rb+10]\nout [rb+10]\nhlt");
+
+        assert_eq!(Ok(Stop::WaitingForInput), dbg.cont());
+        assert_eq!(Err(Error::MissingInput { ip: 0, opcode: 203 }), dbg.step());
+        dbg.cpu_mut().push_input(5);
+        assert_eq!(Ok(Stop::Terminated), dbg.cont());
+        assert_eq!(vec![5], dbg.take_outputs());
+    }
+
+    #[test]
+    fn repl_script() {
+        let mut dbg = debugger(COUNTER);
+        let script = "b out\nc\nx 14\nset 14 2\nd out\nc\ni\nq\n";
+        let mut output = Vec::new();
+        dbg.repl(script.as_bytes(), &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.contains("opcode 4 at 4"));
+        assert!(output.contains("0014: 1"));
+        assert!(output.contains("output: 2\noutput: 3\nterminated"));
+        assert!(output.contains("input: []"));
+    }
+
+    #[test]
+    fn repl_rejects_addresses_beyond_memory() {
+        let mut dbg = debugger(COUNTER);
+        let script = "set 18446744073709551615 1\nset 4000000000 1\nx 18446744073709551615 2\nq\n";
+        let mut output = Vec::new();
+        dbg.repl(script.as_bytes(), &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.contains("error: invalid address 18446744073709551615, memory ends at 16777216"));
+        assert!(output.contains("error: invalid address 4000000000, memory ends at 16777216"));
+        assert!(output.contains("error: memory ends at 16777216"));
+        assert_eq!(assemble(COUNTER).unwrap().len(), dbg.cpu().memory().len());
+    }
+}
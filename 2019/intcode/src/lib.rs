@@ -0,0 +1,769 @@
+//! Intcode computer shared by the 2019 puzzles.
+//!
+//! Typical usage is to [`parse`] the puzzle input, load it into a [`CPU`]
+//! with [`CPU::load_rom`], feed it with [`CPU::push_input`] and then either
+//! [`CPU::run`] it until it waits for more input and drain its output queue,
+//! drive it event by event with [`CPU::run_until_event`] or run it to the end
+//! with [`CPU::run_to_completion`]. A faulty program is reported with an
+//! [`Error`] instead of a panic.
+
+pub mod ascii;
+pub mod asm;
+pub mod debugger;
+pub mod network;
+pub mod search;
+pub mod snapshot;
+pub mod trace;
+
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::error;
+use std::fmt;
+
+use aoc_core::{parse_number, ParseError};
+use asm::{op_by_code, Param};
+use trace::{TraceEntry, Tracer};
+
+/// Parses comma separated Intcode program.
+pub fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+    input.trim().split(',').map(|v| parse_number(input, v.trim())).collect()
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub enum CPUState {
+    Running,
+    /// Stopped on an `in` instruction with an empty input queue. Cycling
+    /// again after pushing input executes the instruction.
+    WaitingForInput,
+    Terminated,
+}
+
+/// Reason for which [`CPU::run_until_event`] returned.
+#[derive(PartialEq, Debug)]
+pub enum Event {
+    Output(i64),
+    WaitingForInput,
+    Terminated,
+}
+
+/// Highest address a program is allowed to touch. Keeps a faulty program
+/// from growing memory until the process runs out of it.
+pub const MEMORY_LIMIT: usize = 1 << 24;
+
+/// Fault raised by the [`CPU`] instead of panicking. Every variant carries the
+/// instruction pointer at which the fault happened and, when an instruction
+/// was being executed, its raw opcode.
+#[derive(PartialEq, Debug, Clone)]
+pub enum Error {
+    UnknownOpcode { ip: usize, opcode: i64 },
+    UnknownParamMode { ip: usize, opcode: i64, mode: i64 },
+    InvalidAddress { ip: usize, opcode: i64, addr: i64 },
+    Overflow { ip: usize, opcode: i64 },
+    MissingInput { ip: usize, opcode: i64 },
+    NotRunning { ip: usize },
+}
+
+impl Error {
+    pub fn ip(&self) -> usize {
+        match *self {
+            Error::UnknownOpcode { ip, .. }
+            | Error::UnknownParamMode { ip, .. }
+            | Error::InvalidAddress { ip, .. }
+            | Error::Overflow { ip, .. }
+            | Error::MissingInput { ip, .. }
+            | Error::NotRunning { ip } => ip,
+        }
+    }
+
+    pub fn opcode(&self) -> Option<i64> {
+        match *self {
+            Error::UnknownOpcode { opcode, .. }
+            | Error::UnknownParamMode { opcode, .. }
+            | Error::InvalidAddress { opcode, .. }
+            | Error::Overflow { opcode, .. }
+            | Error::MissingInput { opcode, .. } => Some(opcode),
+            Error::NotRunning { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::UnknownOpcode { ip, opcode } => {
+                write!(f, "ip {}: unknown opcode {}", ip, opcode)
+            }
+            Error::UnknownParamMode { ip, opcode, mode } => {
+                write!(f, "ip {}: unknown param mode {} in opcode {}", ip, mode, opcode)
+            }
+            Error::InvalidAddress { ip, opcode, addr } => {
+                write!(f, "ip {}: invalid address {} in opcode {}", ip, addr, opcode)
+            }
+            Error::Overflow { ip, opcode } => {
+                write!(f, "ip {}: arithmetic overflow in opcode {}", ip, opcode)
+            }
+            Error::MissingInput { ip, opcode } => {
+                write!(f, "ip {}: opcode {} tried to get input but didnt get any", ip, opcode)
+            }
+            Error::NotRunning { ip } => write!(f, "ip {}: tried to cycle not running machine", ip),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone)]
+pub struct CPU {
+    ip: usize,
+    relative_base: i64,
+    memory: Vec<i64>,
+    pub input: VecDeque<i64>,
+    pub output: VecDeque<i64>,
+    state: CPUState,
+    tracer: Option<Tracer>,
+    cache: Option<Vec<Option<Decoded>>>,
+}
+
+/// Instruction decoded from memory, together with its raw operands.
+#[derive(Clone, Copy)]
+struct Decoded {
+    instruction: u8,
+    modes: [u8; 3],
+    params: [i64; 3],
+}
+
+impl Default for CPU {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CPU {
+    pub fn new() -> CPU {
+        let ip = 0;
+        let relative_base = 0;
+        let memory = Vec::new();
+        let input = VecDeque::new();
+        let output = VecDeque::new();
+        let state = CPUState::Running;
+        let tracer = None;
+        let cache = None;
+
+        CPU { ip, relative_base, memory, input, output, state, tracer, cache }
+    }
+
+    pub fn load_rom(&mut self, rom: Vec<i64>) {
+        self.memory = rom;
+        self.clear_cache();
+        self.input.clear();
+        self.output.clear();
+        self.ip = 0;
+        self.relative_base = 0;
+        self.state = CPUState::Running;
+    }
+
+    /// Resets the machine to a fresh copy of `rom`, like [`CPU::load_rom`].
+    /// Only cells which differ from `rom` are rewritten, so decode cache
+    /// entries of the code the previous run didn't modify stay valid. Makes
+    /// running many variants of one program cheaper.
+    pub fn reload(&mut self, rom: &[i64]) {
+        for addr in rom.len()..self.memory.len() {
+            if self.memory[addr] != 0 {
+                self.store(addr, 0);
+            }
+        }
+        self.memory.resize(rom.len(), 0);
+
+        for (addr, value) in rom.iter().enumerate() {
+            if self.memory[addr] != *value {
+                self.store(addr, *value);
+            }
+        }
+
+        self.input.clear();
+        self.output.clear();
+        self.ip = 0;
+        self.relative_base = 0;
+        self.state = CPUState::Running;
+    }
+
+    /// Enables or disables the decode cache. With the cache enabled every
+    /// instruction is decoded the first time it runs and kept until the
+    /// program writes over any of its cells; there is no pre-decode pass.
+    /// Pays off for programs which loop a lot, but straight line ones pay for
+    /// the bookkeeping: `benches/decode_cache.rs` measures the day 2 program
+    /// at x0.75 of the plain interpreter, so the cache is off by default.
+    pub fn set_decode_cache(&mut self, enabled: bool) {
+        self.cache = if enabled { Some(Vec::new()) } else { None };
+    }
+
+    pub(crate) fn clear_cache(&mut self) {
+        if let Some(cache) = self.cache.as_mut() {
+            cache.clear();
+        }
+    }
+
+    pub fn push_input(&mut self, value: i64) {
+        self.input.push_back(value);
+    }
+
+    pub fn ip(&self) -> usize {
+        self.ip
+    }
+
+    pub fn relative_base(&self) -> i64 {
+        self.relative_base
+    }
+
+    pub fn state(&self) -> CPUState {
+        self.state
+    }
+
+    /// Attaches a tracer which sees every executed instruction. Replaces the
+    /// previous one, if any.
+    pub fn set_tracer(&mut self, tracer: Tracer) {
+        self.tracer = Some(tracer);
+    }
+
+    pub fn tracer(&self) -> Option<&Tracer> {
+        self.tracer.as_ref()
+    }
+
+    /// Detaches the tracer.
+    pub fn take_tracer(&mut self) -> Option<Tracer> {
+        self.tracer.take()
+    }
+
+    /// Memory touched so far by the program. Cells past its end read as 0.
+    pub fn memory(&self) -> &[i64] {
+        &self.memory
+    }
+
+    /// Reads memory cell at `addr`. Cells outside of the loaded rom are 0.
+    pub fn read(&self, addr: usize) -> i64 {
+        self.memory.get(addr).copied().unwrap_or(0)
+    }
+
+    /// Writes `value` at `addr`, growing memory with zeroes if needed.
+    /// Addresses from [`MEMORY_LIMIT`] on are rejected like the program's own
+    /// writes.
+    pub fn write(&mut self, addr: usize, value: i64) -> Result<(), Error> {
+        let addr = self.check_addr(i64::try_from(addr).unwrap_or(i64::MAX))?;
+        self.store(addr, value);
+
+        Ok(())
+    }
+
+    /// Writes `value` at `addr`, which was already checked.
+    fn store(&mut self, addr: usize, value: i64) {
+        if addr >= self.memory.len() {
+            self.memory.resize(addr + 1, 0);
+        }
+
+        self.memory[addr] = value;
+
+        // Any instruction starting up to 3 cells before could include `addr`.
+        if let Some(cache) = self.cache.as_mut() {
+            let end = (addr + 1).min(cache.len());
+            let start = addr.saturating_sub(3).min(end);
+            for entry in &mut cache[start..end] {
+                *entry = None;
+            }
+        }
+    }
+
+    fn opcode(&self) -> i64 {
+        self.read(self.ip)
+    }
+
+    fn check_addr(&self, addr: i64) -> Result<usize, Error> {
+        if addr < 0 || addr as usize >= MEMORY_LIMIT {
+            return Err(Error::InvalidAddress { ip: self.ip, opcode: self.opcode(), addr });
+        }
+
+        Ok(addr as usize)
+    }
+
+    fn decode(&self) -> Result<Decoded, Error> {
+        let opcode = self.opcode();
+        let (t_mode, s_mode, f_mode, instruction) = split_opcode(opcode);
+
+        let (count, writes) = match instruction {
+            1 | 2 | 7 | 8 => (3, true),
+            3 => (1, true),
+            4 | 9 => (1, false),
+            5 | 6 => (2, false),
+            99 => (0, false),
+            _ => return Err(Error::UnknownOpcode { ip: self.ip, opcode }),
+        };
+
+        let modes = [f_mode, s_mode, t_mode];
+        let mut decoded = Decoded { instruction: instruction as u8, modes: [0; 3], params: [0; 3] };
+
+        for (i, mode) in modes[..count].iter().enumerate() {
+            match mode {
+                0 | 2 => {}
+                1 if !(writes && i == count - 1) => {}
+                _ => return Err(Error::UnknownParamMode { ip: self.ip, opcode, mode: *mode }),
+            }
+
+            decoded.modes[i] = *mode as u8;
+            decoded.params[i] = self.read(self.ip + i + 1);
+        }
+
+        Ok(decoded)
+    }
+
+    fn decode_cached(&mut self) -> Result<Decoded, Error> {
+        let ip = self.ip;
+        if let Some(Some(decoded)) = self.cache.as_ref().and_then(|cache| cache.get(ip)) {
+            return Ok(*decoded);
+        }
+
+        let decoded = self.decode()?;
+        if let Some(cache) = self.cache.as_mut() {
+            if cache.len() <= ip {
+                cache.resize(ip + 1, None);
+            }
+            cache[ip] = Some(decoded);
+        }
+
+        Ok(decoded)
+    }
+
+    /// Address the `i`-th parameter points to.
+    fn addr(&self, decoded: &Decoded, i: usize) -> Result<usize, Error> {
+        let addr = match decoded.modes[i] {
+            2 => self
+                .relative_base
+                .checked_add(decoded.params[i])
+                .ok_or(Error::Overflow { ip: self.ip, opcode: self.opcode() })?,
+            _ => decoded.params[i],
+        };
+
+        self.check_addr(addr)
+    }
+
+    /// Value of the `i`-th parameter.
+    fn param(&self, decoded: &Decoded, i: usize) -> Result<i64, Error> {
+        match decoded.modes[i] {
+            1 => Ok(decoded.params[i]),
+            _ => Ok(self.read(self.addr(decoded, i)?)),
+        }
+    }
+
+    pub fn cycle(&mut self) -> Result<(), Error> {
+        if self.tracer.is_none() {
+            return self.execute();
+        }
+
+        let entry = self.trace_entry();
+        self.execute()?;
+
+        if self.waiting() {
+            return Ok(());
+        }
+
+        if let Some(mut entry) = entry {
+            if let Some((addr, _)) = entry.write {
+                entry.write = Some((addr, self.read(addr)));
+            }
+
+            if let Some(tracer) = self.tracer.as_mut() {
+                tracer.record(entry);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Decodes and resolves operands of the instruction at `ip` before it is
+    /// executed. Returns `None` for instructions which would fault.
+    fn trace_entry(&self) -> Option<TraceEntry> {
+        let decoded = self.decode().ok()?;
+        let op = op_by_code(decoded.instruction as i64)?;
+
+        let mut params = Vec::with_capacity(op.params);
+        let mut values = Vec::with_capacity(op.params);
+        for i in 0..op.params {
+            let raw = decoded.params[i];
+
+            params.push(match decoded.modes[i] {
+                0 => Param::Position(raw),
+                1 => Param::Immediate(raw),
+                _ => Param::Relative(raw),
+            });
+
+            if op.writes && i == op.params - 1 {
+                values.push(self.addr(&decoded, i).ok()? as i64);
+            } else {
+                values.push(self.param(&decoded, i).ok()?);
+            }
+        }
+
+        let write = if op.writes { Some((*values.last()? as usize, 0)) } else { None };
+
+        Some(TraceEntry { ip: self.ip, opcode: self.opcode(), params, values, write })
+    }
+
+    fn execute(&mut self) -> Result<(), Error> {
+        match self.state {
+            CPUState::Running => {}
+            CPUState::WaitingForInput if !self.input.is_empty() => self.state = CPUState::Running,
+            CPUState::WaitingForInput => {
+                return Err(Error::MissingInput { ip: self.ip, opcode: self.opcode() })
+            }
+            CPUState::Terminated => return Err(Error::NotRunning { ip: self.ip }),
+        }
+
+        let decoded = if self.cache.is_some() { self.decode_cached()? } else { self.decode()? };
+        let instruction = decoded.instruction;
+        let overflow = Error::Overflow { ip: self.ip, opcode: self.opcode() };
+
+        match instruction {
+            1 | 2 | 7 | 8 => {
+                let first_param = self.param(&decoded, 0)?;
+                let second_param = self.param(&decoded, 1)?;
+
+                let addr = self.addr(&decoded, 2)?;
+
+                if instruction == 1 {
+                    let value = first_param.checked_add(second_param).ok_or(overflow)?;
+                    self.store(addr, value);
+                } else if instruction == 2 {
+                    let value = first_param.checked_mul(second_param).ok_or(overflow)?;
+                    self.store(addr, value);
+                } else if instruction == 7 {
+                    if first_param < second_param {
+                        self.store(addr, 1);
+                    } else {
+                        self.store(addr, 0);
+                    }
+                } else if instruction == 8 {
+                    if first_param == second_param {
+                        self.store(addr, 1);
+                    } else {
+                        self.store(addr, 0);
+                    }
+                }
+
+                self.ip += 4;
+            }
+            3 => {
+                let addr = self.addr(&decoded, 0)?;
+                match self.input.pop_front() {
+                    Some(value) => {
+                        self.store(addr, value);
+                        self.ip += 2;
+                    }
+                    None => self.state = CPUState::WaitingForInput,
+                }
+            }
+            4 => {
+                let value = self.param(&decoded, 0)?;
+                self.output.push_back(value);
+                self.ip += 2;
+            }
+            5 | 6 => {
+                let first_param = self.param(&decoded, 0)?;
+                let second_param = self.param(&decoded, 1)?;
+
+                if (instruction == 5 && first_param != 0) || (instruction == 6 && first_param == 0) {
+                    self.ip = self.check_addr(second_param)?;
+                } else {
+                    self.ip += 3;
+                }
+            }
+            9 => {
+                let offset = self.param(&decoded, 0)?;
+                self.relative_base = self.relative_base.checked_add(offset).ok_or(overflow)?;
+                self.ip += 2;
+            }
+            _ => self.state = CPUState::Terminated,
+        }
+
+        Ok(())
+    }
+
+    /// Runs the machine until it terminates or waits for input it doesn't
+    /// have. Everything it outputs meanwhile stays in the output queue.
+    pub fn run(&mut self) -> Result<(), Error> {
+        if self.waiting() && !self.input.is_empty() {
+            self.state = CPUState::Running;
+        }
+
+        while self.state == CPUState::Running {
+            self.cycle()?;
+        }
+
+        Ok(())
+    }
+
+    /// Runs the machine until it outputs a value, waits for input or
+    /// terminates. Values already in the output queue are returned first.
+    pub fn run_until_event(&mut self) -> Result<Event, Error> {
+        loop {
+            if let Some(value) = self.output.pop_front() {
+                return Ok(Event::Output(value));
+            }
+
+            match self.state {
+                CPUState::Terminated => return Ok(Event::Terminated),
+                CPUState::WaitingForInput if self.input.is_empty() => {
+                    return Ok(Event::WaitingForInput)
+                }
+                _ => self.cycle()?,
+            }
+        }
+    }
+
+    /// Runs the machine until it terminates, returning every output value.
+    /// Running out of input is reported as [`Error::MissingInput`].
+    pub fn run_to_completion(&mut self) -> Result<Vec<i64>, Error> {
+        self.run()?;
+
+        if self.waiting() {
+            return Err(Error::MissingInput { ip: self.ip, opcode: self.opcode() });
+        }
+
+        Ok(self.drain_output())
+    }
+
+    /// Takes every value from the output queue.
+    pub fn drain_output(&mut self) -> Vec<i64> {
+        self.output.drain(..).collect()
+    }
+
+    pub fn terminated(&self) -> bool { self.state == CPUState::Terminated }
+
+    pub fn waiting(&self) -> bool { self.state == CPUState::WaitingForInput }
+}
+
+pub(crate) fn split_opcode(opcode: i64) -> (i64, i64, i64, i64) {
+    let ins = opcode % 100;
+    let third = (opcode / 10000) % 10;
+    let second = (opcode / 1000) % 10;
+    let first = (opcode / 100) % 10;
+
+    (third, second, first, ins)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_collecting(rom: Vec<i64>) -> Vec<i64> {
+        let mut cpu = CPU::new();
+        cpu.load_rom(rom);
+        cpu.run_to_completion().unwrap()
+    }
+
+    #[test]
+    fn quine() {
+        let rom = vec![
+            109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+        ];
+
+        assert_eq!(rom.clone(), run_collecting(rom));
+    }
+
+    #[test]
+    fn large_multiplication() {
+        let rom = vec![1102, 34915192, 34915192, 7, 4, 7, 99, 0];
+
+        assert_eq!(vec![1219070632396864], run_collecting(rom));
+    }
+
+    #[test]
+    fn large_immediate() {
+        let rom = vec![104, 1125899906842624, 99];
+
+        assert_eq!(vec![1125899906842624], run_collecting(rom));
+    }
+
+    #[test]
+    fn write_grows_memory() {
+        let rom = vec![1101, 2, 3, 1000, 4, 1000, 99];
+
+        assert_eq!(vec![5], run_collecting(rom));
+    }
+
+    #[test]
+    fn caller_writes_are_limited() {
+        let mut cpu = CPU::new();
+        cpu.load_rom(vec![99]);
+
+        assert_eq!(Ok(()), cpu.write(MEMORY_LIMIT - 1, 7));
+        assert_eq!(7, cpu.read(MEMORY_LIMIT - 1));
+        assert_eq!(Err(Error::InvalidAddress { ip: 0, opcode: 99, addr: MEMORY_LIMIT as i64 }), cpu.write(MEMORY_LIMIT, 7));
+        assert_eq!(Err(Error::InvalidAddress { ip: 0, opcode: 99, addr: i64::MAX }), cpu.write(usize::MAX, 7));
+    }
+
+    #[test]
+    fn relative_base_write() {
+        // Sets relative base to 50, reads input into [base + 2], outputs it.
+        let rom = vec![109, 50, 203, 2, 4, 52, 99];
+        let mut cpu = CPU::new();
+        cpu.load_rom(rom);
+        cpu.input.push_back(42);
+        cpu.run().unwrap();
+
+        assert!(cpu.terminated());
+        assert_eq!(vec![42], cpu.drain_output());
+        assert_eq!(42, cpu.read(52));
+    }
+
+    #[test]
+    fn parse_trailing_newline() {
+        assert_eq!(Ok(vec![1, 0, -3, 99]), parse("1,0,-3,99\n"));
+        assert_eq!(Err(ParseError::new(1, 6, "expected a number, found '3x'")), parse("1,0, 3x,99\n"));
+    }
+
+    #[test]
+    fn run_until_event() {
+        // Outputs its input twice then terminates.
+        let rom = vec![3, 9, 4, 9, 4, 9, 99, 0, 0, 0];
+        let mut cpu = CPU::new();
+        cpu.load_rom(rom);
+        cpu.push_input(7);
+
+        assert_eq!(Ok(Event::Output(7)), cpu.run_until_event());
+        assert_eq!(Ok(Event::Output(7)), cpu.run_until_event());
+        assert_eq!(Ok(Event::Terminated), cpu.run_until_event());
+    }
+
+    #[test]
+    fn waiting_for_input() {
+        // Outputs the sum of two inputs.
+        let rom = vec![3, 11, 3, 12, 1, 11, 12, 11, 4, 11, 99, 0, 0];
+        let mut cpu = CPU::new();
+        cpu.load_rom(rom);
+        cpu.push_input(2);
+        cpu.run().unwrap();
+
+        assert_eq!(CPUState::WaitingForInput, cpu.state());
+        assert_eq!(2, cpu.ip());
+        assert_eq!(Ok(Event::WaitingForInput), cpu.run_until_event());
+        assert_eq!(Err(Error::MissingInput { ip: 2, opcode: 3 }), cpu.cycle());
+
+        cpu.push_input(3);
+        cpu.run().unwrap();
+
+        assert!(cpu.terminated());
+        assert_eq!(vec![5], cpu.drain_output());
+    }
+
+    #[test]
+    fn output_queue_keeps_every_value() {
+        let rom = vec![104, 1, 104, 2, 3, 0, 104, 3, 99];
+        let mut cpu = CPU::new();
+        cpu.load_rom(rom);
+        cpu.run().unwrap();
+
+        assert!(cpu.waiting());
+        assert_eq!(vec![1, 2], cpu.drain_output());
+        assert!(cpu.output.is_empty());
+    }
+
+    fn run_err(rom: Vec<i64>) -> Error {
+        let mut cpu = CPU::new();
+        cpu.load_rom(rom);
+        cpu.run_to_completion().unwrap_err()
+    }
+
+    #[test]
+    fn unknown_opcode() {
+        assert_eq!(Error::UnknownOpcode { ip: 4, opcode: 42 }, run_err(vec![1101, 1, 1, 0, 42]));
+    }
+
+    #[test]
+    fn unknown_param_mode() {
+        let err = run_err(vec![301, 0, 0, 0, 99]);
+
+        assert_eq!(Error::UnknownParamMode { ip: 0, opcode: 301, mode: 3 }, err);
+    }
+
+    #[test]
+    fn write_in_immediate_mode() {
+        let err = run_err(vec![11101, 0, 0, 0, 99]);
+
+        assert_eq!(Error::UnknownParamMode { ip: 0, opcode: 11101, mode: 1 }, err);
+    }
+
+    #[test]
+    fn negative_address() {
+        let err = run_err(vec![1, -1, 0, 0, 99]);
+
+        assert_eq!(Error::InvalidAddress { ip: 0, opcode: 1, addr: -1 }, err);
+    }
+
+    #[test]
+    fn address_above_limit() {
+        let err = run_err(vec![1101, 0, 0, 1 << 40, 99]);
+
+        assert_eq!(Error::InvalidAddress { ip: 0, opcode: 1101, addr: 1 << 40 }, err);
+    }
+
+    #[test]
+    fn overflow() {
+        let err = run_err(vec![1102, i64::MAX, 2, 0, 99]);
+
+        assert_eq!(Error::Overflow { ip: 0, opcode: 1102 }, err);
+    }
+
+    #[test]
+    fn missing_input() {
+        let err = run_err(vec![1101, 0, 0, 0, 3, 0, 99]);
+
+        assert_eq!(Error::MissingInput { ip: 4, opcode: 3 }, err);
+        assert_eq!(4, err.ip());
+        assert_eq!(Some(3), err.opcode());
+    }
+
+    #[test]
+    fn cycle_terminated() {
+        let mut cpu = CPU::new();
+        cpu.load_rom(vec![99]);
+        cpu.run().unwrap();
+        assert_eq!(Err(Error::NotRunning { ip: 0 }), cpu.cycle());
+    }
+
+    #[test]
+    fn error_message() {
+        let err = Error::UnknownOpcode { ip: 4, opcode: 42 };
+
+        assert_eq!("ip 4: unknown opcode 42", err.to_string());
+    }
+
+    /// Outputs `1 + 1`, then patches the second operand of that `add` to 10
+    /// and runs it again.
+    const SELF_MODIFYING: [i64; 23] = [
+        1101, 1, 1, 21, 4, 21, 1101, 0, 10, 2, 1005, 22, 20, 1101, 0, 1, 22, 1105, 1, 0, 99, 0, 0,
+    ];
+
+    #[test]
+    fn decode_cache_sees_writes() {
+        let mut cpu = CPU::new();
+        cpu.set_decode_cache(true);
+        cpu.load_rom(SELF_MODIFYING.to_vec());
+
+        assert_eq!(Ok(vec![2, 11]), cpu.run_to_completion());
+    }
+
+    #[test]
+    fn reload_resets_memory() {
+        let mut cpu = CPU::new();
+        cpu.set_decode_cache(true);
+
+        for _ in 0..2 {
+            cpu.reload(&SELF_MODIFYING);
+            cpu.write(40, 7).unwrap();
+            assert_eq!(Ok(vec![2, 11]), cpu.run_to_completion());
+        }
+
+        cpu.reload(&[104, 5, 99]);
+        assert_eq!(&[104, 5, 99], cpu.memory());
+        assert_eq!(Ok(vec![5]), cpu.run_to_completion());
+    }
+}
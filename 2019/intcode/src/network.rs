@@ -0,0 +1,482 @@
+//! Schedulers running several [`CPU`]s which talk to each other.
+//!
+//! [`Network`] connects machines with one way channels: every value output by
+//! a machine is appended to the input of each machine it is connected to.
+//! Chains, rings and broadcasts are built from the same primitive with
+//! [`Network::connect`]. [`PacketNetwork`] instead routes `(address, x, y)`
+//! packets between addressed machines which never block on input, with an
+//! optional NAT waking the network up when it goes idle.
+
+use std::error;
+use std::fmt;
+
+use crate::{Error, CPU};
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum NetworkError {
+    /// Every machine which hasn't terminated waits for input nobody will send.
+    Deadlock,
+    Fault { machine: usize, error: Error },
+    /// Link to or from a machine the network doesn't have.
+    NoMachine { machine: usize, machines: usize },
+}
+
+impl fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NetworkError::Deadlock => write!(f, "all machines are blocked on input"),
+            NetworkError::Fault { machine, error } => write!(f, "machine {}: {}", machine, error),
+            NetworkError::NoMachine { machine, machines } => {
+                write!(f, "no machine {} in a network of {}", machine, machines)
+            }
+        }
+    }
+}
+
+impl error::Error for NetworkError {}
+
+#[derive(PartialEq, Debug)]
+enum Yield {
+    Blocked,
+    Terminated,
+}
+
+/// Runs the machine until it needs input it doesn't have or terminates,
+/// appending everything it outputs to `outputs`.
+fn run_machine(cpu: &mut CPU, outputs: &mut Vec<i64>) -> Result<Yield, Error> {
+    cpu.run()?;
+    outputs.extend(cpu.drain_output());
+
+    if cpu.terminated() {
+        Ok(Yield::Terminated)
+    } else {
+        Ok(Yield::Blocked)
+    }
+}
+
+fn machines(rom: &[i64], n: usize) -> Vec<CPU> {
+    (0..n)
+        .map(|_| {
+            let mut cpu = CPU::new();
+            cpu.load_rom(rom.to_vec());
+            cpu
+        })
+        .collect()
+}
+
+pub struct Network {
+    machines: Vec<CPU>,
+    links: Vec<Vec<usize>>,
+    outputs: Vec<Vec<i64>>,
+}
+
+impl Network {
+    /// Network of unconnected machines, one per rom.
+    pub fn new(machines: Vec<CPU>) -> Network {
+        let n = machines.len();
+
+        Network { machines, links: vec![Vec::new(); n], outputs: vec![Vec::new(); n] }
+    }
+
+    /// `n` copies of `rom` where machine `i` feeds machine `i + 1`.
+    pub fn chain(rom: &[i64], n: usize) -> Network {
+        let mut network = Network::new(machines(rom, n));
+        for i in 1..n {
+            network.connect(i - 1, i).expect("chain links machines in the network");
+        }
+
+        network
+    }
+
+    /// Chain whose last machine feeds back into the first one.
+    pub fn ring(rom: &[i64], n: usize) -> Network {
+        let mut network = Network::chain(rom, n);
+        if n > 0 {
+            network.connect(n - 1, 0).expect("ring links machines in the network");
+        }
+
+        network
+    }
+
+    /// `n` copies of `rom` where machine 0 feeds every other machine.
+    pub fn broadcast(rom: &[i64], n: usize) -> Network {
+        let mut network = Network::new(machines(rom, n));
+        for i in 1..n {
+            network.connect(0, i).expect("broadcast links machines in the network");
+        }
+
+        network
+    }
+
+    /// Sends every value output by `from` to `to` as well.
+    pub fn connect(&mut self, from: usize, to: usize) -> Result<(), NetworkError> {
+        let machines = self.machines.len();
+        if let Some(&machine) = [from, to].iter().find(|&&machine| machine >= machines) {
+            return Err(NetworkError::NoMachine { machine, machines });
+        }
+
+        self.links[from].push(to);
+        Ok(())
+    }
+
+    pub fn push_input(&mut self, machine: usize, value: i64) {
+        self.machines[machine].push_input(value);
+    }
+
+    pub fn machine(&self, machine: usize) -> &CPU {
+        &self.machines[machine]
+    }
+
+    /// Every value output so far by `machine`, regardless of where it went.
+    pub fn outputs(&self, machine: usize) -> &[i64] {
+        &self.outputs[machine]
+    }
+
+    /// Runs machines in turns until all of them terminate.
+    pub fn run(&mut self) -> Result<(), NetworkError> {
+        loop {
+            let mut all_terminated = true;
+
+            for i in 0..self.machines.len() {
+                let mut produced = Vec::new();
+                let result = run_machine(&mut self.machines[i], &mut produced)
+                    .map_err(|error| NetworkError::Fault { machine: i, error })?;
+
+                for &to in &self.links[i] {
+                    self.machines[to].input.extend(&produced);
+                }
+                self.outputs[i].extend(produced);
+
+                if result != Yield::Terminated {
+                    all_terminated = false;
+                }
+            }
+
+            if all_terminated {
+                return Ok(());
+            }
+
+            let stuck = self.machines.iter().all(|cpu| cpu.terminated() || cpu.input.is_empty());
+            if stuck {
+                return Err(NetworkError::Deadlock);
+            }
+        }
+    }
+}
+
+/// What happened on a [`PacketNetwork`] during a round.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum PacketEvent {
+    /// Packet sent by machine `from`. Packets sent to an address without a
+    /// machine, other than the NAT, are dropped after being reported.
+    Sent { from: usize, to: i64, x: i64, y: i64 },
+    /// Network went idle and the NAT delivered its last packet to machine 0.
+    Wake { x: i64, y: i64 },
+}
+
+/// Rounds in a row without traffic after which a [`PacketNetwork`] counts as
+/// idle. Machines may poll an empty queue a few times before sending, so a
+/// single quiet round isn't enough.
+const IDLE_ROUNDS: usize = 2;
+
+pub struct PacketNetwork {
+    machines: Vec<CPU>,
+    pending: Vec<Vec<i64>>,
+    nat: Option<i64>,
+    nat_packet: Option<(i64, i64)>,
+    idle_rounds: usize,
+}
+
+impl PacketNetwork {
+    /// `n` copies of `rom`, each given its address as the first input.
+    pub fn new(rom: &[i64], n: usize) -> PacketNetwork {
+        let mut machines = machines(rom, n);
+        for (address, cpu) in machines.iter_mut().enumerate() {
+            cpu.push_input(address as i64);
+        }
+
+        PacketNetwork { machines, pending: vec![Vec::new(); n], nat: None, nat_packet: None, idle_rounds: 0 }
+    }
+
+    /// Packets sent to `address` are kept by a NAT, which sends the latest of
+    /// them to machine 0 whenever the network goes idle.
+    pub fn with_nat(mut self, address: i64) -> PacketNetwork {
+        self.nat = Some(address);
+        self
+    }
+
+    pub fn push_input(&mut self, machine: usize, value: i64) {
+        self.machines[machine].push_input(value);
+    }
+
+    /// Gives every running machine one turn. A machine is idle when it had
+    /// nothing to read at the start of its turn and sent nothing during it;
+    /// terminated machines are skipped and always count as idle. The network
+    /// is idle after [`IDLE_ROUNDS`] rounds in a row where every machine was.
+    pub fn round(&mut self) -> Result<Vec<PacketEvent>, NetworkError> {
+        let mut events = Vec::new();
+        let mut idle = true;
+
+        for i in 0..self.machines.len() {
+            let cpu = &mut self.machines[i];
+            if cpu.terminated() {
+                continue;
+            }
+
+            let starved = cpu.input.is_empty();
+            if starved {
+                cpu.push_input(-1);
+            }
+
+            let mut produced = Vec::new();
+            run_machine(cpu, &mut produced).map_err(|error| NetworkError::Fault { machine: i, error })?;
+
+            if !starved || !produced.is_empty() {
+                idle = false;
+            }
+
+            // Packets may be split between turns, so keep leftovers around.
+            let pending = &mut self.pending[i];
+            pending.extend(produced);
+
+            let complete = pending.len() - pending.len() % 3;
+            let packets: Vec<i64> = pending.drain(..complete).collect();
+
+            for packet in packets.chunks(3) {
+                let (to, x, y) = (packet[0], packet[1], packet[2]);
+                events.push(PacketEvent::Sent { from: i, to, x, y });
+
+                if Some(to) == self.nat {
+                    self.nat_packet = Some((x, y));
+                } else if to >= 0 && (to as usize) < self.machines.len() {
+                    self.machines[to as usize].input.extend(&[x, y]);
+                }
+            }
+        }
+
+        self.idle_rounds = if idle { self.idle_rounds + 1 } else { 0 };
+        if self.idle_rounds >= IDLE_ROUNDS {
+            self.idle_rounds = 0;
+            match (self.nat, self.nat_packet) {
+                (Some(_), Some((x, y))) if self.machines.first().is_some_and(|cpu| !cpu.terminated()) => {
+                    self.machines[0].input.extend(&[x, y]);
+                    events.push(PacketEvent::Wake { x, y });
+                }
+                _ => return Err(NetworkError::Deadlock),
+            }
+        }
+
+        Ok(events)
+    }
+
+    /// Runs rounds until `f` returns a value for one of the events.
+    pub fn run_until<T, F>(&mut self, mut f: F) -> Result<T, NetworkError>
+    where
+        F: FnMut(&PacketEvent) -> Option<T>,
+    {
+        loop {
+            for event in self.round()? {
+                if let Some(value) = f(&event) {
+                    return Ok(value);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asm::assemble;
+
+    /// Adds 1 to every value it reads and outputs it, forever.
+    const INCREMENT: &str = "
+        loop: in [value]
+              add [value], #1, [value]
+              out [value]
+              jt #1, #loop
+        value: data 0
+    ";
+
+    /// Reads a limit, then increments its input until it reaches it.
+    const COUNT_TO: &str = "
+              in [limit]
+        loop: in [value]
+              add [value], #1, [value]
+              out [value]
+              lt [value], [limit], [flag]
+              jt [flag], #loop
+              hlt
+        limit: data 0
+        value: data 0
+        flag: data 0
+    ";
+
+    #[test]
+    fn chain() {
+        let rom = assemble(COUNT_TO).unwrap();
+        let mut network = Network::chain(&rom, 3);
+        for i in 0..3 {
+            network.push_input(i, 1);
+        }
+        network.push_input(0, 0);
+        network.run().unwrap();
+
+        assert_eq!(&[1], network.outputs(0));
+        assert_eq!(&[3], network.outputs(2));
+    }
+
+    #[test]
+    fn ring() {
+        let rom = assemble(COUNT_TO).unwrap();
+        let mut network = Network::ring(&rom, 2);
+        network.push_input(0, 5);
+        network.push_input(1, 6);
+        network.push_input(0, 0);
+        network.run().unwrap();
+
+        assert_eq!(&[1, 3, 5], network.outputs(0));
+        assert_eq!(&[2, 4, 6], network.outputs(1));
+    }
+
+    #[test]
+    fn broadcast() {
+        let rom = assemble(COUNT_TO).unwrap();
+        let mut network = Network::broadcast(&rom, 3);
+        network.push_input(0, 1);
+        network.push_input(1, 1);
+        network.push_input(2, 1);
+        network.push_input(0, 10);
+        network.run().unwrap();
+
+        assert_eq!(&[12], network.outputs(1));
+        assert_eq!(&[12], network.outputs(2));
+    }
+
+    #[test]
+    fn deadlock() {
+        let rom = assemble(INCREMENT).unwrap();
+        let mut network = Network::ring(&rom, 2);
+
+        assert_eq!(Err(NetworkError::Deadlock), network.run());
+    }
+
+    #[test]
+    fn connect_outside_network() {
+        let rom = assemble(INCREMENT).unwrap();
+        let mut network = Network::chain(&rom, 2);
+
+        assert_eq!(Err(NetworkError::NoMachine { machine: 2, machines: 2 }), network.connect(0, 2));
+        assert_eq!(Err(NetworkError::NoMachine { machine: 5, machines: 2 }), network.connect(5, 1));
+    }
+
+    #[test]
+    fn fault() {
+        let mut network = Network::chain(&[42], 2);
+
+        let err = network.run().unwrap_err();
+        assert_eq!(NetworkError::Fault { machine: 0, error: Error::UnknownOpcode { ip: 0, opcode: 42 } }, err);
+    }
+
+    /// Machine 0 sends `(1, x, x)` for every x it reads, machine 1 forwards
+    /// every packet it gets to address 255. Every other input is dropped.
+    const ROUTER: &str = "
+              in [address]
+        loop: in [x]
+              eq [x], #-1, [flag]
+              jt [flag], #loop
+              jt [address], #forward
+              out #1
+              out [x]
+              out [x]
+              jt #1, #loop
+        forward: in [y]
+              out #255
+              out [x]
+              out [y]
+              jt #1, #loop
+        address: data 0
+        x: data 0
+        y: data 0
+        flag: data 0
+    ";
+
+    #[test]
+    fn packets() {
+        let rom = assemble(ROUTER).unwrap();
+        let mut network = PacketNetwork::new(&rom, 2).with_nat(255);
+        network.push_input(0, 7);
+
+        let first = network.run_until(|event| match *event {
+            PacketEvent::Sent { to: 255, y, .. } => Some(y),
+            _ => None,
+        });
+        assert_eq!(Ok(7), first);
+
+        let wake = network.run_until(|event| match *event {
+            PacketEvent::Wake { x, y } => Some((x, y)),
+            _ => None,
+        });
+        assert_eq!(Ok((7, 7)), wake);
+    }
+
+    #[test]
+    fn idle_without_nat() {
+        let rom = assemble(ROUTER).unwrap();
+        let mut network = PacketNetwork::new(&rom, 2);
+
+        assert_eq!(Err(NetworkError::Deadlock), network.run_until(|_| Some(())));
+    }
+
+    /// Polls its queue twice before sending `(255, 4, 2)`, then polls forever.
+    const SLOW_SENDER: &str = "
+              in [address]
+        poll: in [x]
+              add [polls], #1, [polls]
+              lt [polls], #2, [flag]
+              jt [flag], #poll
+              out #255
+              out #4
+              out #2
+        idle: in [x]
+              jt #1, #idle
+        address: data 0
+        x: data 0
+        polls: data 0
+        flag: data 0
+    ";
+
+    #[test]
+    fn one_quiet_round_is_not_idle() {
+        let rom = assemble(SLOW_SENDER).unwrap();
+        let mut network = PacketNetwork::new(&rom, 1);
+
+        let sent = network.run_until(|event| match *event {
+            PacketEvent::Sent { to: 255, x, y, .. } => Some((x, y)),
+            _ => None,
+        });
+        assert_eq!(Ok((4, 2)), sent);
+        assert_eq!(Err(NetworkError::Deadlock), network.run_until(|_| Some(())));
+    }
+
+    /// Machine 0 polls forever, every other machine halts right away.
+    const HALT_UNLESS_FIRST: &str = "
+              in [address]
+              jt [address], #end
+        loop: in [x]
+              jt #1, #loop
+        end:  hlt
+        address: data 0
+        x: data 0
+    ";
+
+    #[test]
+    fn terminated_machines_are_skipped() {
+        let rom = assemble(HALT_UNLESS_FIRST).unwrap();
+        let mut network = PacketNetwork::new(&rom, 2).with_nat(255);
+
+        assert_eq!(Err(NetworkError::Deadlock), network.run_until(|_| Some(())));
+        assert!(network.machines[1].terminated());
+        assert!(network.machines[1].input.is_empty());
+    }
+}
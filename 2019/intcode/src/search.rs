@@ -0,0 +1,333 @@
+//! Brute force search over variants of an Intcode program.
+//!
+//! Each [`Candidate`] patches a few memory cells of the rom and provides an
+//! input sequence. [`Search`] runs candidates on several threads and hands
+//! every finished machine to a check function, which decides whether it is a
+//! match. Candidates are handed out in order and [`Mode::First`] always
+//! reports the earliest matching candidate, no matter which thread found it.
+
+use std::error;
+use std::fmt;
+use std::ops::Range;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+use crate::{CPU, MEMORY_LIMIT};
+
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct Candidate {
+    /// `(address, value)` pairs written over the rom before running it.
+    pub patches: Vec<(usize, i64)>,
+    pub input: Vec<i64>,
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Mode {
+    First,
+    All,
+}
+
+#[derive(PartialEq, Debug)]
+pub struct SearchResult<T> {
+    /// Matches in candidate order.
+    pub matches: Vec<(Candidate, T)>,
+    /// Number of candidates which were run.
+    pub runs: usize,
+    /// Runs which faulted or exceeded the cycle limit.
+    pub faults: usize,
+}
+
+pub struct Search<'a> {
+    rom: &'a [i64],
+    threads: usize,
+    mode: Mode,
+    cycle_limit: Option<usize>,
+    decode_cache: bool,
+}
+
+impl<'a> Search<'a> {
+    pub fn new(rom: &'a [i64]) -> Search<'a> {
+        let threads = thread::available_parallelism().map_or(1, |n| n.get());
+
+        Search { rom, threads, mode: Mode::First, cycle_limit: None, decode_cache: false }
+    }
+
+    pub fn threads(mut self, threads: usize) -> Search<'a> {
+        self.threads = threads.max(1);
+        self
+    }
+
+    pub fn mode(mut self, mode: Mode) -> Search<'a> {
+        self.mode = mode;
+        self
+    }
+
+    /// Gives up on runs taking more than `limit` instructions, so programs
+    /// which never terminate for some candidates can still be searched.
+    pub fn cycle_limit(mut self, limit: usize) -> Search<'a> {
+        self.cycle_limit = Some(limit);
+        self
+    }
+
+    /// Runs candidates with the decode cache of [`CPU::set_decode_cache`].
+    /// Off by default: it only pays off for programs which loop, and costs
+    /// time on straight line ones like 2019 day 2.
+    pub fn decode_cache(mut self, enabled: bool) -> Search<'a> {
+        self.decode_cache = enabled;
+        self
+    }
+
+    /// Runs every candidate until it terminates or waits for input and calls
+    /// `check` with the resulting machine.
+    pub fn run<I, F, T>(&self, candidates: I, check: F) -> SearchResult<T>
+    where
+        I: IntoIterator<Item = Candidate>,
+        I::IntoIter: Send,
+        F: Fn(&Candidate, &mut CPU) -> Option<T> + Sync,
+        T: Send,
+    {
+        let candidates = Mutex::new(candidates.into_iter().enumerate());
+        let matches = Mutex::new(Vec::new());
+        let done = AtomicBool::new(false);
+        let runs = AtomicUsize::new(0);
+        let faults = AtomicUsize::new(0);
+
+        thread::scope(|scope| {
+            for _ in 0..self.threads {
+                scope.spawn(|| {
+                    let mut cpu = CPU::new();
+                    cpu.set_decode_cache(self.decode_cache);
+
+                    loop {
+                        if done.load(Ordering::Relaxed) {
+                            break;
+                        }
+
+                        let (index, candidate) = match candidates.lock().unwrap().next() {
+                            Some(next) => next,
+                            None => break,
+                        };
+
+                        runs.fetch_add(1, Ordering::Relaxed);
+                        if !self.execute(&mut cpu, &candidate) {
+                            faults.fetch_add(1, Ordering::Relaxed);
+                            continue;
+                        }
+
+                        if let Some(value) = check(&candidate, &mut cpu) {
+                            matches.lock().unwrap().push((index, candidate, value));
+
+                            if self.mode == Mode::First {
+                                done.store(true, Ordering::Relaxed);
+                            }
+                        }
+                    }
+                });
+            }
+        });
+
+        let mut matches = matches.into_inner().unwrap();
+        matches.sort_by_key(|(index, _, _)| *index);
+        if self.mode == Mode::First {
+            matches.truncate(1);
+        }
+
+        SearchResult {
+            matches: matches.into_iter().map(|(_, c, v)| (c, v)).collect(),
+            runs: runs.into_inner(),
+            faults: faults.into_inner(),
+        }
+    }
+
+    /// Loads the patched rom and runs it. Returns `false` on a fault.
+    fn execute(&self, cpu: &mut CPU, candidate: &Candidate) -> bool {
+        cpu.reload(self.rom);
+        for (addr, value) in &candidate.patches {
+            if cpu.write(*addr, *value).is_err() {
+                return false;
+            }
+        }
+        cpu.input.extend(&candidate.input);
+
+        match self.cycle_limit {
+            None => cpu.run().is_ok(),
+            Some(limit) => {
+                for _ in 0..limit {
+                    if cpu.terminated() || (cpu.waiting() && cpu.input.is_empty()) {
+                        return true;
+                    }
+
+                    if cpu.cycle().is_err() {
+                        return false;
+                    }
+                }
+
+                cpu.terminated() || cpu.waiting()
+            }
+        }
+    }
+}
+
+/// Every combination of values from `ranges`, the last range changing the
+/// fastest.
+fn product(ranges: Vec<Range<i64>>) -> impl Iterator<Item = Vec<i64>> {
+    let mut current: Option<Vec<i64>> = if ranges.iter().any(|r| r.is_empty()) {
+        None
+    } else {
+        Some(ranges.iter().map(|r| r.start).collect())
+    };
+
+    std::iter::from_fn(move || {
+        let item = current.clone()?;
+
+        let mut next = item.clone();
+        let mut i = next.len();
+        current = loop {
+            if i == 0 {
+                break None;
+            }
+            i -= 1;
+
+            next[i] += 1;
+            if next[i] < ranges[i].end {
+                break Some(next);
+            }
+            next[i] = ranges[i].start;
+        };
+
+        Some(item)
+    })
+}
+
+/// Patch specification which cannot be searched.
+#[derive(PartialEq, Debug, Clone)]
+pub enum PatchError {
+    /// Address at or beyond [`MEMORY_LIMIT`].
+    InvalidAddress(usize),
+    /// Every patched address needs exactly one range.
+    RangeCount { addrs: usize, ranges: usize },
+}
+
+impl fmt::Display for PatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PatchError::InvalidAddress(addr) => write!(f, "patched address {} is beyond memory", addr),
+            PatchError::RangeCount { addrs, ranges } => write!(f, "{} patched addresses but {} ranges", addrs, ranges),
+        }
+    }
+}
+
+impl error::Error for PatchError {}
+
+/// Candidates writing every combination of values from `ranges` to `addrs`,
+/// like the noun and verb of 2019 day 2.
+pub fn patches(addrs: &[usize], ranges: Vec<Range<i64>>) -> Result<impl Iterator<Item = Candidate>, PatchError> {
+    if addrs.len() != ranges.len() {
+        return Err(PatchError::RangeCount { addrs: addrs.len(), ranges: ranges.len() });
+    }
+    if let Some(&addr) = addrs.iter().find(|&&addr| addr >= MEMORY_LIMIT) {
+        return Err(PatchError::InvalidAddress(addr));
+    }
+    let addrs = addrs.to_vec();
+
+    Ok(product(ranges).map(move |values| Candidate {
+        patches: addrs.iter().copied().zip(values).collect(),
+        input: Vec::new(),
+    }))
+}
+
+/// Candidates feeding every combination of values from `ranges` as input.
+pub fn inputs(ranges: Vec<Range<i64>>) -> impl Iterator<Item = Candidate> {
+    product(ranges).map(|input| Candidate { patches: Vec::new(), input })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asm::assemble;
+
+    /// Outputs `[7] * [8]`.
+    const MULTIPLY: &str = "
+        mul [7], [8], [9]
+        out [9]
+        hlt
+        data 0, 0, 0
+    ";
+
+    fn output_is(expected: i64) -> impl Fn(&Candidate, &mut CPU) -> Option<i64> + Sync {
+        move |_, cpu| cpu.output.front().copied().filter(|v| *v == expected)
+    }
+
+    #[test]
+    fn product_order() {
+        let all: Vec<Vec<i64>> = product(vec![0..2, 5..7]).collect();
+
+        assert_eq!(vec![vec![0, 5], vec![0, 6], vec![1, 5], vec![1, 6]], all);
+        assert_eq!(0, product(vec![0..2, 0..0]).count());
+    }
+
+    #[test]
+    fn first_match_is_earliest() {
+        let rom = assemble(MULTIPLY).unwrap();
+
+        for threads in 1..5 {
+            let result = Search::new(&rom).threads(threads).run(patches(&[7, 8], vec![1..10, 1..10]).unwrap(), output_is(12));
+
+            assert_eq!(1, result.matches.len());
+            assert_eq!(vec![(7, 2), (8, 6)], result.matches[0].0.patches);
+            assert!(result.runs >= 15);
+        }
+    }
+
+    #[test]
+    fn all_matches() {
+        let rom = assemble(MULTIPLY).unwrap();
+        let result = Search::new(&rom).mode(Mode::All).run(patches(&[7, 8], vec![1..10, 1..10]).unwrap(), output_is(12));
+        let found: Vec<Vec<(usize, i64)>> = result.matches.into_iter().map(|(c, _)| c.patches).collect();
+
+        assert_eq!(4, found.len());
+        assert_eq!(vec![(7, 6), (8, 2)], found[3]);
+        assert_eq!(81, result.runs);
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn invalid_patches() {
+        assert_eq!(Some(PatchError::InvalidAddress(usize::MAX)), patches(&[usize::MAX], vec![0..1]).err());
+        assert_eq!(Some(PatchError::RangeCount { addrs: 2, ranges: 1 }), patches(&[1, 2], vec![0..1]).err());
+    }
+
+    #[test]
+    fn input_search() {
+        let rom = assemble("in [rb+20]\nin [rb+21]\nadd [rb+20], [rb+21], [rb+22]\nout [rb+22]\nhlt").unwrap();
+        let result = Search::new(&rom).mode(Mode::All).run(inputs(vec![0..3, 0..3]), output_is(4));
+
+        let found: Vec<Vec<i64>> = result.matches.into_iter().map(|(c, _)| c.input).collect();
+        assert_eq!(vec![vec![2, 2]], found);
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn faults_and_cycle_limit() {
+        // Loops forever when the input is 0, faults when it's 2.
+        let rom = assemble("
+            in [x]
+            eq [x], #2, [flag]
+            jt [flag], #bad
+            loop: jf [x], #loop
+            hlt
+            bad: data 42
+            x: data 0
+            flag: data 0
+        ").unwrap();
+
+        let result = Search::new(&rom).mode(Mode::All).cycle_limit(1000).decode_cache(true).run(inputs(vec![0..3]), |c, cpu| {
+            Some(c.input[0]).filter(|_| cpu.terminated())
+        });
+
+        assert_eq!(3, result.runs);
+        assert_eq!(2, result.faults);
+        assert_eq!(1, result.matches[0].1);
+    }
+}
@@ -0,0 +1,272 @@
+//! Saving and restoring the state of a [`CPU`].
+//!
+//! A [`Snapshot`] holds everything needed to continue execution later:
+//! memory, instruction pointer, relative base, state and both queues. It can
+//! be written to disk in a small line based text format:
+//!
+//! ```text
+//! ip 4
+//! rb 0
+//! state waiting
+//! input
+//! output 1,2
+//! memory 104,1,104,2,3,0,99
+//! ```
+
+use std::collections::hash_map::DefaultHasher;
+use std::error;
+use std::fmt;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::path::Path;
+
+use crate::{CPUState, CPU};
+
+#[derive(PartialEq, Eq, Debug, Clone, Hash)]
+pub struct Snapshot {
+    pub ip: usize,
+    pub relative_base: i64,
+    pub state: CPUState,
+    pub input: Vec<i64>,
+    pub output: Vec<i64>,
+    pub memory: Vec<i64>,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct SnapshotError {
+    /// One based line number in the parsed text.
+    pub line: usize,
+    pub reason: String,
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.reason)
+    }
+}
+
+impl error::Error for SnapshotError {}
+
+fn join(values: &[i64]) -> String {
+    let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+    values.join(",")
+}
+
+fn split(values: &str) -> Result<Vec<i64>, String> {
+    if values.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    values
+        .split(',')
+        .map(|v| v.trim().parse().map_err(|_| format!("invalid value '{}'", v)))
+        .collect()
+}
+
+impl Snapshot {
+    pub fn to_text(&self) -> String {
+        let state = match self.state {
+            CPUState::Running => "running",
+            CPUState::WaitingForInput => "waiting",
+            CPUState::Terminated => "terminated",
+        };
+
+        let lines = [
+            ("ip", self.ip.to_string()),
+            ("rb", self.relative_base.to_string()),
+            ("state", state.to_string()),
+            ("input", join(&self.input)),
+            ("output", join(&self.output)),
+            ("memory", join(&self.memory)),
+        ];
+
+        let mut text = String::new();
+        for (key, value) in lines.iter() {
+            text.push_str(format!("{} {}", key, value).trim_end());
+            text.push('\n');
+        }
+
+        text
+    }
+
+    pub fn from_text(text: &str) -> Result<Snapshot, SnapshotError> {
+        const KEYS: [&str; 6] = ["ip", "rb", "state", "input", "output", "memory"];
+        let lines: Vec<&str> = text.lines().collect();
+
+        if lines.len() != KEYS.len() {
+            let reason = format!("expected {} lines, got {}", KEYS.len(), lines.len());
+            return Err(SnapshotError { line: lines.len().min(KEYS.len()) + 1, reason });
+        }
+
+        let mut values = Vec::with_capacity(KEYS.len());
+        for (i, (line, key)) in lines.iter().zip(KEYS.iter()).enumerate() {
+            let (name, value) = match line.find(' ') {
+                Some(space) => (&line[..space], line[space + 1..].trim()),
+                None => (line.trim(), ""),
+            };
+
+            if name != *key {
+                let reason = format!("expected '{}', got '{}'", key, name);
+                return Err(SnapshotError { line: i + 1, reason });
+            }
+
+            values.push(value);
+        }
+
+        let error = |line: usize| move |reason| SnapshotError { line, reason };
+        let number = |line: usize| {
+            values[line - 1]
+                .parse::<i64>()
+                .map_err(|_| SnapshotError { line, reason: format!("invalid number '{}'", values[line - 1]) })
+        };
+
+        let ip = number(1)?;
+        if ip < 0 {
+            return Err(SnapshotError { line: 1, reason: format!("negative ip {}", ip) });
+        }
+
+        let state = match values[2] {
+            "running" => CPUState::Running,
+            "waiting" => CPUState::WaitingForInput,
+            "terminated" => CPUState::Terminated,
+            other => return Err(SnapshotError { line: 3, reason: format!("unknown state '{}'", other) }),
+        };
+
+        Ok(Snapshot {
+            ip: ip as usize,
+            relative_base: number(2)?,
+            state,
+            input: split(values[3]).map_err(error(4))?,
+            output: split(values[4]).map_err(error(5))?,
+            memory: split(values[5]).map_err(error(6))?,
+        })
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_text())
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Snapshot> {
+        let text = fs::read_to_string(path)?;
+        Snapshot::from_text(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+impl CPU {
+    /// Copies the machine state. The tracer, if any, is not part of it.
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            ip: self.ip,
+            relative_base: self.relative_base,
+            state: self.state,
+            input: self.input.iter().copied().collect(),
+            output: self.output.iter().copied().collect(),
+            memory: self.memory.clone(),
+        }
+    }
+
+    /// Brings the machine back to the state from `snapshot`. The tracer, if
+    /// any, stays attached.
+    pub fn restore(&mut self, snapshot: &Snapshot) {
+        self.ip = snapshot.ip;
+        self.relative_base = snapshot.relative_base;
+        self.state = snapshot.state;
+        self.input = snapshot.input.iter().copied().collect();
+        self.output = snapshot.output.iter().copied().collect();
+        self.memory = snapshot.memory.clone();
+        self.clear_cache();
+    }
+
+    /// Hash of the machine state, equal for machines which behave the same.
+    /// Memory is hashed without trailing zeroes, since cells past the end of
+    /// memory read as 0 anyway.
+    pub fn state_hash(&self) -> u64 {
+        let end = self.memory.iter().rposition(|v| *v != 0).map_or(0, |i| i + 1);
+        let mut hasher = DefaultHasher::new();
+
+        self.ip.hash(&mut hasher);
+        self.relative_base.hash(&mut hasher);
+        self.state.hash(&mut hasher);
+        self.input.hash(&mut hasher);
+        self.output.hash(&mut hasher);
+        self.memory[..end].hash(&mut hasher);
+
+        hasher.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROM: [i64; 7] = [104, 1, 104, 2, 3, 0, 99];
+
+    fn waiting() -> CPU {
+        let mut cpu = CPU::new();
+        cpu.load_rom(ROM.to_vec());
+        cpu.run().unwrap();
+        cpu
+    }
+
+    #[test]
+    fn restore_and_branch() {
+        let mut cpu = waiting();
+        let snapshot = cpu.snapshot();
+
+        cpu.push_input(7);
+        cpu.run().unwrap();
+        assert!(cpu.terminated());
+        assert_eq!(7, cpu.read(0));
+
+        cpu.restore(&snapshot);
+        assert!(cpu.waiting());
+        cpu.push_input(8);
+        cpu.run().unwrap();
+        assert_eq!(8, cpu.read(0));
+        assert_eq!(vec![1, 2], cpu.drain_output());
+    }
+
+    #[test]
+    fn text_round_trip() {
+        let snapshot = waiting().snapshot();
+        let text = snapshot.to_text();
+
+        assert_eq!("ip 4\nrb 0\nstate waiting\ninput\noutput 1,2\nmemory 104,1,104,2,3,0,99\n", text);
+        assert_eq!(Ok(snapshot), Snapshot::from_text(&text));
+    }
+
+    #[test]
+    fn file_round_trip() {
+        let snapshot = waiting().snapshot();
+        let path = std::env::temp_dir().join(format!("intcode-snapshot-{}", std::process::id()));
+
+        snapshot.save(&path).unwrap();
+        let loaded = Snapshot::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(snapshot, loaded);
+    }
+
+    #[test]
+    fn malformed_text() {
+        let text = "ip 4\nrb 0\nstate sleeping\ninput\noutput\nmemory 99\n";
+        let err = Snapshot::from_text(text).unwrap_err();
+
+        assert_eq!(SnapshotError { line: 3, reason: "unknown state 'sleeping'".to_string() }, err);
+        assert_eq!(6, Snapshot::from_text("ip 4\nrb 0\nstate running\ninput\noutput\nmemory 1,x\n").unwrap_err().line);
+    }
+
+    #[test]
+    fn hash_ignores_trailing_zeroes() {
+        let mut a = waiting();
+        let b = waiting();
+        assert_eq!(a.state_hash(), b.state_hash());
+
+        a.write(100, 0).unwrap();
+        assert_eq!(a.state_hash(), b.state_hash());
+
+        a.write(100, 1).unwrap();
+        assert_ne!(a.state_hash(), b.state_hash());
+    }
+}
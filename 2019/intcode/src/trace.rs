@@ -0,0 +1,216 @@
+//! Execution tracing and profiling for the [`CPU`](crate::CPU).
+//!
+//! A [`Tracer`] attached with [`CPU::set_tracer`](crate::CPU::set_tracer)
+//! sees every successfully executed instruction. It always keeps hit counts
+//! per address and per opcode, and when created with [`Tracer::recording`]
+//! it also keeps the full list of [`TraceEntry`] values, which can be compared
+//! between two runs with [`diff`].
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::asm::{op_by_code, Param};
+
+/// Single executed instruction.
+#[derive(PartialEq, Debug, Clone)]
+pub struct TraceEntry {
+    pub ip: usize,
+    pub opcode: i64,
+    /// Operands as they are encoded in memory.
+    pub params: Vec<Param>,
+    /// Values the operands resolved to. For the written operand this is the
+    /// target address.
+    pub values: Vec<i64>,
+    /// Address and value written by the instruction, if any.
+    pub write: Option<(usize, i64)>,
+}
+
+impl fmt::Display for TraceEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let op = op_by_code(self.opcode % 100);
+        let mnemonic = op.map_or("???", |op| op.mnemonic);
+        let writes = op.is_some_and(|op| op.writes);
+        write!(f, "{:04}: {}", self.ip, mnemonic)?;
+
+        for (i, (param, value)) in self.params.iter().zip(&self.values).enumerate() {
+            let separator = if i == 0 { " " } else { ", " };
+            let target = writes && i == self.params.len() - 1;
+            match param {
+                Param::Immediate(_) => write!(f, "{}{}", separator, param)?,
+                _ if target => write!(f, "{}{}", separator, param)?,
+                _ => write!(f, "{}{}={}", separator, param, value)?,
+            }
+        }
+
+        if let Some((addr, value)) = self.write {
+            write!(f, " -> [{}]={}", addr, value)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Default, Clone)]
+pub struct Tracer {
+    record: bool,
+    entries: Vec<TraceEntry>,
+    address_hits: HashMap<usize, u64>,
+    opcode_hits: HashMap<i64, u64>,
+}
+
+impl Tracer {
+    /// Tracer which only counts hits. Cheap enough for long running programs.
+    pub fn counting() -> Tracer {
+        Tracer::default()
+    }
+
+    /// Tracer which additionally keeps every executed instruction.
+    pub fn recording() -> Tracer {
+        Tracer { record: true, ..Tracer::default() }
+    }
+
+    pub(crate) fn record(&mut self, entry: TraceEntry) {
+        *self.address_hits.entry(entry.ip).or_insert(0) += 1;
+        *self.opcode_hits.entry(entry.opcode % 100).or_insert(0) += 1;
+
+        if self.record {
+            self.entries.push(entry);
+        }
+    }
+
+    pub fn entries(&self) -> &[TraceEntry] {
+        &self.entries
+    }
+
+    pub fn address_hits(&self) -> &HashMap<usize, u64> {
+        &self.address_hits
+    }
+
+    pub fn opcode_hits(&self) -> &HashMap<i64, u64> {
+        &self.opcode_hits
+    }
+
+    pub fn total(&self) -> u64 {
+        self.opcode_hits.values().sum()
+    }
+
+    /// Human readable profile with `top` hottest addresses and totals for
+    /// every opcode seen.
+    pub fn report(&self, top: usize) -> String {
+        let mut report = format!("executed {} instructions\n", self.total());
+
+        let mut addresses: Vec<(&usize, &u64)> = self.address_hits.iter().collect();
+        addresses.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+
+        report.push_str("hottest addresses:\n");
+        for (addr, hits) in addresses.into_iter().take(top) {
+            report.push_str(&format!("  {:04}: {}\n", addr, hits));
+        }
+
+        let mut opcodes: Vec<(&i64, &u64)> = self.opcode_hits.iter().collect();
+        opcodes.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+
+        report.push_str("opcodes:\n");
+        for (opcode, hits) in opcodes {
+            let mnemonic = op_by_code(*opcode).map_or("???", |op| op.mnemonic);
+            report.push_str(&format!("  {:<4} {}\n", mnemonic, hits));
+        }
+
+        report
+    }
+}
+
+/// Index of the first entry at which two traces differ, or `None` when they
+/// are identical. A trace which is a prefix of the other differs at the end
+/// of the shorter one.
+pub fn diff(a: &[TraceEntry], b: &[TraceEntry]) -> Option<usize> {
+    match a.iter().zip(b).position(|(x, y)| x != y) {
+        Some(index) => Some(index),
+        None if a.len() != b.len() => Some(a.len().min(b.len())),
+        None => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asm::assemble;
+    use crate::CPU;
+
+    const COUNTER: &str = "
+        loop: add [counter], #1, [counter]
+              out [counter]
+              lt [counter], #3, [flag]
+              jt [flag], #loop
+              hlt
+        counter: data 0
+        flag: data 0
+    ";
+
+    fn traced(source: &str) -> Tracer {
+        let mut cpu = CPU::new();
+        cpu.load_rom(assemble(source).unwrap());
+        cpu.set_tracer(Tracer::recording());
+        cpu.run_to_completion().unwrap();
+
+        cpu.take_tracer().unwrap()
+    }
+
+    #[test]
+    fn records_entries() {
+        let tracer = traced(COUNTER);
+        let entries = tracer.entries();
+
+        assert_eq!(13, entries.len());
+        assert_eq!("0000: add [14]=0, #1, [14] -> [14]=1", entries[0].to_string());
+        assert_eq!("0004: out [14]=1", entries[1].to_string());
+        assert_eq!("0006: lt [14]=1, #3, [15] -> [15]=1", entries[2].to_string());
+        assert_eq!("0013: hlt", entries[12].to_string());
+    }
+
+    #[test]
+    fn counts_hits() {
+        let tracer = traced(COUNTER);
+
+        assert_eq!(13, tracer.total());
+        assert_eq!(Some(&3), tracer.address_hits().get(&0));
+        assert_eq!(Some(&1), tracer.address_hits().get(&13));
+        assert_eq!(Some(&3), tracer.opcode_hits().get(&7));
+        assert!(tracer.report(1).contains("  0000: 3\n"));
+    }
+
+    #[test]
+    fn counting_keeps_no_entries() {
+        let mut cpu = CPU::new();
+        cpu.load_rom(assemble(COUNTER).unwrap());
+        cpu.set_tracer(Tracer::counting());
+        cpu.run_to_completion().unwrap();
+        let tracer = cpu.take_tracer().unwrap();
+
+        assert!(tracer.entries().is_empty());
+        assert_eq!(13, tracer.total());
+    }
+
+    #[test]
+    fn diff_traces() {
+        let a = traced(COUNTER);
+        let b = traced(&COUNTER.replace("#3", "#2"));
+
+        assert_eq!(None, diff(a.entries(), a.entries()));
+        assert_eq!(Some(2), diff(a.entries(), b.entries()));
+        assert_eq!(Some(5), diff(&a.entries()[..5], a.entries()));
+    }
+
+    #[test]
+    fn relative_write() {
+        let mut cpu = CPU::new();
+        cpu.load_rom(assemble("arb #10\nin [rb-2]\nhlt").unwrap());
+        cpu.set_tracer(Tracer::recording());
+        cpu.push_input(5);
+        cpu.run().unwrap();
+        let entry = &cpu.tracer().unwrap().entries()[1];
+
+        assert_eq!(vec![8], entry.values);
+        assert_eq!("0002: in [rb-2] -> [8]=5", entry.to_string());
+    }
+}
@@ -0,0 +1,31 @@
+pub mod year_2022;
+
+use aoc_core::{ParseError, Registry};
+use nom::IResult;
+
+/// Every solved puzzle of this crate.
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    registry.register(year_2022::SOLUTIONS);
+
+    registry
+}
+
+/// Output of a nom parser that should have consumed the whole `input`, or
+/// where it got stuck.
+pub(crate) fn finish<T>(input: &str, result: IResult<&str, T>) -> Result<T, ParseError> {
+    let rest = match result {
+        Ok(("", output)) => return Ok(output),
+        Ok((rest, _)) => rest,
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => e.input,
+        Err(nom::Err::Incomplete(_)) => "",
+    };
+
+    let message = match rest.lines().next() {
+        Some(line) if !line.is_empty() => format!("unexpected '{}'", line),
+        _ if rest.is_empty() => "unexpected end of input".to_string(),
+        _ => "unexpected empty line".to_string(),
+    };
+
+    Err(ParseError::at(input, rest, message))
+}
@@ -1,55 +1,59 @@
-mod year_2022;
+use aoc_core::Inputs;
 
 use std::env;
-use std::io;
-use std::io::Read;
+use std::process;
 
-fn main() {
-    let mut args = env::args();
-
-    let _ = args.next();
-    let year: u32 = args.next().unwrap().parse().unwrap();
-    let day: u32 = args.next().unwrap().parse().unwrap();
-
-    let mut input = String::new();
-    io::stdin().read_to_string(&mut input).unwrap();
-
-    let (part_1_solution, part_2_solution) = match (year, day) {
-        (2022, 1) => {
-            use year_2022::day_01::{solve_part_1, solve_part_2};
-
-            let part_1_solution = solve_part_1(&input);
-            let part_2_solution = solve_part_2(&input);
-
-            (part_1_solution, part_2_solution)
-        }
-        (2022, 2) => {
-            use year_2022::day_02::{solve_part_1, solve_part_2};
+const USAGE: &str = "usage: aoc-2022 <year> <day> [input]";
 
-            let part_1_solution = solve_part_1(&input);
-            let part_2_solution = solve_part_2(&input);
-
-            (part_1_solution, part_2_solution)
+/// Parses the next argument as a number, or exits with the usage.
+fn number(arg: Option<String>) -> u32 {
+    match arg.map(|arg| arg.parse()) {
+        Some(Ok(number)) => number,
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
         }
-        (2022, 3) => {
-            use year_2022::day_03::{solve_part_1, solve_part_2};
+    }
+}
 
-            let part_1_solution = solve_part_1(&input);
-            let part_2_solution = solve_part_2(&input);
+fn main() {
+    let mut args = env::args();
 
-            (part_1_solution, part_2_solution)
+    let _ = args.next();
+    let year = number(args.next());
+    let day = number(args.next());
+    let inputs = match args.next() {
+        Some(arg) => Inputs::from_arg(&arg),
+        None => Inputs::from_env(),
+    };
+    if args.next().is_some() {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    }
+
+    let puzzle = match aoc_2022::registry().get(year, day) {
+        Ok(puzzle) => *puzzle,
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(1);
         }
-        (2022, 4) => {
-            use year_2022::day_04::{solve_part_1, solve_part_2};
-
-            let part_1_solution = solve_part_1(&input);
-            let part_2_solution = solve_part_2(&input);
+    };
 
-            (part_1_solution, part_2_solution)
+    let input = match inputs.read(year, day) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(1);
         }
-        _ => panic!("Not solved yet"),
     };
 
-    println!("{}", part_1_solution);
-    println!("{}", part_2_solution);
+    for part in 1..=2 {
+        match puzzle.solve(part, &input) {
+            Ok(answer) => println!("{}", answer),
+            Err(err) => {
+                eprintln!("{}", err.diagnostic(&input));
+                process::exit(1);
+            }
+        }
+    }
 }
@@ -1,14 +1,15 @@
+use crate::finish;
+use aoc_core::{ParseError, Solution};
 use nom::{
     character::complete::{digit1, newline},
-    combinator::opt,
+    combinator::{map_res, opt},
     multi::many1,
     IResult,
 };
 
 fn parse_line(input: &str) -> IResult<&str, u32> {
-    let (input, calories) = digit1(input)?;
+    let (input, calories) = map_res(digit1, |s: &str| s.parse())(input)?;
     let (input, _) = newline(input)?;
-    let calories = calories.parse().unwrap();
     Ok((input, calories))
 }
 
@@ -25,31 +26,46 @@ fn parse_input(input: &str) -> IResult<&str, Vec<Vec<u32>>> {
     Ok((input, elves_calories))
 }
 
-pub fn solve_part_1(input: &str) -> u32 {
-    let (_, elves_calories) = parse_input(input).unwrap();
+pub struct Solver;
 
-    elves_calories.iter().map(|v| v.iter().sum()).max().unwrap()
-}
+impl Solution for Solver {
+    type Input<'a> = Vec<Vec<u32>>;
+    type Part1 = u32;
+    type Part2 = u32;
 
-pub fn solve_part_2(input: &str) -> u32 {
-    let (_, total_calories) = parse_input(input).unwrap();
+    fn parse(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+        finish(input, parse_input(input))
+    }
 
-    let mut per_elf_calories: Vec<u32> = total_calories
-        .iter()
-        .map(|elf_calories| elf_calories.iter().sum())
-        .collect();
-    per_elf_calories.sort();
-    per_elf_calories.reverse();
+    fn part1(elves_calories: &Vec<Vec<u32>>) -> u32 {
+        elves_calories.iter().map(|v| v.iter().sum()).max().unwrap()
+    }
+
+    fn part2(total_calories: &Vec<Vec<u32>>) -> u32 {
+        let mut per_elf_calories: Vec<u32> = total_calories
+            .iter()
+            .map(|elf_calories| elf_calories.iter().sum())
+            .collect();
+        per_elf_calories.sort();
+        per_elf_calories.reverse();
 
-    per_elf_calories.iter().take(3).sum()
+        per_elf_calories.iter().take(3).sum()
+    }
+}
+
+pub fn solve_part_1(input: &str) -> u32 {
+    Solver::part1(&Solver::parse(input).unwrap())
+}
+
+pub fn solve_part_2(input: &str) -> u32 {
+    Solver::part2(&Solver::parse(input).unwrap())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const SAMPLE_INPUT: &'static str =
-        "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+    const SAMPLE_INPUT: &str = include_str!("../../../examples/2022/01/sample.txt");
 
     #[test]
     fn test_solve_part_1_on_sample_input() {
@@ -60,9 +76,9 @@ mod tests {
 
     #[test]
     fn test_solve_part_1_on_full_input() {
-        let input = include_str!("../../input/2022/01.txt");
+        let input = include_str!("../../../inputs/2022/01.txt");
 
-        let solution = solve_part_1(&input);
+        let solution = solve_part_1(input);
 
         assert_eq!(solution, 71124);
     }
@@ -76,7 +92,7 @@ mod tests {
 
     #[test]
     fn test_solve_part_2_on_full_input() {
-        let input = include_str!("../../input/2022/01.txt");
+        let input = include_str!("../../../inputs/2022/01.txt");
 
         let solution = solve_part_2(input);
 
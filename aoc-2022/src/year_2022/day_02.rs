@@ -1,3 +1,5 @@
+use crate::finish;
+use aoc_core::{ParseError, Solution};
 use nom::{
     character::complete::{char, newline, one_of},
     multi::many1,
@@ -106,12 +108,45 @@ fn score_round(player: Shape, opponent: Shape) -> u32 {
     player.score() + outcome.score()
 }
 
-pub fn solve_part_1(input: &str) -> u32 {
-    let (_, data) = parse_part_1(input).unwrap();
+/// Both parts read the second column differently, so the guide holds both readings.
+pub struct Guide {
+    part_1: Vec<Strategy1>,
+    part_2: Vec<Strategy2>,
+}
+
+pub struct Solver;
 
-    data.into_iter()
-        .map(|(opponent, player)| score_round(player, opponent))
-        .sum()
+impl Solution for Solver {
+    type Input<'a> = Guide;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Guide, ParseError> {
+        Ok(Guide {
+            part_1: finish(input, parse_part_1(input))?,
+            part_2: finish(input, parse_part_2(input))?,
+        })
+    }
+
+    fn part1(guide: &Guide) -> u32 {
+        guide
+            .part_1
+            .iter()
+            .map(|&(opponent, player)| score_round(player, opponent))
+            .sum()
+    }
+
+    fn part2(guide: &Guide) -> u32 {
+        guide
+            .part_2
+            .iter()
+            .map(|&(opponent, outcome)| get_score_to_achieve(opponent, outcome))
+            .sum()
+    }
+}
+
+pub fn solve_part_1(input: &str) -> u32 {
+    Solver::part1(&Solver::parse(input).unwrap())
 }
 
 fn parse_second_column_part_2(input: &str) -> IResult<&str, Outcome> {
@@ -157,18 +192,14 @@ fn get_score_to_achieve(opponent: Shape, outcome: Outcome) -> u32 {
 }
 
 pub fn solve_part_2(input: &str) -> u32 {
-    let (_, data) = parse_part_2(input).unwrap();
-
-    data.into_iter()
-        .map(|(opponent, outcome)| get_score_to_achieve(opponent, outcome))
-        .sum()
+    Solver::part2(&Solver::parse(input).unwrap())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const SAMPLE_INPUT: &'static str = "A Y\nB X\nC Z\n";
+    const SAMPLE_INPUT: &str = include_str!("../../../examples/2022/02/sample.txt");
 
     #[test]
     fn test_solve_part_1_on_sample_input() {
@@ -179,7 +210,7 @@ mod tests {
 
     #[test]
     fn test_solve_part_1_on_full_input() {
-        let input = include_str!("../../input/2022/02.txt");
+        let input = include_str!("../../../inputs/2022/02.txt");
 
         let solution = solve_part_1(input);
 
@@ -195,10 +226,17 @@ mod tests {
 
     #[test]
     fn test_solve_part_2_on_full_input() {
-        let input = include_str!("../../input/2022/02.txt");
+        let input = include_str!("../../../inputs/2022/02.txt");
 
         let solution = solve_part_2(input);
 
         assert_eq!(solution, 13889);
     }
+
+    #[test]
+    fn test_parse_malformed_line() {
+        let error = Solver::parse("A Y\nB W\n").err();
+
+        assert_eq!(error, Some(ParseError::new(2, 1, "unexpected 'B W'")));
+    }
 }
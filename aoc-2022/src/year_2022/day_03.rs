@@ -1,3 +1,5 @@
+use crate::finish;
+use aoc_core::{ParseError, Solution};
 use nom::{
     character::complete::{alpha1, newline},
     combinator::verify,
@@ -9,7 +11,7 @@ use std::collections::HashSet;
 type Item = char;
 
 #[derive(Debug)]
-struct Rucksack(Vec<Item>);
+pub struct Rucksack(Vec<Item>);
 
 impl Rucksack {
     fn compartment_size(&self) -> usize {
@@ -45,7 +47,7 @@ fn priority(c: char) -> u32 {
 }
 
 fn parse_line(input: &str) -> IResult<&str, Rucksack> {
-    let (input, output) = verify(alpha1, |s: &str| s.len() % 2 == 0)(input)?;
+    let (input, output) = verify(alpha1, |s: &str| s.len().is_multiple_of(2))(input)?;
     let (input, _) = newline(input)?;
 
     let items = output.chars().collect();
@@ -55,40 +57,56 @@ fn parse_line(input: &str) -> IResult<&str, Rucksack> {
 }
 
 fn parse_input(input: &str) -> IResult<&str, Vec<Rucksack>> {
-    verify(many1(parse_line), |r: &Vec<Rucksack>| r.len() % 3 == 0)(input)
+    verify(many1(parse_line), |r: &Vec<Rucksack>| r.len().is_multiple_of(3))(input)
 }
 
-pub fn solve_part_1(input: &str) -> u32 {
-    let (_, rucksacks) = parse_input(input).unwrap();
+pub struct Solver;
 
-    rucksacks.iter().map(|r| priority(r.shared_item())).sum()
-}
+impl Solution for Solver {
+    type Input<'a> = Vec<Rucksack>;
+    type Part1 = u32;
+    type Part2 = u32;
 
-pub fn solve_part_2(input: &str) -> u32 {
-    let (_, rucksacks) = parse_input(input).unwrap();
+    fn parse(input: &str) -> Result<Vec<Rucksack>, ParseError> {
+        finish(input, parse_input(input))
+    }
+
+    fn part1(rucksacks: &Vec<Rucksack>) -> u32 {
+        rucksacks.iter().map(|r| priority(r.shared_item())).sum()
+    }
 
-    let mut result = 0;
+    fn part2(rucksacks: &Vec<Rucksack>) -> u32 {
+        let mut result = 0;
 
-    for i in (0..rucksacks.len()).step_by(3) {
-        let mut r1 = HashSet::<_>::from_iter(rucksacks[i].0.iter());
-        let r2 = HashSet::<_>::from_iter(rucksacks[i + 1].0.iter());
-        let r3 = HashSet::<_>::from_iter(rucksacks[i + 2].0.iter());
+        for i in (0..rucksacks.len()).step_by(3) {
+            let mut r1 = HashSet::<_>::from_iter(rucksacks[i].0.iter());
+            let r2 = HashSet::<_>::from_iter(rucksacks[i + 1].0.iter());
+            let r3 = HashSet::<_>::from_iter(rucksacks[i + 2].0.iter());
 
-        r1.retain(|c| r2.contains(c) && r3.contains(c));
+            r1.retain(|c| r2.contains(c) && r3.contains(c));
 
-        let item = r1.iter().next().unwrap();
+            let item = r1.iter().next().unwrap();
 
-        result += priority(**item);
+            result += priority(**item);
+        }
+
+        result
     }
+}
+
+pub fn solve_part_1(input: &str) -> u32 {
+    Solver::part1(&Solver::parse(input).unwrap())
+}
 
-    result
+pub fn solve_part_2(input: &str) -> u32 {
+    Solver::part2(&Solver::parse(input).unwrap())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const SAMPLE_INPUT: &'static str = "vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\nPmmdzqPrVvPwwTWBwg\nwMqvLMZHhHMvwLHjbvcjnnSBnvTQFn\nttgJtRGJQctTZtZT\nCrZsJsPPZsGzwwsLwLmpwMDw\n";
+    const SAMPLE_INPUT: &str = include_str!("../../../examples/2022/03/sample.txt");
 
     #[test]
     fn test_solve_part_1_on_sample_input() {
@@ -99,7 +117,7 @@ mod tests {
 
     #[test]
     fn test_solve_part_1_on_full_input() {
-        let input = include_str!("../../input/2022/03.txt");
+        let input = include_str!("../../../inputs/2022/03.txt");
 
         let solution = solve_part_1(input);
 
@@ -115,7 +133,7 @@ mod tests {
 
     #[test]
     fn test_solve_part_2_on_full_input() {
-        let input = include_str!("../../input/2022/03.txt");
+        let input = include_str!("../../../inputs/2022/03.txt");
 
         let solution = solve_part_2(input);
 
@@ -1,3 +1,5 @@
+use crate::finish;
+use aoc_core::{ParseError, Solution};
 use nom::{
     character::complete::{char, digit1, line_ending},
     combinator::map_res,
@@ -5,7 +7,7 @@ use nom::{
     IResult,
 };
 
-struct Range {
+pub struct Range {
     left: u32,
     right: u32,
 }
@@ -23,7 +25,7 @@ impl Range {
     }
 }
 
-type AssignmentPair = (Range, Range);
+pub type AssignmentPair = (Range, Range);
 
 fn parse_assignment(input: &str) -> IResult<&str, Range> {
     let (input, left) = map_res(digit1, |s: &str| s.parse())(input)?;
@@ -48,31 +50,47 @@ fn parse_input(input: &str) -> IResult<&str, Vec<AssignmentPair>> {
     many1(parse_line)(input)
 }
 
-pub fn solve_part_1(input: &str) -> u32 {
-    let (_, assignments) = parse_input(input).unwrap();
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Vec<AssignmentPair>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<AssignmentPair>, ParseError> {
+        finish(input, parse_input(input))
+    }
 
-    assignments
-        .into_iter()
-        .filter(|(left, right)| left.contains(right) || right.contains(left))
-        .map(|_| 1)
-        .sum()
+    fn part1(assignments: &Vec<AssignmentPair>) -> u32 {
+        assignments
+            .iter()
+            .filter(|(left, right)| left.contains(right) || right.contains(left))
+            .map(|_| 1)
+            .sum()
+    }
+
+    fn part2(assignments: &Vec<AssignmentPair>) -> u32 {
+        assignments
+            .iter()
+            .filter(|(left, right)| left.overlaps(right))
+            .map(|_| 1)
+            .sum()
+    }
 }
 
-pub fn solve_part_2(input: &str) -> u32 {
-    let (_, assignments) = parse_input(input).unwrap();
+pub fn solve_part_1(input: &str) -> u32 {
+    Solver::part1(&Solver::parse(input).unwrap())
+}
 
-    assignments
-        .into_iter()
-        .filter(|(left, right)| left.overlaps(right))
-        .map(|_| 1)
-        .sum()
+pub fn solve_part_2(input: &str) -> u32 {
+    Solver::part2(&Solver::parse(input).unwrap())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &'static str = "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8\n";
+    const INPUT: &str = include_str!("../../../examples/2022/04/sample.txt");
 
     #[test]
     fn test_solve_part_1_on_sample_input() {
@@ -83,7 +101,7 @@ mod tests {
 
     #[test]
     fn test_solve_part_1_on_full_input() {
-        let input = include_str!("../../input/2022/04.txt");
+        let input = include_str!("../../../inputs/2022/04.txt");
 
         let solution = solve_part_1(input);
 
@@ -99,10 +117,17 @@ mod tests {
 
     #[test]
     fn test_solve_part_2_on_full_input() {
-        let input = include_str!("../../input/2022/04.txt");
+        let input = include_str!("../../../inputs/2022/04.txt");
 
         let solution = solve_part_2(input);
 
         assert_eq!(solution, 847);
     }
+
+    #[test]
+    fn test_parse_malformed_line() {
+        let error = Solver::parse("2-4,6-8\n2-x,4-5\n").err();
+
+        assert_eq!(error, Some(ParseError::new(2, 1, "unexpected '2-x,4-5'")));
+    }
 }
@@ -1,4 +1,7 @@
-pub mod day_01;
-pub mod day_02;
-pub mod day_03;
-pub mod day_04;
+aoc_core::days! {
+    2022;
+    1 => day_01,
+    2 => day_02,
+    3 => day_03,
+    4 => day_04,
+}
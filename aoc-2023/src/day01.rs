@@ -1,25 +1,4 @@
-pub fn solve_part_1(input: &str) -> u32 {
-    let mut calibration_value = 0;
-    for line in input.lines() {
-        let first_digit = line
-            .chars()
-            .find(|c| c.is_ascii_digit())
-            .unwrap()
-            .to_digit(10)
-            .unwrap();
-        let last_digit = line
-            .chars()
-            .filter(|c| c.is_ascii_digit())
-            .last()
-            .unwrap()
-            .to_digit(10)
-            .unwrap();
-
-        calibration_value += first_digit * 10 + last_digit;
-    }
-
-    calibration_value
-}
+use aoc_core::{ParseError, Solution};
 
 const DIGITS: [&str; 18] = [
     "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "1", "2", "3", "4",
@@ -41,47 +20,82 @@ fn digit_to_value(digit: &str) -> u32 {
     }
 }
 
-fn starts_with(input: &str, with: &str) -> bool {
-    if with.len() > input.len() {
-        return false;
-    }
-    &input[0..with.len()] == with
+/// Values of the first and last of `digits` found in the line, if any.
+fn first_and_last(line: &str, digits: &[&str]) -> Option<(u32, u32)> {
+    let mut values = line.char_indices().filter_map(|(i, _)| {
+        let substring = &line[i..];
+
+        digits
+            .iter()
+            .find(|digit| substring.starts_with(**digit))
+            .map(|digit| digit_to_value(digit))
+    });
+
+    let first_value = values.next()?;
+    let last_value = values.next_back().unwrap_or(first_value);
+
+    Some((first_value, last_value))
+}
+
+/// First and last digit of a line, read once as digits only and once with
+/// spelled out digits too.
+pub struct Calibration {
+    digits: Option<(u32, u32)>,
+    spelled: (u32, u32),
+}
+
+pub fn parse(input: &str) -> Result<Vec<Calibration>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let spelled = first_and_last(line, &DIGITS).ok_or_else(|| {
+                ParseError::at(input, line, format!("expected a digit in '{}'", line))
+            })?;
+
+            Ok(Calibration {
+                digits: first_and_last(line, &DIGITS[9..]),
+                spelled,
+            })
+        })
+        .collect()
+}
+
+/// Lines with only spelled out digits, like those of the part 2 example,
+/// add nothing.
+pub fn solve_part_1(calibrations: &[Calibration]) -> u32 {
+    calibrations
+        .iter()
+        .filter_map(|calibration| calibration.digits)
+        .map(|(first_digit, last_digit)| first_digit * 10 + last_digit)
+        .sum()
+}
+
+pub fn solve_part_2(calibrations: &[Calibration]) -> u32 {
+    calibrations
+        .iter()
+        .map(|calibration| calibration.spelled)
+        .map(|(first_value, last_value)| first_value * 10 + last_value)
+        .sum()
 }
 
-pub fn solve_part_2(input: &str) -> u32 {
-    let mut calibration_value = 0;
-    for line in input.lines() {
-        let mut first_value: Option<u32> = None;
-
-        'outer: for i in 0..line.len() {
-            let substring = &line[i..];
-
-            for digit in DIGITS {
-                if starts_with(substring, digit) {
-                    first_value = Some(digit_to_value(digit));
-                    break 'outer;
-                }
-            }
-        }
-
-        let mut last_value: Option<u32> = None;
-        for i in 0..line.len() {
-            let substring = &line[i..];
-
-            for digit in DIGITS {
-                if starts_with(substring, digit) {
-                    last_value = Some(digit_to_value(digit));
-                }
-            }
-        }
-
-        let first_value = first_value.unwrap();
-        let last_value = last_value.unwrap();
-
-        calibration_value += first_value * 10 + last_value;
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Vec<Calibration>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<Calibration>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<Calibration>) -> u32 {
+        solve_part_1(input)
     }
 
-    calibration_value
+    fn part2(input: &Vec<Calibration>) -> u32 {
+        solve_part_2(input)
+    }
 }
 
 #[cfg(test)]
@@ -90,37 +104,44 @@ mod tests {
 
     #[test]
     fn solves_part_1_example() {
-        let input = include_str!("../input/day_1_part_1_example.txt");
+        let input = include_str!("../../examples/2023/01/part1.txt");
 
-        let result = solve_part_1(input);
+        let result = solve_part_1(&parse(input).unwrap());
 
         assert_eq!(result, 142);
     }
 
     #[test]
     fn solves_part_1_puzzle() {
-        let input = include_str!("../input/day_1_puzzle.txt");
+        let input = include_str!("../../inputs/2023/01.txt");
 
-        let result = solve_part_1(input);
+        let result = solve_part_1(&parse(input).unwrap());
 
         assert_eq!(result, 55002);
     }
 
     #[test]
     fn solves_part_2_example() {
-        let input = include_str!("../input/day_1_part_2_example.txt");
+        let input = include_str!("../../examples/2023/01/part2.txt");
 
-        let result = solve_part_2(input);
+        let result = solve_part_2(&parse(input).unwrap());
 
         assert_eq!(result, 281);
     }
 
     #[test]
     fn solves_part_2_puzzle() {
-        let input = include_str!("../input/day_1_puzzle.txt");
+        let input = include_str!("../../inputs/2023/01.txt");
 
-        let result = solve_part_2(input);
+        let result = solve_part_2(&parse(input).unwrap());
 
         assert_eq!(result, 55093);
     }
+
+    #[test]
+    fn reports_line_without_digits() {
+        let error = parse("1abc2\nzéro\n").err();
+
+        assert_eq!(error, Some(ParseError::new(2, 1, "expected a digit in 'zéro'")));
+    }
 }
@@ -1 +1,4 @@
-pub mod day01;
+aoc_core::days! {
+    2023;
+    1 => day01,
+}
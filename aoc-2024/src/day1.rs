@@ -0,0 +1,87 @@
+use aoc_core::{parse_number, ParseError, Solution};
+use std::collections::HashMap;
+
+pub struct Input {
+    x: Vec<u32>,
+    y: Vec<u32>,
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let mut x = Vec::new();
+    let mut y = Vec::new();
+
+    for line in input.lines() {
+        let mut splitted_line = line.split_whitespace();
+        let (a, b) = match (splitted_line.next(), splitted_line.next(), splitted_line.next()) {
+            (Some(a), Some(b), None) => (a, b),
+            _ => return Err(ParseError::at(input, line, format!("expected two numbers, found '{}'", line))),
+        };
+
+        x.push(parse_number(input, a)?);
+        y.push(parse_number(input, b)?);
+    }
+
+    x.sort();
+    y.sort();
+
+    Ok(Input { x, y })
+}
+
+pub fn solve_part_1(input: &Input) -> u32 {
+    input
+        .x
+        .iter()
+        .zip(&input.y)
+        .map(|(a, b)| a.abs_diff(*b))
+        .sum()
+}
+
+pub fn solve_part_2(input: &Input) -> u32 {
+    let mut map = HashMap::new();
+    for b in &input.y {
+        *map.entry(*b).or_insert(0) += 1;
+    }
+
+    input.x.iter().map(|v| *v * map.get(v).unwrap_or(&0)).sum()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Input;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> u32 {
+        solve_part_1(input)
+    }
+
+    fn part2(input: &Input) -> u32 {
+        solve_part_2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../../examples/2024/01/sample.txt");
+
+    #[test]
+    fn test_part_1() {
+        let result = solve_part_1(&parse(INPUT).unwrap());
+
+        assert_eq!(result, 11);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let result = solve_part_2(&parse(INPUT).unwrap());
+
+        assert_eq!(result, 31);
+    }
+}
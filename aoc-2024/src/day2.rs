@@ -0,0 +1,113 @@
+use aoc_core::{parse_number, ParseError, Solution};
+
+pub fn parse(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    let mut v = Vec::new();
+    for line in input.lines() {
+        let mut inner_v = Vec::new();
+        for c in line.split(" ") {
+            inner_v.push(parse_number(input, c)?);
+        }
+        v.push(inner_v);
+    }
+
+    Ok(v)
+}
+
+pub fn solve_part_1(input: &Vec<Vec<u32>>) -> u32 {
+    let mut sum = 0;
+    for report in input {
+        let v = gen_level_diff(report);
+        if is_safe(&v) {
+            sum += 1;
+        }
+    }
+
+    sum
+}
+
+pub fn solve_part_2(input: &Vec<Vec<u32>>) -> u32 {
+    let mut sum = 0;
+    for report in input {
+        // Create a Vec that contains the original report as well as new reports
+        // created by removing one element from the original report
+        let mut subreports = Vec::with_capacity(report.len() + 1);
+        subreports.push(report.clone());
+        for i in 0..report.len() {
+            let mut subreport = Vec::with_capacity(report.len() - 1);
+            for (j, n) in report.iter().enumerate() {
+                if i != j {
+                    subreport.push(*n);
+                }
+            }
+            subreports.push(subreport);
+        }
+
+        // Calculate difference between subsequent levels
+        let subreport_level_diffs: Vec<Vec<i32>> = subreports
+            .iter()
+            .map(|subreport| gen_level_diff(subreport))
+            .collect();
+
+        // If any of the subreports are safe then the report is safe
+        if subreport_level_diffs.iter().any(|a| is_safe(a)) {
+            sum += 1;
+        }
+    }
+
+    sum
+}
+
+fn is_safe(report: &[i32]) -> bool {
+    report.iter().all(|n| [-1, -2, -3].contains(n)) || report.iter().all(|n| [1, 2, 3].contains(n))
+}
+
+fn gen_level_diff(report: &[u32]) -> Vec<i32> {
+    let mut v = Vec::with_capacity(report.len() - 1);
+    for levels in report.windows(2) {
+        let [a, b]: [_; 2] = levels.try_into().unwrap();
+        v.push(a as i32 - b as i32);
+    }
+
+    v
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Vec<Vec<u32>>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<Vec<u32>>) -> u32 {
+        solve_part_1(input)
+    }
+
+    fn part2(input: &Vec<Vec<u32>>) -> u32 {
+        solve_part_2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../../examples/2024/02/sample.txt");
+
+    #[test]
+    fn test_solve_part_1() {
+        let result = solve_part_1(&parse(INPUT).unwrap());
+
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn test_solve_part_2() {
+        let result = solve_part_2(&parse(INPUT).unwrap());
+
+        assert_eq!(result, 4);
+    }
+}
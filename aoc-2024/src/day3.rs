@@ -0,0 +1,109 @@
+use aoc_core::{parse_number, ParseError, Solution};
+use regex::Regex;
+
+#[derive(Debug, PartialEq)]
+pub enum Instruction {
+    Mul(u32, u32),
+    Do,
+    Dont,
+}
+
+/// The instructions found among the corrupted memory, which is skipped.
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let re = Regex::new(r"(don't\(\))|(do\(\))|(mul\((\d+),(\d+)\))")
+        .expect("valid regex must be provided");
+
+    re.captures_iter(input)
+        .map(|x| {
+            if x.get(1).is_some() {
+                Ok(Instruction::Dont)
+            } else if x.get(2).is_some() {
+                Ok(Instruction::Do)
+            } else {
+                let a = parse_number(input, x.get(4).unwrap().as_str())?;
+                let b = parse_number(input, x.get(5).unwrap().as_str())?;
+
+                Ok(Instruction::Mul(a, b))
+            }
+        })
+        .collect()
+}
+
+pub fn solve_part_1(instructions: &[Instruction]) -> u32 {
+    let mut sum = 0;
+    for instruction in instructions {
+        if let Instruction::Mul(x, y) = instruction {
+            sum += x * y;
+        }
+    }
+
+    sum
+}
+
+pub fn solve_part_2(instructions: &[Instruction]) -> u32 {
+    let mut sum = 0;
+    let mut enabled = true;
+    for instruction in instructions {
+        match instruction {
+            Instruction::Dont => enabled = false,
+            Instruction::Do => enabled = true,
+            Instruction::Mul(a, b) if enabled => sum += a * b,
+            Instruction::Mul(..) => {}
+        }
+    }
+
+    sum
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Vec<Instruction>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<Instruction>) -> u32 {
+        solve_part_1(input)
+    }
+
+    fn part2(input: &Vec<Instruction>) -> u32 {
+        solve_part_2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_part_1() {
+        let input = include_str!("../../examples/2024/03/part1.txt");
+
+        let result = solve_part_1(&parse(input).unwrap());
+
+        assert_eq!(result, 161);
+    }
+
+    #[test]
+    fn test_solve_part_2() {
+        let input = include_str!("../../examples/2024/03/part2.txt");
+
+        let result = solve_part_2(&parse(input).unwrap());
+
+        assert_eq!(result, 48);
+    }
+
+    #[test]
+    fn test_parse_malformed_number() {
+        let input = "mul(2,4)%&mul[3,7]!@^do_not_mul(4294967296,5)";
+
+        assert_eq!(
+            parse(input),
+            Err(ParseError::new(1, 33, "expected a number, found '4294967296'"))
+        );
+    }
+}
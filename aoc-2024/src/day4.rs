@@ -0,0 +1,173 @@
+use aoc_core::{ParseError, Solution};
+
+/// Rows of the word search, all as wide as the first one.
+pub fn parse(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    let width = input.lines().next().map_or(0, |line| line.chars().count());
+    if width == 0 {
+        return Err(ParseError::at(input, input, "expected a row of letters"));
+    }
+
+    input
+        .lines()
+        .map(|line| {
+            let row: Vec<char> = line.chars().collect();
+            if row.len() != width {
+                let message = format!("expected {} letters, found {}", width, row.len());
+                return Err(ParseError::at(input, line, message));
+            }
+
+            Ok(row)
+        })
+        .collect()
+}
+
+pub fn solve_part_1(v: &[Vec<char>]) -> u32 {
+    let height = v.len();
+    let width = v[0].len();
+
+    let mut sum = 0;
+    for i in 0..height {
+        for j in 0..width {
+            if j + 3 < width
+                && v[i][j] == 'X'
+                && v[i][j + 1] == 'M'
+                && v[i][j + 2] == 'A'
+                && v[i][j + 3] == 'S'
+            {
+                sum += 1;
+            }
+            if j >= 3 && v[i][j - 3..=j] == ['S', 'A', 'M', 'X'] {
+                sum += 1;
+            }
+            if i + 3 < height
+                && v[i][j] == 'X'
+                && v[i + 1][j] == 'M'
+                && v[i + 2][j] == 'A'
+                && v[i + 3][j] == 'S'
+            {
+                sum += 1;
+            }
+            if i >= 3
+                && v[i][j] == 'X'
+                && v[i - 1][j] == 'M'
+                && v[i - 2][j] == 'A'
+                && v[i - 3][j] == 'S'
+            {
+                sum += 1;
+            }
+            if j + 3 < width
+                && i >= 3
+                && v[i][j] == 'X'
+                && v[i - 1][j + 1] == 'M'
+                && v[i - 2][j + 2] == 'A'
+                && v[i - 3][j + 3] == 'S'
+            {
+                sum += 1;
+            }
+            if j + 3 < width
+                && i + 3 < height
+                && v[i][j] == 'X'
+                && v[i + 1][j + 1] == 'M'
+                && v[i + 2][j + 2] == 'A'
+                && v[i + 3][j + 3] == 'S'
+            {
+                sum += 1;
+            }
+            if j >= 3
+                && i >= 3
+                && v[i][j] == 'X'
+                && v[i - 1][j - 1] == 'M'
+                && v[i - 2][j - 2] == 'A'
+                && v[i - 3][j - 3] == 'S'
+            {
+                sum += 1;
+            }
+            if j >= 3
+                && i + 3 < height
+                && v[i][j] == 'X'
+                && v[i + 1][j - 1] == 'M'
+                && v[i + 2][j - 2] == 'A'
+                && v[i + 3][j - 3] == 'S'
+            {
+                sum += 1;
+            }
+        }
+    }
+
+    sum
+}
+
+pub fn solve_part_2(v: &[Vec<char>]) -> u32 {
+    let height = v.len();
+    let width = v[0].len();
+
+    let mut sum = 0;
+    for i in 0..height {
+        for j in 0..width {
+            if i > 0
+                && i + 1 < height
+                && j > 0
+                && j + 1 < width
+                && ((v[i - 1][j - 1] == 'M' && v[i][j] == 'A' && v[i + 1][j + 1] == 'S')
+                    || (v[i - 1][j - 1] == 'S' && v[i][j] == 'A' && v[i + 1][j + 1] == 'M'))
+                && ((v[i + 1][j - 1] == 'M' && v[i][j] == 'A' && v[i - 1][j + 1] == 'S')
+                    || (v[i + 1][j - 1] == 'S' && v[i][j] == 'A' && v[i - 1][j + 1] == 'M'))
+            {
+                sum += 1;
+            }
+        }
+    }
+
+    sum
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Vec<Vec<char>>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<Vec<char>>) -> u32 {
+        solve_part_1(input)
+    }
+
+    fn part2(input: &Vec<Vec<char>>) -> u32 {
+        solve_part_2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../../examples/2024/04/sample.txt");
+
+    #[test]
+    fn test_solve_part_1() {
+        let result = solve_part_1(&parse(INPUT).unwrap());
+
+        assert_eq!(result, 18);
+    }
+
+    #[test]
+    fn test_solve_part_2() {
+        let result = solve_part_2(&parse(INPUT).unwrap());
+
+        assert_eq!(result, 9);
+    }
+
+    #[test]
+    fn test_rectangular_grid() {
+        assert_eq!(solve_part_1(&parse("XMAS\nSAMX\n").unwrap()), 2);
+        assert_eq!(
+            parse("XMAS\nMAS\n"),
+            Err(ParseError::new(2, 1, "expected 4 letters, found 3"))
+        );
+        assert_eq!(parse(""), Err(ParseError::new(1, 1, "expected a row of letters")));
+    }
+}
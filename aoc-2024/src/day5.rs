@@ -0,0 +1,162 @@
+use aoc_core::{parse_number, ParseError, Solution};
+use std::collections::HashSet;
+
+pub struct Input {
+    page_numbers_with_rules: HashSet<u32>,
+    rules: HashSet<[u32; 2]>,
+    updates: Vec<Vec<u32>>,
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let (rules, updates) = input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::at_offset(input, input.len(), "expected a blank line between rules and updates"))?;
+    let (page_numbers_with_rules, rules) = parse_rules(input, rules)?;
+    let updates = parse_updates(input, updates)?;
+
+    Ok(Input {
+        page_numbers_with_rules,
+        rules,
+        updates,
+    })
+}
+
+fn parse_rules(input: &str, rules: &str) -> Result<(HashSet<u32>, HashSet<[u32; 2]>), ParseError> {
+    let rules: HashSet<[u32; 2]> = rules
+        .lines()
+        .map(|line| to_rule(input, line))
+        .collect::<Result<_, _>>()?;
+    let page_numbers_with_rules: HashSet<u32> = rules.iter().flatten().copied().collect();
+
+    Ok((page_numbers_with_rules, rules))
+}
+
+fn parse_updates(input: &str, updates: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    updates
+        .lines()
+        .map(|update| to_page_numbers(input, update))
+        .collect()
+}
+
+fn to_rule(input: &str, line: &str) -> Result<[u32; 2], ParseError> {
+    let (a, b) = line
+        .split_once("|")
+        .ok_or_else(|| ParseError::at(input, line, format!("expected a rule like 47|53, found '{}'", line)))?;
+
+    Ok([parse_number(input, a)?, parse_number(input, b)?])
+}
+
+fn to_page_numbers(input: &str, update: &str) -> Result<Vec<u32>, ParseError> {
+    let page_numbers: Vec<u32> = update
+        .split(",")
+        .map(|page_number| parse_number(input, page_number))
+        .collect::<Result<_, _>>()?;
+    if page_numbers.len().is_multiple_of(2) {
+        return Err(ParseError::at(input, update, "an update must have an odd number of pages"));
+    }
+
+    Ok(page_numbers)
+}
+
+pub fn solve_part_1(input: &Input) -> u32 {
+    let mut sum = 0;
+    'outer: for update in &input.updates {
+        for i in 0..update.len() - 1 {
+            for j in i + 1..update.len() {
+                let a = update[i];
+                let b = update[j];
+
+                if input.page_numbers_with_rules.contains(&a)
+                    && input.page_numbers_with_rules.contains(&b)
+                    && input.rules.contains(&[b, a])
+                {
+                    continue 'outer;
+                }
+            }
+        }
+
+        sum += update[update.len() / 2];
+    }
+
+    sum
+}
+
+pub fn solve_part_2(input: &Input) -> u32 {
+    let mut updates = input.updates.clone();
+
+    let mut sum = 0;
+    for update in &mut updates {
+        let mut incorrect = false;
+
+        for i in 0..update.len() - 1 {
+            for j in i + 1..update.len() {
+                let a = update[i];
+                let b = update[j];
+
+                if input.page_numbers_with_rules.contains(&a)
+                    && input.page_numbers_with_rules.contains(&b)
+                    && input.rules.contains(&[b, a])
+                {
+                    incorrect = true;
+                    update[i] = b;
+                    update[j] = a;
+                }
+            }
+        }
+        if incorrect {
+            sum += update[update.len() / 2];
+        }
+    }
+
+    sum
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Input;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> u32 {
+        solve_part_1(input)
+    }
+
+    fn part2(input: &Input) -> u32 {
+        solve_part_2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../../examples/2024/05/sample.txt");
+
+    #[test]
+    fn test_solve_part_1() {
+        let result = solve_part_1(&parse(INPUT).unwrap());
+
+        assert_eq!(result, 143);
+    }
+
+    #[test]
+    fn test_solve_part_2() {
+        let result = solve_part_2(&parse(INPUT).unwrap());
+
+        assert_eq!(result, 123);
+    }
+
+    #[test]
+    fn test_parse_malformed_rule() {
+        let input = "47|53\n97|x3\n\n75,47,61\n";
+
+        let error = parse(input).err();
+
+        assert_eq!(error, Some(ParseError::new(2, 4, "expected a number, found 'x3'")));
+    }
+}
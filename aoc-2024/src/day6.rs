@@ -0,0 +1,235 @@
+use aoc_core::{ParseError, Solution};
+use std::collections::HashSet;
+
+pub struct Input {
+    map: Vec<Vec<char>>,
+    pos: (isize, isize),
+    dir: (isize, isize),
+}
+
+/// A rectangular map of . and # with exactly one guard, facing one of ^ > v <.
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let width = input.lines().next().map_or(0, |line| line.chars().count());
+    if width == 0 {
+        return Err(ParseError::at(input, input, "expected a row of the map"));
+    }
+
+    let mut map = Vec::new();
+    let mut guard = None;
+    for (i, line) in input.lines().enumerate() {
+        if let Some(offset) = line.find(|c| !".#^>v<".contains(c)) {
+            return Err(ParseError::at(input, &line[offset..], "expected one of . # ^ > v <"));
+        }
+        let row: Vec<char> = line.chars().collect();
+        if row.len() != width {
+            let message = format!("expected {} cells, found {}", width, row.len());
+            return Err(ParseError::at(input, line, message));
+        }
+
+        for (j, value) in line.char_indices() {
+            let dir = match value {
+                '^' => (-1, 0),
+                '>' => (0, 1),
+                'v' => (1, 0),
+                '<' => (0, -1),
+                _ => continue,
+            };
+            if guard.is_some() {
+                return Err(ParseError::at(input, &line[j..], "expected a single guard"));
+            }
+            guard = Some(((i as isize, j as isize), dir));
+        }
+
+        map.push(row);
+    }
+
+    let (pos, dir) = guard.ok_or_else(|| ParseError::at_offset(input, input.len(), "expected a guard"))?;
+
+    Ok(Input { map, pos, dir })
+}
+
+pub fn solve_part_1(input: &Input) -> u32 {
+    let map = &input.map;
+    let height = map.len() as isize;
+    let width = map[0].len() as isize;
+    let mut pos = input.pos;
+    let mut dir = input.dir;
+
+    let mut positions = HashSet::new();
+    positions.insert(pos);
+    loop {
+        let (y, x) = (pos.0 + dir.0, pos.1 + dir.1);
+
+        if y < 0 || y >= height || x < 0 || x >= width {
+            break;
+        }
+
+        if map[y as usize][x as usize] == '#' {
+            if dir == (-1, 0) {
+                dir = (0, 1);
+            } else if dir == (0, 1) {
+                dir = (1, 0);
+            } else if dir == (1, 0) {
+                dir = (0, -1)
+            } else if dir == (0, -1) {
+                dir = (-1, 0);
+            } else {
+                panic!("invalid dir");
+            }
+        } else {
+            pos = (y, x);
+            positions.insert((y, x));
+        }
+    }
+
+    positions.len() as u32
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+struct State {
+    pos: (isize, isize),
+    dir: (isize, isize),
+}
+
+impl State {
+    fn next_pos(&self) -> (isize, isize) {
+        (self.pos.0 + self.dir.0, self.pos.1 + self.dir.1)
+    }
+
+    fn next_dir(&self) -> (isize, isize) {
+        let dir;
+        if self.dir == (-1, 0) {
+            dir = (0, 1);
+        } else if self.dir == (0, 1) {
+            dir = (1, 0);
+        } else if self.dir == (1, 0) {
+            dir = (0, -1);
+        } else if self.dir == (0, -1) {
+            dir = (-1, 0);
+        } else {
+            panic!("invalid dir");
+        }
+
+        dir
+    }
+}
+
+pub fn solve_part_2(input: &Input) -> u32 {
+    let map = &input.map;
+    let height = map.len() as isize;
+    let width = map[0].len() as isize;
+    let (pos, dir) = (input.pos, input.dir);
+
+    let mut sum = 0;
+    for i in 0..height {
+        for j in 0..width {
+            let cell = map[i as usize][j as usize];
+
+            if cell != '.' {
+                continue;
+            }
+
+            let mut new_map = map.clone();
+            new_map[i as usize][j as usize] = '#';
+            let mut state = State { pos, dir };
+
+            let mut positions: HashSet<State> = HashSet::new();
+            loop {
+                let (y, x) = state.next_pos();
+
+                if y < 0 || y >= height || x < 0 || x >= width {
+                    break;
+                } else {
+                    let new_state = if new_map[y as usize][x as usize] == '#' {
+                        let new_dir = state.next_dir();
+                        let new_pos = state.pos;
+
+                        State {
+                            pos: new_pos,
+                            dir: new_dir,
+                        }
+                    } else {
+                        let new_dir = state.dir;
+                        let new_pos = state.next_pos();
+
+                        State {
+                            pos: new_pos,
+                            dir: new_dir,
+                        }
+                    };
+
+                    if positions.contains(&new_state) {
+                        sum += 1;
+                        break;
+                    } else {
+                        state = new_state;
+                        positions.insert(state);
+                    }
+                }
+            }
+        }
+    }
+
+    sum
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Input;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> u32 {
+        solve_part_1(input)
+    }
+
+    fn part2(input: &Input) -> u32 {
+        solve_part_2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../../examples/2024/06/sample.txt");
+
+    #[test]
+    fn test_solve_part_1() {
+        let result = solve_part_1(&parse(INPUT).unwrap());
+
+        assert_eq!(result, 41);
+    }
+
+    #[test]
+    fn test_solve_part_2() {
+        let result = solve_part_2(&parse(INPUT).unwrap());
+
+        assert_eq!(result, 6);
+    }
+
+    #[test]
+    fn test_parse_malformed_map() {
+        assert_eq!(
+            parse("..#\n.^.\n..x\n").err(),
+            Some(ParseError::new(3, 3, "expected one of . # ^ > v <"))
+        );
+        assert_eq!(
+            parse("..#\n.^.\n.<.\n").err(),
+            Some(ParseError::new(3, 2, "expected a single guard"))
+        );
+        assert_eq!(
+            parse("..#\n...\n").err(),
+            Some(ParseError::new(3, 1, "expected a guard"))
+        );
+        assert_eq!(
+            parse("..#\n.^\n").err(),
+            Some(ParseError::new(2, 1, "expected 3 cells, found 2"))
+        );
+    }
+}
@@ -0,0 +1,9 @@
+aoc_core::days! {
+    2024;
+    1 => day1,
+    2 => day2,
+    3 => day3,
+    4 => day4,
+    5 => day5,
+    6 => day6,
+}
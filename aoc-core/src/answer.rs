@@ -0,0 +1,120 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+/// Answer to one part of a puzzle.
+///
+/// Integers of every width share one variant so that `7u8` and `7usize` are
+/// the same answer. Grids hold pictures drawn in ASCII (or any other
+/// characters), one string per row, which the puzzle wants read by eye.
+#[derive(PartialEq, Eq, Debug, Clone, Hash)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    /// Grid made of the lines of `picture`, ignoring trailing empty lines.
+    pub fn grid(picture: &str) -> Answer {
+        let mut rows: Vec<String> = picture.lines().map(|row| row.to_string()).collect();
+
+        while rows.last().is_some_and(|row| row.is_empty()) {
+            rows.pop();
+        }
+
+        Answer::Grid(rows)
+    }
+}
+
+/// Grids are printed one row per line, without a trailing newline.
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{}", n),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+/// Reads back what [`Display`](fmt::Display) wrote, like `From<&str>`.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Answer, Infallible> {
+        Ok(Answer::from(s))
+    }
+}
+
+macro_rules! from_integer {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Answer {
+                Answer::Integer(n as i128)
+            }
+        })*
+    };
+}
+
+from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize);
+
+/// Multi-line strings become grids, so renderers can keep returning a
+/// `String`. Digits spelled the way an integer prints become that integer,
+/// while ones with leading zeros stay text.
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        match text.parse::<i128>() {
+            Ok(n) if n.to_string() == text => Answer::Integer(n),
+            _ if text.trim_end().contains('\n') => Answer::grid(&text),
+            _ => Answer::Text(text),
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Answer {
+        Answer::from(text.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers_of_any_width_are_equal() {
+        assert_eq!(Answer::from(7u8), Answer::from(7usize));
+        assert_eq!(Answer::from(-3i64), Answer::Integer(-3));
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+    }
+
+    #[test]
+    fn multi_line_strings_are_grids() {
+        let answer = Answer::from(String::from("#..#\n.##.\n\n"));
+
+        assert_eq!(Answer::Grid(vec!["#..#".to_string(), ".##.".to_string()]), answer);
+        assert_eq!("#..#\n.##.", answer.to_string());
+        assert_eq!(Answer::Text("a,b".to_string()), Answer::from("a,b"));
+    }
+
+    #[test]
+    fn digit_strings_are_integers_unless_zero_padded() {
+        assert_eq!(Answer::Integer(7162937112), Answer::from("7162937112"));
+        assert_eq!(Answer::Text("0123".to_string()), Answer::from("0123"));
+    }
+
+    #[test]
+    fn display_round_trips() {
+        let answers = [
+            Answer::Integer(-42),
+            Answer::Text("0123".to_string()),
+            Answer::Text("kqzxdenujwcstybmgvyiofrrd".to_string()),
+            Answer::grid("#..#\n.##."),
+        ];
+
+        for answer in answers {
+            assert_eq!(answer, answer.to_string().parse().unwrap());
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::manifest::{entries, part};
+use crate::Answer;
+
+/// Example from a puzzle's text, with the answers given for it.
+///
+/// The examples of a day live in `<year>/<day>/` of the example directory,
+/// the day padded to two digits like inputs. Each example is a `<name>.txt`
+/// file, and `answers.txt` next to them lists the answers after the
+/// example's name and the part, in the format of a
+/// [`Manifest`](crate::Manifest):
+///
+/// ```text
+/// # example part answer
+/// sample 1 2
+/// sample 2 4
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    /// Answers of part 1 and 2. Some examples only apply to one part.
+    pub answers: [Option<Answer>; 2],
+}
+
+/// `examples/` at the repository root.
+pub fn examples_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("examples")
+}
+
+fn invalid(path: &Path, reason: impl std::fmt::Display) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), reason))
+}
+
+impl Example {
+    /// File listing the answers of a day's examples.
+    pub const ANSWERS: &'static str = "answers.txt";
+
+    pub fn dir(root: &Path, year: u32, day: u32) -> PathBuf {
+        root.join(year.to_string()).join(format!("{:02}", day))
+    }
+
+    /// Examples of the day in `root`, ordered by name. A day without a
+    /// directory has none, and an empty file stands for an example still to
+    /// be pasted in.
+    pub fn load(root: &Path, year: u32, day: u32) -> io::Result<Vec<Example>> {
+        let dir = Example::dir(root, year, day);
+        if !dir.is_dir() {
+            return Ok(Vec::new());
+        }
+
+        let answers_path = dir.join(Example::ANSWERS);
+        let text = fs::read_to_string(&answers_path)?;
+        let mut answers: BTreeMap<String, [Option<Answer>; 2]> = BTreeMap::new();
+        for entry in entries(&text, &["example", "part"]).map_err(|e| invalid(&answers_path, e))? {
+            let part = part(&entry).map_err(|e| invalid(&answers_path, e))?;
+            let slot = &mut answers.entry(entry.keys[0].clone()).or_default()[part - 1];
+            if slot.is_some() {
+                return Err(invalid(&answers_path, format!("line {}: {} part {} listed twice", entry.line, entry.keys[0], part)));
+            }
+            *slot = Some(entry.answer);
+        }
+
+        let mut examples = Vec::new();
+        for file in fs::read_dir(&dir)? {
+            let path = file?.path();
+            let name = match (path.file_stem(), path.extension()) {
+                (Some(name), Some(extension)) if extension == "txt" && path != answers_path => name.to_string_lossy().to_string(),
+                _ => continue,
+            };
+
+            let input = fs::read_to_string(&path)?;
+            if input.is_empty() {
+                continue;
+            }
+
+            let answers = answers.remove(&name).ok_or_else(|| invalid(&answers_path, format!("no answers for {}", name)))?;
+            examples.push(Example { name, input, answers });
+        }
+
+        if let Some(name) = answers.keys().next() {
+            return Err(invalid(&answers_path, format!("no example called {}", name)));
+        }
+        examples.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(examples)
+    }
+
+    /// Years and days with examples in `root`, in order.
+    pub fn days(root: &Path) -> io::Result<Vec<(u32, u32)>> {
+        let numbered = |dir: &Path| -> io::Result<Vec<(u32, PathBuf)>> {
+            let mut numbered = Vec::new();
+            for entry in fs::read_dir(dir)? {
+                let path = entry?.path();
+                if let Some(number) = path.file_name().and_then(|name| name.to_str()).and_then(|name| name.parse().ok()) {
+                    numbered.push((number, path));
+                }
+            }
+
+            Ok(numbered)
+        };
+
+        if !root.is_dir() {
+            return Ok(Vec::new());
+        }
+
+        let mut days = Vec::new();
+        for (year, dir) in numbered(root)? {
+            days.extend(numbered(&dir)?.into_iter().map(|(day, _)| (year, day)));
+        }
+        days.sort();
+
+        Ok(days)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn examples_with_answers() {
+        let root = env::temp_dir().join(format!("aoc-examples-{}", std::process::id()));
+        let dir = Example::dir(&root, 2018, 10);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("stars.txt"), "position=< 9,  1> velocity=< 0,  2>\n").unwrap();
+        fs::write(dir.join("small.txt"), "1\n").unwrap();
+        fs::write(dir.join(Example::ANSWERS), "# example part answer\nstars 1\n| #..#\n| #..#\nstars 2 3\nsmall 2 1\n").unwrap();
+
+        let examples = Example::load(&root, 2018, 10).unwrap();
+
+        assert_eq!(vec![(2018, 10)], Example::days(&root).unwrap());
+        assert_eq!(vec!["small", "stars"], examples.iter().map(|e| e.name.as_str()).collect::<Vec<_>>());
+        assert_eq!([None, Some(Answer::Integer(1))], examples[0].answers);
+        assert_eq!([Some(Answer::grid("#..#\n#..#")), Some(Answer::Integer(3))], examples[1].answers);
+        assert!(Example::load(&root, 2018, 11).unwrap().is_empty());
+
+        fs::write(dir.join("pending.txt"), "").unwrap();
+        assert_eq!(2, Example::load(&root, 2018, 10).unwrap().len());
+
+        fs::write(dir.join("other.txt"), "2\n").unwrap();
+        assert!(Example::load(&root, 2018, 10).unwrap_err().to_string().ends_with("no answers for other"));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
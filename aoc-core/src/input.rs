@@ -0,0 +1,137 @@
+use std::env;
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process;
+
+/// Environment variable naming the input directory used by default.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where puzzle input is read from.
+#[derive(PartialEq, Debug, Clone)]
+pub enum Inputs {
+    /// Directory laid out as `<year>/<day>.txt`, with the day padded to two
+    /// digits, e.g. `inputs/2022/04.txt`.
+    Directory(PathBuf),
+    /// Single file, used whatever puzzle is asked for.
+    File(PathBuf),
+    Stdin,
+}
+
+/// `$AOC_INPUT_DIR` if set, otherwise `inputs/` at the repository root.
+pub fn input_dir() -> PathBuf {
+    match env::var_os(INPUT_DIR_VAR) {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("inputs"),
+    }
+}
+
+impl Inputs {
+    /// Reads from [`input_dir`].
+    pub fn from_env() -> Inputs {
+        Inputs::Directory(input_dir())
+    }
+
+    /// Override given on the command line: `-` is stdin, anything else a file.
+    pub fn from_arg(arg: &str) -> Inputs {
+        if arg == "-" {
+            Inputs::Stdin
+        } else {
+            Inputs::File(PathBuf::from(arg))
+        }
+    }
+
+    /// Path of the input for the puzzle inside `dir`.
+    pub fn path(dir: &Path, year: u32, day: u32) -> PathBuf {
+        dir.join(year.to_string()).join(format!("{:02}.txt", day))
+    }
+
+    pub fn read(&self, year: u32, day: u32) -> Result<String, InputError> {
+        match self {
+            Inputs::Directory(dir) => {
+                let path = Inputs::path(dir, year, day);
+                let input = read_file(&path)?;
+
+                // An empty file is a placeholder for an input not downloaded yet.
+                if input.is_empty() {
+                    let error = io::Error::new(io::ErrorKind::NotFound, "input is empty");
+                    return Err(InputError { location: path.display().to_string(), error });
+                }
+
+                Ok(input)
+            }
+            Inputs::File(path) => read_file(path),
+            Inputs::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|error| InputError { location: "stdin".to_string(), error })?;
+
+                Ok(input)
+            }
+        }
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|error| InputError { location: path.display().to_string(), error })
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    /// File path, or `stdin`.
+    pub location: String,
+    pub error: io::Error,
+}
+
+impl InputError {
+    pub fn is_missing(&self) -> bool {
+        self.error.kind() == io::ErrorKind::NotFound
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.location, self.error)
+    }
+}
+
+impl error::Error for InputError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Input for the single-puzzle binaries. The first command line argument, if
+/// any, is read instead of the input directory (see [`Inputs::from_arg`]).
+/// Exits with a message when the input cannot be read.
+pub fn load(year: u32, day: u32) -> String {
+    let inputs = match env::args().nth(1) {
+        Some(arg) => Inputs::from_arg(&arg),
+        None => Inputs::from_env(),
+    };
+
+    inputs.read(year, day).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn directory_layout() {
+        let inputs = Inputs::Directory(PathBuf::from("inputs"));
+
+        assert_eq!(PathBuf::from("inputs/2022/04.txt"), Inputs::path(Path::new("inputs"), 2022, 4));
+        assert_eq!(PathBuf::from("inputs/2018/14.txt"), Inputs::path(Path::new("inputs"), 2018, 14));
+        assert_eq!(Inputs::Stdin, Inputs::from_arg("-"));
+        assert_eq!(Inputs::File(PathBuf::from("mine.txt")), Inputs::from_arg("mine.txt"));
+        assert!(inputs.read(1999, 1).unwrap_err().is_missing());
+    }
+}
@@ -0,0 +1,201 @@
+//! Shared pieces of the puzzle runner.
+//!
+//! Every day implements [`Solution`] on a unit struct called `Solver`. Crates
+//! holding several days declare them with [`days!`], which also builds the
+//! crate's `SOLUTIONS` list. The runner collects those lists in a
+//! [`Registry`] and looks puzzles up by year and day. Whatever a part
+//! returns is turned into an [`Answer`], so the runner prints and compares
+//! numbers, text and pictures alike. Puzzle input is found by year and day
+//! through [`Inputs`], the examples from the puzzle text through [`Example`].
+//! Malformed input is reported as a [`ParseError`] pointing at where it went
+//! wrong.
+
+mod answer;
+mod example;
+mod input;
+mod manifest;
+mod parse;
+
+pub use answer::Answer;
+pub use example::{examples_dir, Example};
+pub use manifest::{Manifest, ManifestError};
+pub use parse::{parse_number, parse_or_exit, ParseError};
+pub use input::{input_dir, load, InputError, Inputs, INPUT_DIR_VAR};
+
+use std::error;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+pub trait Solution {
+    /// Parsed puzzle input. May borrow from the input text.
+    type Input<'a>;
+    type Part1: Into<Answer>;
+    type Part2: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+    fn part1(input: &Self::Input<'_>) -> Self::Part1;
+    fn part2(input: &Self::Input<'_>) -> Self::Part2;
+}
+
+/// Parses the input and solves one part.
+type Solve = fn(&str) -> Result<Answer, ParseError>;
+
+/// Registered puzzle with its solution behind plain function pointers.
+#[derive(Clone, Copy)]
+pub struct Puzzle {
+    pub year: u32,
+    pub day: u32,
+    parts: [Solve; 2],
+    run: fn(&str) -> Result<Run, ParseError>,
+}
+
+/// Answers of both parts, with how long parsing and each part took.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Run {
+    pub answers: [Answer; 2],
+    pub parse: Duration,
+    /// Time spent in each part, without parsing.
+    pub parts: [Duration; 2],
+}
+
+fn solve_part_1<S: Solution>(input: &str) -> Result<Answer, ParseError> {
+    Ok(S::part1(&S::parse(input)?).into())
+}
+
+fn solve_part_2<S: Solution>(input: &str) -> Result<Answer, ParseError> {
+    Ok(S::part2(&S::parse(input)?).into())
+}
+
+fn run<S: Solution>(input: &str) -> Result<Run, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let part1 = S::part1(&parsed).into();
+    let part1_time = start.elapsed();
+
+    let start = Instant::now();
+    let part2 = S::part2(&parsed).into();
+    let part2_time = start.elapsed();
+
+    Ok(Run { answers: [part1, part2], parse, parts: [part1_time, part2_time] })
+}
+
+impl Puzzle {
+    pub const fn new<S: Solution>(year: u32, day: u32) -> Puzzle {
+        Puzzle { year, day, parts: [solve_part_1::<S>, solve_part_2::<S>], run: run::<S> }
+    }
+
+    /// Parses `input` and solves `part`, which is 1 or 2.
+    pub fn solve(&self, part: usize, input: &str) -> Result<Answer, ParseError> {
+        (self.parts[part - 1])(input)
+    }
+
+    /// Parses `input` once and solves both parts, timing each step.
+    pub fn run(&self, input: &str) -> Result<Run, ParseError> {
+        (self.run)(input)
+    }
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct NotSolved {
+    pub year: u32,
+    pub day: u32,
+}
+
+impl fmt::Display for NotSolved {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} day {} is not solved yet", self.year, self.day)
+    }
+}
+
+impl error::Error for NotSolved {}
+
+#[derive(Default)]
+pub struct Registry {
+    puzzles: Vec<Puzzle>,
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        Registry::default()
+    }
+
+    /// Adds `puzzles`, keeping the registry ordered by year and day.
+    pub fn register(&mut self, puzzles: &[Puzzle]) {
+        self.puzzles.extend_from_slice(puzzles);
+        self.puzzles.sort_by_key(|p| (p.year, p.day));
+    }
+
+    pub fn get(&self, year: u32, day: u32) -> Result<&Puzzle, NotSolved> {
+        self.puzzles
+            .iter()
+            .find(|p| p.year == year && p.day == day)
+            .ok_or(NotSolved { year, day })
+    }
+
+    pub fn puzzles(&self) -> &[Puzzle] {
+        &self.puzzles
+    }
+}
+
+/// Declares one module per day and lists their `Solver`s in `SOLUTIONS`:
+///
+/// ```ignore
+/// aoc_core::days! {
+///     2022;
+///     1 => day_01,
+///     2 => day_02,
+/// }
+/// ```
+#[macro_export]
+macro_rules! days {
+    ($year:expr; $($day:expr => $module:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        pub const SOLUTIONS: &[$crate::Puzzle] = &[$($crate::Puzzle::new::<$module::Solver>($year, $day)),*];
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input<'a> = Vec<&'a str>;
+        type Part1 = usize;
+        type Part2 = String;
+
+        fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+            match input.find(' ') {
+                Some(offset) => Err(ParseError::at_offset(input, offset, "unexpected space")),
+                None => Ok(input.split(',').collect()),
+            }
+        }
+
+        fn part1(input: &Vec<&str>) -> usize {
+            input.len()
+        }
+
+        fn part2(input: &Vec<&str>) -> String {
+            input.concat()
+        }
+    }
+
+    #[test]
+    fn registry_lookup() {
+        let mut registry = Registry::new();
+        registry.register(&[Puzzle::new::<Sum>(2020, 2), Puzzle::new::<Sum>(2019, 5)]);
+
+        assert_eq!(vec![(2019, 5), (2020, 2)], registry.puzzles().iter().map(|p| (p.year, p.day)).collect::<Vec<_>>());
+        assert_eq!(Answer::Integer(3), registry.get(2020, 2).unwrap().solve(1, "a,b,c").unwrap());
+        assert_eq!(Answer::from("abc"), registry.get(2019, 5).unwrap().solve(2, "a,b,c").unwrap());
+        assert_eq!([Answer::Integer(2), Answer::from("xy")], registry.get(2019, 5).unwrap().run("x,y").unwrap().answers);
+        assert_eq!(Err(ParseError::new(1, 3, "unexpected space")), registry.get(2019, 5).unwrap().solve(1, "x, y"));
+        assert_eq!(Some(NotSolved { year: 2020, day: 3 }), registry.get(2020, 3).err());
+        assert_eq!("2020 day 3 is not solved yet", NotSolved { year: 2020, day: 3 }.to_string());
+    }
+}
@@ -0,0 +1,208 @@
+use std::collections::BTreeMap;
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::Answer;
+
+/// Verified answers, keyed by year, day and part.
+///
+/// Stored as text, one answer per line after its year, day and part. Grid
+/// answers leave the answer out and put their rows on the following lines,
+/// each after `| `:
+///
+/// ```text
+/// # year day part answer
+/// 2018 10 1
+/// | #....#
+/// | ######
+/// 2018 10 2 10312
+/// ```
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct Manifest {
+    answers: BTreeMap<(u32, u32, usize), Answer>,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct ManifestError {
+    /// One based line number in the parsed text.
+    pub line: usize,
+    pub reason: String,
+}
+
+impl fmt::Display for ManifestError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.reason)
+    }
+}
+
+impl error::Error for ManifestError {}
+
+impl Manifest {
+    /// File name of the manifest inside the input directory.
+    pub const FILE: &'static str = "answers.txt";
+
+    pub fn new() -> Manifest {
+        Manifest::default()
+    }
+
+    pub fn from_text(text: &str) -> Result<Manifest, ManifestError> {
+        let mut manifest = Manifest::new();
+
+        for entry in entries(text, &["year", "day", "part"])? {
+            let error = |reason: String| ManifestError { line: entry.line, reason };
+            let number = |field: &str| field.parse().map_err(|_| error(format!("invalid number '{}'", field)));
+
+            let (year, day, part) = (number(&entry.keys[0])?, number(&entry.keys[1])?, part(&entry)?);
+            let key = (year, day, part);
+            if manifest.answers.contains_key(&key) {
+                return Err(error(format!("{} day {} part {} listed twice", year, day, part)));
+            }
+
+            manifest.answers.insert(key, entry.answer);
+        }
+
+        Ok(manifest)
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = String::from("# year day part answer\n");
+
+        for (&(year, day, part), answer) in &self.answers {
+            match answer {
+                Answer::Grid(rows) => {
+                    text.push_str(&format!("{} {} {}\n", year, day, part));
+                    for row in rows {
+                        text.push_str(&format!("| {}\n", row));
+                    }
+                }
+                answer => text.push_str(&format!("{} {} {} {}\n", year, day, part, answer)),
+            }
+        }
+
+        text
+    }
+
+    pub fn load(path: &Path) -> io::Result<Manifest> {
+        let text = fs::read_to_string(path)?;
+
+        Manifest::from_text(&text)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_text())
+    }
+
+    pub fn get(&self, year: u32, day: u32, part: usize) -> Option<&Answer> {
+        self.answers.get(&(year, day, part))
+    }
+
+    pub fn insert(&mut self, year: u32, day: u32, part: usize, answer: Answer) {
+        self.answers.insert((year, day, part), answer);
+    }
+
+    /// Every answer as `((year, day, part), answer)`, in order.
+    pub fn answers(&self) -> impl Iterator<Item = (&(u32, u32, usize), &Answer)> {
+        self.answers.iter()
+    }
+}
+
+/// Answer listed after its keys, `line` being where it starts.
+pub(crate) struct Entry {
+    pub line: usize,
+    pub keys: Vec<String>,
+    pub answer: Answer,
+}
+
+/// Parses lines of answers after the given keys, in the format described at
+/// [`Manifest`].
+pub(crate) fn entries(text: &str, keys: &[&str]) -> Result<Vec<Entry>, ManifestError> {
+    let mut entries = Vec::new();
+    let mut grid: Option<(usize, Vec<String>, Vec<String>)> = None;
+
+    for (i, line) in text.lines().enumerate() {
+        let error = |reason: String| ManifestError { line: i + 1, reason };
+
+        // An empty row may have lost its trailing space to an editor.
+        if let Some(row) = line.strip_prefix("| ").or((line == "|").then_some("")) {
+            match grid.as_mut() {
+                Some((_, _, rows)) => rows.push(row.to_string()),
+                None => return Err(error("grid row without an answer before it".to_string())),
+            }
+            continue;
+        }
+
+        if let Some((line, keys, rows)) = grid.take() {
+            entries.push(Entry { line, keys, answer: Answer::Grid(rows) });
+        }
+
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut fields: Vec<String> = line.splitn(keys.len() + 1, ' ').map(str::to_string).collect();
+        if fields.len() < keys.len() {
+            return Err(error(format!("expected {} in '{}'", keys.join(", "), line)));
+        }
+
+        if fields.len() > keys.len() {
+            let answer = Answer::from(fields.pop().unwrap());
+            entries.push(Entry { line: i + 1, keys: fields, answer });
+        } else {
+            grid = Some((i + 1, fields, Vec::new()));
+        }
+    }
+
+    if let Some((line, keys, rows)) = grid {
+        entries.push(Entry { line, keys, answer: Answer::Grid(rows) });
+    }
+
+    Ok(entries)
+}
+
+/// Part number of an entry whose last key is the part.
+pub(crate) fn part(entry: &Entry) -> Result<usize, ManifestError> {
+    match entry.keys.last().map(String::as_str) {
+        Some("1") => Ok(1),
+        Some("2") => Ok(2),
+        part => Err(ManifestError { line: entry.line, reason: format!("invalid part {}", part.unwrap_or("")) }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "# year day part answer
+2018 2 2 kqzxdenujwcstybmgvyiofrrd
+2018 10 1
+| #....#
+| ######
+2018 10 2 10312
+";
+
+    #[test]
+    fn text_round_trip() {
+        let manifest = Manifest::from_text(TEXT).unwrap();
+
+        assert_eq!(Some(&Answer::Integer(10312)), manifest.get(2018, 10, 2));
+        assert_eq!(Some(&Answer::grid("#....#\n######")), manifest.get(2018, 10, 1));
+        assert_eq!(Some(&Answer::from("kqzxdenujwcstybmgvyiofrrd")), manifest.get(2018, 2, 2));
+        assert_eq!(None, manifest.get(2018, 2, 1));
+        assert_eq!(TEXT, manifest.to_text());
+    }
+
+    #[test]
+    fn invalid_manifests() {
+        let error = |text| Manifest::from_text(text).unwrap_err();
+
+        assert_eq!(ManifestError { line: 1, reason: "grid row without an answer before it".to_string() }, error("| #"));
+        assert_eq!(ManifestError { line: 2, reason: "invalid part 3".to_string() }, error("2018 1 1 5\n2018 1 3 5"));
+        assert_eq!(ManifestError { line: 2, reason: "2018 day 1 part 1 listed twice".to_string() }, error("2018 1 1 5\n2018 1 1 6"));
+        assert_eq!(ManifestError { line: 1, reason: "invalid number 'x'".to_string() }, error("2018 x 1 5"));
+    }
+}
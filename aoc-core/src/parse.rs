@@ -0,0 +1,112 @@
+use std::error;
+use std::fmt;
+use std::process;
+use std::str::FromStr;
+
+/// Malformed puzzle input, with where in the input it was found.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ParseError {
+    /// One based line number.
+    pub line: usize,
+    /// One based column, counted in characters.
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> ParseError {
+        ParseError { line, column, message: message.into() }
+    }
+
+    /// Error at the start of `part`, a slice of `input` such as a line or a
+    /// field split off it. Anything else is taken to be at the end of the
+    /// input.
+    pub fn at(input: &str, part: &str, message: impl Into<String>) -> ParseError {
+        let start = input.as_ptr() as usize;
+        let offset = (part.as_ptr() as usize).checked_sub(start).filter(|&offset| offset <= input.len());
+
+        ParseError::at_offset(input, offset.unwrap_or(input.len()), message)
+    }
+
+    /// Error at byte `offset` of `input`.
+    pub fn at_offset(input: &str, offset: usize, message: impl Into<String>) -> ParseError {
+        let mut offset = offset.min(input.len());
+        while !input.is_char_boundary(offset) {
+            offset -= 1;
+        }
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        ParseError::new(before.matches('\n').count() + 1, before[line_start..].chars().count() + 1, message)
+    }
+
+    /// The error followed by the line of `input` it is on, marked at the
+    /// column:
+    ///
+    /// ```text
+    /// line 3, column 4: expected a number, found 'x3'
+    ///   |
+    /// 3 | 47|x3
+    ///   |    ^
+    /// ```
+    pub fn diagnostic(&self, input: &str) -> String {
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let text = self.line.checked_sub(1).and_then(|n| input.lines().nth(n)).unwrap_or("");
+
+        format!(
+            "{}\n{} |\n{} | {}\n{} | {}^",
+            self,
+            gutter,
+            number,
+            text,
+            gutter,
+            " ".repeat(self.column.saturating_sub(1))
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Parses `field`, a slice of `input`, as a number.
+pub fn parse_number<T: FromStr>(input: &str, field: &str) -> Result<T, ParseError> {
+    field
+        .parse()
+        .map_err(|_| ParseError::at(input, field, format!("expected a number, found '{}'", field)))
+}
+
+/// Parsed input for the single-puzzle binaries. Exits with a diagnostic when
+/// the input is malformed.
+pub fn parse_or_exit<'a, T>(input: &'a str, parse: impl FnOnce(&'a str) -> Result<T, ParseError>) -> T {
+    parse(input).unwrap_or_else(|e| {
+        eprintln!("{}", e.diagnostic(input));
+        process::exit(1);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions_and_diagnostics() {
+        let input = "47|53\n97|x3\n";
+        let line = input.lines().nth(1).unwrap();
+        let field = line.split('|').nth(1).unwrap();
+
+        let error = parse_number::<u32>(input, field).unwrap_err();
+
+        assert_eq!(ParseError::new(2, 4, "expected a number, found 'x3'"), error);
+        assert_eq!("line 2, column 4: expected a number, found 'x3'\n  |\n2 | 97|x3\n  |    ^", error.diagnostic(input));
+        assert_eq!(ParseError::new(3, 1, "missing"), ParseError::at(input, "elsewhere", "missing"));
+        assert_eq!(ParseError::new(1, 2, "é"), ParseError::at_offset("éé", 3, "é"));
+        assert_eq!("line 0, column 0: bad\n  |\n0 | \n  | ^", ParseError::new(0, 0, "bad").diagnostic(input));
+    }
+}
@@ -0,0 +1,104 @@
+//! Generates a test for every day with examples in `examples/`, so adding one
+//! takes no Rust. Days whose examples are all still empty placeholders get
+//! none.
+//!
+//! Also lists the puzzles of the years kept as one crate per day, every
+//! `<year>/day<day>` crate of the `<year>/day*` workspace members, so they
+//! need no registering by hand.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+/// Entries of `dir` named by a number.
+fn numbered(dir: &Path) -> Vec<u32> {
+    let mut numbers: Vec<u32> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse().ok())
+        .collect();
+    numbers.sort();
+
+    numbers
+}
+
+/// Whether `dir` holds an example that is not empty.
+fn has_example(dir: &Path) -> bool {
+    fs::read_dir(dir).into_iter().flatten().filter_map(|entry| entry.ok()).any(|entry| {
+        let path = entry.path();
+        path.extension().is_some_and(|extension| extension == "txt")
+            && path.file_name().is_some_and(|name| name != "answers.txt")
+            && fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0)
+    })
+}
+
+/// Years whose days are workspace members as `"<year>/day*"`.
+fn single_day_years(manifest: &str) -> Vec<u32> {
+    manifest
+        .lines()
+        .filter_map(|line| line.trim().trim_end_matches(',').trim_matches('"').strip_suffix("/day*")?.parse().ok())
+        .collect()
+}
+
+/// Days of `year_dir` with a crate of their own, in `day<day>/`.
+fn single_day_crates(year_dir: &Path) -> Vec<u32> {
+    let mut days: Vec<u32> = fs::read_dir(year_dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let day = entry.file_name().to_str()?.strip_prefix("day")?.parse().ok()?;
+            entry.path().join("Cargo.toml").is_file().then_some(day)
+        })
+        .collect();
+    days.sort();
+
+    days
+}
+
+fn write_single_day_crates(out: &Path) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let manifest = root.join("Cargo.toml");
+    println!("cargo:rerun-if-changed={}", manifest.display());
+
+    let mut puzzles = String::new();
+    for year in single_day_years(&fs::read_to_string(manifest).unwrap()) {
+        let year_dir = root.join(year.to_string());
+        println!("cargo:rerun-if-changed={}", year_dir.display());
+
+        for day in single_day_crates(&year_dir) {
+            puzzles.push_str(&format!(
+                "    Puzzle::new::<year{year}_day{day}::Solver>({year}, {day}),\n",
+                year = year,
+                day = day
+            ));
+        }
+    }
+
+    let list = format!("/// Years kept as one crate per day, so they have no `SOLUTIONS` list of their own.\nconst SINGLE_DAY_CRATES: &[Puzzle] = &[\n{}];\n", puzzles);
+    fs::write(out.join("single_day_crates.rs"), list).unwrap();
+}
+
+fn main() {
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).to_path_buf();
+    write_single_day_crates(&out);
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("examples");
+    println!("cargo:rerun-if-changed={}", root.display());
+
+    let mut tests = String::new();
+    for year in numbered(&root) {
+        for day in numbered(&root.join(year.to_string())) {
+            if !has_example(&root.join(year.to_string()).join(format!("{:02}", day))) {
+                continue;
+            }
+            tests.push_str(&format!(
+                "#[test]\nfn examples_{year}_{day:02}() {{\n    check_examples({year}, {day});\n}}\n\n",
+                year = year,
+                day = day
+            ));
+        }
+    }
+
+    fs::write(out.join("example_tests.rs"), tests).unwrap();
+}
@@ -0,0 +1,247 @@
+//! Timing parsing and both parts of every solution.
+//!
+//! Each puzzle runs a few times to warm up, then the requested number of
+//! samples is kept. Results can be saved as JSON and compared with an earlier
+//! run to spot regressions.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+use aoc_core::{Inputs, ParseError, Puzzle, Registry};
+use serde::{Deserialize, Serialize};
+
+use crate::watch;
+
+/// Median and 95th percentile of the samples of one step, in nanoseconds.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, Default)]
+pub struct Stats {
+    pub median_ns: u64,
+    pub p95_ns: u64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        if samples.is_empty() {
+            return Stats::default();
+        }
+
+        let mut nanos: Vec<u64> = samples.iter().map(|d| d.as_nanos() as u64).collect();
+        nanos.sort_unstable();
+
+        let middle = nanos.len() / 2;
+        let median_ns = if nanos.len().is_multiple_of(2) { (nanos[middle - 1] + nanos[middle]) / 2 } else { nanos[middle] };
+        // Nearest rank.
+        let p95_ns = nanos[(nanos.len() * 95).div_ceil(100) - 1];
+
+        Stats { median_ns, p95_ns }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct DayResult {
+    pub year: u32,
+    pub day: u32,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+    /// Of whole runs, so the p95 is that of the runs and not a sum.
+    #[serde(default)]
+    pub total: Stats,
+}
+
+impl DayResult {
+    fn stages(&self) -> [(&'static str, Stats); 3] {
+        [("parse", self.parse), ("part 1", self.part1), ("part 2", self.part2)]
+    }
+}
+
+/// Saved results of a whole benchmark run.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct Report {
+    pub warmup: usize,
+    pub samples: usize,
+    pub days: Vec<DayResult>,
+}
+
+impl Report {
+    pub fn load(path: &Path) -> io::Result<Report> {
+        serde_json::from_str(&fs::read_to_string(path)?).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)? + "\n")
+    }
+}
+
+pub fn measure(puzzle: &Puzzle, input: &str, warmup: usize, samples: usize) -> Result<DayResult, ParseError> {
+    for _ in 0..warmup {
+        puzzle.run(input)?;
+    }
+
+    let runs = (0..samples).map(|_| puzzle.run(input)).collect::<Result<Vec<_>, _>>()?;
+    let stats = |time: fn(&aoc_core::Run) -> Duration| Stats::from_samples(&runs.iter().map(time).collect::<Vec<_>>());
+
+    Ok(DayResult {
+        year: puzzle.year,
+        day: puzzle.day,
+        parse: stats(|run| run.parse),
+        part1: stats(|run| run.parts[0]),
+        part2: stats(|run| run.parts[1]),
+        total: stats(|run| run.parse + run.parts[0] + run.parts[1]),
+    })
+}
+
+/// Benchmarks the selected puzzles whose input is in `inputs`, printing a row
+/// for each day as it finishes and one for each year. A solution that panics
+/// is skipped. Percentiles do not add up, so a year only shows the sum of its
+/// medians.
+pub fn run(registry: &Registry, inputs: &Inputs, selected: impl Fn(u32, u32) -> bool, warmup: usize, samples: usize) -> Report {
+    let mut report = Report { warmup, samples, days: Vec::new() };
+
+    println!("{:<12} {:>21} {:>21} {:>21} {:>21}", "median/p95", "parse", "part 1", "part 2", "total");
+
+    let puzzles: Vec<&Puzzle> = registry.puzzles().iter().filter(|p| selected(p.year, p.day)).collect();
+    for (i, puzzle) in puzzles.iter().enumerate() {
+        match inputs.read(puzzle.year, puzzle.day).map(|input| watch::catch(|| measure(puzzle, &input, warmup, samples))) {
+            Ok(Ok(Ok(result))) => {
+                println!("{}", row(&format!("{} day {}", puzzle.year, puzzle.day), &result));
+                report.days.push(result);
+            }
+            Ok(Ok(Err(e))) => println!("{} day {}: skipped, malformed input at {}", puzzle.year, puzzle.day, e),
+            Ok(Err(e)) => println!("{} day {}: skipped, panicked: {}", puzzle.year, puzzle.day, e),
+            Err(e) => println!("{} day {}: skipped, {}", puzzle.year, puzzle.day, e),
+        }
+
+        let year_done = puzzles.get(i + 1).is_none_or(|next| next.year != puzzle.year);
+        if year_done {
+            let days: Vec<&DayResult> = report.days.iter().filter(|d| d.year == puzzle.year).collect();
+            let sum = |stage: fn(&DayResult) -> Stats| format!("sum {}", format_nanos(days.iter().map(|&d| stage(d).median_ns).sum()));
+            println!(
+                "{:<12} {:>21} {:>21} {:>21} {:>21}",
+                format!("{} total", puzzle.year),
+                sum(|d| d.parse),
+                sum(|d| d.part1),
+                sum(|d| d.part2),
+                sum(|d| d.total)
+            );
+        }
+    }
+
+    report
+}
+
+fn row(label: &str, result: &DayResult) -> String {
+    let cell = |stats: Stats| format!("{} / {}", format_nanos(stats.median_ns), format_nanos(stats.p95_ns));
+
+    format!(
+        "{:<12} {:>21} {:>21} {:>21} {:>21}",
+        label,
+        cell(result.parse),
+        cell(result.part1),
+        cell(result.part2),
+        cell(result.total)
+    )
+}
+
+pub fn format_nanos(nanos: u64) -> String {
+    match nanos {
+        0..=999 => format!("{}ns", nanos),
+        1_000..=999_999 => format!("{:.1}µs", nanos as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.1}ms", nanos as f64 / 1e6),
+        _ => format!("{:.2}s", nanos as f64 / 1e9),
+    }
+}
+
+/// Differences smaller than this are noise, however large relatively.
+const NOISE_NS: u64 = 50_000;
+
+/// Step whose median moved by more than the threshold between two reports.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Change {
+    pub year: u32,
+    pub day: u32,
+    pub stage: &'static str,
+    pub before_ns: u64,
+    pub after_ns: u64,
+}
+
+impl Change {
+    pub fn is_regression(&self) -> bool {
+        self.after_ns > self.before_ns
+    }
+}
+
+/// Medians of the days in both reports that changed by more than `threshold`,
+/// a fraction of the baseline time.
+pub fn compare(baseline: &Report, current: &Report, threshold: f64) -> Vec<Change> {
+    let mut changes = Vec::new();
+
+    for day in &current.days {
+        let before = match baseline.days.iter().find(|d| d.year == day.year && d.day == day.day) {
+            Some(before) => before,
+            None => continue,
+        };
+
+        for ((stage, after), (_, before)) in day.stages().into_iter().zip(before.stages()) {
+            let (before_ns, after_ns) = (before.median_ns, after.median_ns);
+            if before_ns.abs_diff(after_ns) > NOISE_NS && before_ns.abs_diff(after_ns) as f64 > before_ns as f64 * threshold {
+                changes.push(Change { year: day.year, day: day.day, stage, before_ns, after_ns });
+            }
+        }
+    }
+
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&ms| Duration::from_millis(ms)).collect()
+    }
+
+    fn day(day: u32, parse_ms: u64, part1_ms: u64, part2_ms: u64) -> DayResult {
+        let stats = |ms: u64| Stats { median_ns: ms * 1_000_000, p95_ns: ms * 1_000_000 };
+
+        let total = stats(parse_ms + part1_ms + part2_ms);
+
+        DayResult { year: 2018, day, parse: stats(parse_ms), part1: stats(part1_ms), part2: stats(part2_ms), total }
+    }
+
+    #[test]
+    fn median_and_p95() {
+        let stats = Stats::from_samples(&millis(&[5, 1, 4, 2, 3]));
+        assert_eq!(Stats { median_ns: 3_000_000, p95_ns: 5_000_000 }, stats);
+
+        let samples: Vec<u64> = (1..=40).rev().collect();
+        let stats = Stats::from_samples(&millis(&samples));
+        assert_eq!(Stats { median_ns: 20_500_000, p95_ns: 38_000_000 }, stats);
+
+        assert_eq!(Stats::default(), Stats::from_samples(&[]));
+        assert_eq!("1.5ms", format_nanos(1_500_000));
+        assert_eq!("2.00s", format_nanos(2_000_000_000));
+    }
+
+    #[test]
+    fn regressions_beyond_threshold() {
+        let baseline = Report { warmup: 1, samples: 10, days: vec![day(9, 1, 100, 1000), day(14, 0, 0, 0)] };
+        let current = Report { warmup: 1, samples: 10, days: vec![day(9, 1, 105, 1500), day(12, 1, 1, 1), day(14, 0, 0, 0)] };
+
+        let changes = compare(&baseline, &current, 0.1);
+
+        assert_eq!(
+            vec![Change { year: 2018, day: 9, stage: "part 2", before_ns: 1_000_000_000, after_ns: 1_500_000_000 }],
+            changes
+        );
+        assert!(changes[0].is_regression());
+        assert_eq!(current, serde_json::from_str(&serde_json::to_string(&current).unwrap()).unwrap());
+
+        // Saved before days had a total.
+        let stats = r#"{"median_ns":1,"p95_ns":2}"#;
+        let old = format!(r#"{{"year":2018,"day":9,"parse":{0},"part1":{0},"part2":{0}}}"#, stats);
+        assert_eq!(Stats::default(), serde_json::from_str::<DayResult>(&old).unwrap().total);
+    }
+}
@@ -0,0 +1,354 @@
+//! Running solutions against their inputs and the verified answers.
+
+use std::fmt;
+use std::path::Path;
+
+use aoc_core::{Answer, Example, Inputs, Manifest, ParseError, Puzzle, Registry};
+
+use crate::watch;
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum Outcome {
+    Correct,
+    Wrong { expected: Answer, actual: Answer },
+    /// Solved, but the manifest has no answer to compare with.
+    Unverified(Answer),
+    MissingInput(String),
+    /// The solution rejected the input, with the diagnostic pointing at where.
+    MalformedInput { error: ParseError, diagnostic: String },
+    /// The solution panicked, with the panic message. Counted as wrong.
+    Panicked(String),
+    /// The manifest has an answer, but no solution is registered.
+    NotSolved,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Check {
+    pub year: u32,
+    pub day: u32,
+    pub part: usize,
+    /// Name of the example checked, if not the real input.
+    pub example: Option<String>,
+    pub outcome: Outcome,
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} day {} part {}", self.year, self.day, self.part)?;
+        if let Some(example) = &self.example {
+            write!(f, ", example {}", example)?;
+        }
+        write!(f, ": ")?;
+
+        match &self.outcome {
+            Outcome::Correct => write!(f, "ok"),
+            Outcome::Wrong { expected, actual } => {
+                let separator = if matches!(expected, Answer::Grid(_)) || matches!(actual, Answer::Grid(_)) { "\n" } else { " " };
+                write!(f, "WRONG, expected{}{}{}got{}{}", separator, expected, separator, separator, actual)
+            }
+            Outcome::Unverified(answer) if matches!(answer, Answer::Grid(_)) => write!(f, "no verified answer, got\n{}", answer),
+            Outcome::Unverified(answer) => write!(f, "no verified answer, got {}", answer),
+            Outcome::MissingInput(location) => write!(f, "MISSING INPUT {}", location),
+            Outcome::MalformedInput { diagnostic, .. } => write!(f, "MALFORMED INPUT\n{}", diagnostic),
+            Outcome::Panicked(message) => write!(f, "PANICKED: {}", message),
+            Outcome::NotSolved => write!(f, "NOT SOLVED"),
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub struct Summary {
+    pub correct: usize,
+    pub wrong: usize,
+    pub unverified: usize,
+    pub missing_inputs: usize,
+    pub malformed_inputs: usize,
+    pub not_solved: usize,
+}
+
+impl Summary {
+    pub fn add(&mut self, outcome: &Outcome) {
+        match outcome {
+            Outcome::Correct => self.correct += 1,
+            Outcome::Wrong { .. } | Outcome::Panicked(_) => self.wrong += 1,
+            Outcome::Unverified(_) => self.unverified += 1,
+            Outcome::MissingInput(_) => self.missing_inputs += 1,
+            Outcome::MalformedInput { .. } => self.malformed_inputs += 1,
+            Outcome::NotSolved => self.not_solved += 1,
+        }
+    }
+
+    /// Unverified answers do not fail a check, everything else but correct
+    /// ones does.
+    pub fn passed(&self) -> bool {
+        self.wrong == 0 && self.missing_inputs == 0 && self.malformed_inputs == 0 && self.not_solved == 0
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} correct, {} wrong, {} unverified, {} missing inputs, {} malformed inputs, {} not solved",
+            self.correct, self.wrong, self.unverified, self.missing_inputs, self.malformed_inputs, self.not_solved
+        )
+    }
+}
+
+fn malformed(error: ParseError, input: &str) -> Outcome {
+    let diagnostic = error.diagnostic(input);
+
+    Outcome::MalformedInput { error, diagnostic }
+}
+
+/// Solves the part of `input` and compares the answer with `expected`. A
+/// panicking solution does not take the remaining checks down with it.
+fn solve(puzzle: &Puzzle, part: usize, input: &str, expected: Option<&Answer>) -> Outcome {
+    match (watch::catch(|| puzzle.solve(part, input)), expected) {
+        (Err(message), _) => Outcome::Panicked(message),
+        (Ok(Err(error)), _) => malformed(error, input),
+        (Ok(Ok(actual)), Some(expected)) if *expected == actual => Outcome::Correct,
+        (Ok(Ok(actual)), Some(expected)) => Outcome::Wrong { expected: expected.clone(), actual },
+        (Ok(Ok(actual)), None) => Outcome::Unverified(actual),
+    }
+}
+
+/// Checks every part of the selected puzzles that is either registered or in
+/// the manifest, in order, calling `report` after each.
+pub fn run(
+    registry: &Registry,
+    inputs: &Inputs,
+    manifest: &Manifest,
+    selected: impl Fn(u32, u32) -> bool,
+    mut report: impl FnMut(&Check),
+) -> Summary {
+    let mut days: Vec<(u32, u32)> = registry.puzzles().iter().map(|p| (p.year, p.day)).collect();
+    days.extend(manifest.answers().map(|(&(year, day, _), _)| (year, day)));
+    days.sort();
+    days.dedup();
+
+    let mut summary = Summary::default();
+
+    for (year, day) in days.into_iter().filter(|&(year, day)| selected(year, day)) {
+        let puzzle = registry.get(year, day).ok();
+        let input = puzzle.map(|_| inputs.read(year, day));
+
+        for part in 1..=2 {
+            let expected = manifest.get(year, day, part);
+            let outcome = match (puzzle, &input, expected) {
+                (None, _, None) => continue,
+                (None, _, Some(_)) => Outcome::NotSolved,
+                (Some(_), Some(Err(e)), _) => Outcome::MissingInput(e.to_string()),
+                (Some(puzzle), Some(Ok(input)), expected) => solve(puzzle, part, input, expected),
+                (Some(_), None, _) => unreachable!("input is read for every registered puzzle"),
+            };
+
+            summary.add(&outcome);
+            report(&Check { year, day, part, example: None, outcome });
+        }
+    }
+
+    summary
+}
+
+/// Checks the examples of the selected days in `root` like [`run`] does the
+/// real inputs.
+pub fn examples(
+    registry: &Registry,
+    root: &Path,
+    selected: impl Fn(u32, u32) -> bool,
+    mut report: impl FnMut(&Check),
+) -> Result<Summary, String> {
+    let mut summary = Summary::default();
+
+    for (year, day) in Example::days(root).map_err(|e| e.to_string())? {
+        if !selected(year, day) {
+            continue;
+        }
+
+        let puzzle = registry.get(year, day).ok();
+        for example in Example::load(root, year, day).map_err(|e| e.to_string())? {
+            for (part, expected) in (1..=2).zip(&example.answers) {
+                let expected = match expected {
+                    Some(expected) => expected,
+                    None => continue,
+                };
+                let outcome = match puzzle {
+                    None => Outcome::NotSolved,
+                    Some(puzzle) => solve(puzzle, part, &example.input, Some(expected)),
+                };
+
+                summary.add(&outcome);
+                report(&Check { year, day, part, example: Some(example.name.clone()), outcome });
+            }
+        }
+    }
+
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+    use std::env;
+    use std::fs;
+
+    struct Lines;
+
+    impl Solution for Lines {
+        type Input<'a> = Vec<&'a str>;
+        type Part1 = usize;
+        type Part2 = String;
+
+        fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+            match input.lines().find(|line| line.is_empty()) {
+                Some(line) => Err(ParseError::at(input, line, "empty line")),
+                None => Ok(input.lines().collect()),
+            }
+        }
+
+        fn part1(lines: &Vec<&str>) -> usize {
+            lines.len()
+        }
+
+        fn part2(lines: &Vec<&str>) -> String {
+            lines.join("\n")
+        }
+    }
+
+    struct Panics;
+
+    impl Solution for Panics {
+        type Input<'a> = &'a str;
+        type Part1 = usize;
+        type Part2 = usize;
+
+        fn parse(input: &str) -> Result<&str, ParseError> {
+            Ok(input)
+        }
+
+        fn part1(input: &&str) -> usize {
+            input.len()
+        }
+
+        fn part2(_: &&str) -> usize {
+            panic!("part 2 panics")
+        }
+    }
+
+    #[test]
+    fn panics_are_counted_as_wrong() {
+        let dir = env::temp_dir().join(format!("aoc-check-panics-{}", std::process::id()));
+        fs::create_dir_all(dir.join("2022")).unwrap();
+        fs::write(Inputs::path(&dir, 2022, 1), "ab").unwrap();
+        fs::write(Inputs::path(&dir, 2022, 2), "a\nb").unwrap();
+
+        let mut registry = Registry::new();
+        registry.register(&[Puzzle::new::<Panics>(2022, 1), Puzzle::new::<Lines>(2022, 2)]);
+        let manifest = Manifest::from_text("2022 1 1 2\n2022 1 2 2\n2022 2 1 2\n").unwrap();
+
+        let mut checks = Vec::new();
+        let summary = run(&registry, &Inputs::Directory(dir.clone()), &manifest, |_, _| true, |c| checks.push(c.to_string()));
+
+        assert_eq!(
+            vec![
+                "2022 day 1 part 1: ok",
+                "2022 day 1 part 2: PANICKED: part 2 panics",
+                "2022 day 2 part 1: ok",
+                "2022 day 2 part 2: no verified answer, got\na\nb",
+            ],
+            checks
+        );
+        assert_eq!(Summary { correct: 2, wrong: 1, unverified: 1, missing_inputs: 0, malformed_inputs: 0, not_solved: 0 }, summary);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn reports_every_outcome() {
+        let dir = env::temp_dir().join(format!("aoc-check-{}", std::process::id()));
+        fs::create_dir_all(dir.join("2022")).unwrap();
+        fs::write(Inputs::path(&dir, 2022, 1), "#.\n.#\n").unwrap();
+        fs::write(Inputs::path(&dir, 2022, 4), "#.\n\n.#\n").unwrap();
+
+        let mut registry = Registry::new();
+        registry.register(&[
+            Puzzle::new::<Lines>(2022, 1),
+            Puzzle::new::<Lines>(2022, 2),
+            Puzzle::new::<Lines>(2022, 4),
+            Puzzle::new::<Lines>(2023, 1),
+        ]);
+        let manifest = Manifest::from_text("2022 1 1 2\n2022 1 2\n| #.\n| ##\n2022 3 1 7\n").unwrap();
+
+        let mut checks = Vec::new();
+        let summary = run(&registry, &Inputs::Directory(dir.clone()), &manifest, |year, _| year == 2022, |c| checks.push(c.clone()));
+
+        let outcomes: Vec<(u32, usize, &Outcome)> = checks.iter().map(|c| (c.day, c.part, &c.outcome)).collect();
+        assert_eq!(Outcome::Correct, *outcomes[0].2);
+        assert_eq!(Outcome::Wrong { expected: Answer::grid("#.\n##"), actual: Answer::grid("#.\n.#") }, *outcomes[1].2);
+        assert!(matches!(outcomes[2], (2, 1, Outcome::MissingInput(_))));
+        assert!(matches!(outcomes[3], (2, 2, Outcome::MissingInput(_))));
+        assert_eq!((3, 1, &Outcome::NotSolved), outcomes[4]);
+        assert!(matches!(outcomes[5], (4, 1, Outcome::MalformedInput { error, .. }) if *error == ParseError::new(2, 1, "empty line")));
+        assert_eq!(7, checks.len());
+        assert_eq!(Summary { correct: 1, wrong: 1, unverified: 0, missing_inputs: 2, malformed_inputs: 2, not_solved: 1 }, summary);
+        assert!(!summary.passed());
+        assert_eq!("2022 day 3 part 1: NOT SOLVED", checks[4].to_string());
+        assert_eq!("2022 day 4 part 2: MALFORMED INPUT\nline 2, column 1: empty line\n  |\n2 | \n  | ^", checks[6].to_string());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn examples_are_checked() {
+        let root = env::temp_dir().join(format!("aoc-check-examples-{}", std::process::id()));
+        for (year, day) in [(2022, 1), (2022, 2)] {
+            let dir = Example::dir(&root, year, day);
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join("short.txt"), "a\nb\n").unwrap();
+            fs::write(dir.join(Example::ANSWERS), "short 1 2\nshort 2 ab\n").unwrap();
+        }
+
+        let mut registry = Registry::new();
+        registry.register(&[Puzzle::new::<Lines>(2022, 1)]);
+
+        let mut checks = Vec::new();
+        let summary = examples(&registry, &root, |_, _| true, |c| checks.push(c.to_string())).unwrap();
+
+        assert_eq!(
+            vec![
+                "2022 day 1 part 1, example short: ok",
+                "2022 day 1 part 2, example short: WRONG, expected\nab\ngot\na\nb",
+                "2022 day 2 part 1, example short: NOT SOLVED",
+                "2022 day 2 part 2, example short: NOT SOLVED",
+            ],
+            checks
+        );
+        assert_eq!(Summary { correct: 1, wrong: 1, unverified: 0, missing_inputs: 0, malformed_inputs: 0, not_solved: 2 }, summary);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
+
+/// One test per day with examples, generated by `build.rs`.
+#[cfg(test)]
+mod example_tests {
+    use super::*;
+    use crate::puzzles;
+
+    fn check_examples(year: u32, day: u32) {
+        let mut failures = Vec::new();
+        let summary = examples(&puzzles::registry(), &aoc_core::examples_dir(), |y, d| (y, d) == (year, day), |check| {
+            if check.outcome != Outcome::Correct {
+                failures.push(check.to_string());
+            }
+        })
+        .unwrap();
+
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+        assert!(summary.correct > 0);
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
+}
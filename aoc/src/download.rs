@@ -0,0 +1,211 @@
+//! Downloading missing puzzle inputs from the Advent of Code site.
+//!
+//! Downloads are cached in the input directory and spaced at least
+//! [`MIN_INTERVAL`] apart, even across runs.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aoc_core::Inputs;
+
+use crate::site::{Http, SiteError, BASE_URL};
+
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// File in the input directory holding the time of the last request.
+const STAMP: &str = ".last-request";
+
+/// Days between 1970-01-01 and the given date of the Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146097 + day_of_era - 719468
+}
+
+/// Events up to 2024 had 25 days, later ones 12.
+pub fn last_day(year: u32) -> u32 {
+    if year < 2025 {
+        25
+    } else {
+        12
+    }
+}
+
+/// Puzzles open at midnight US Eastern time (UTC-5) in December.
+pub fn unlocked_at(year: u32, day: u32) -> Result<SystemTime, SiteError> {
+    if year < 2015 || day == 0 || day > last_day(year) {
+        return Err(SiteError::NoSuchPuzzle { year, day });
+    }
+
+    let seconds = days_from_civil(year as i64, 12, day as i64) * 86400 + 5 * 3600;
+
+    Ok(UNIX_EPOCH + Duration::from_secs(seconds as u64))
+}
+
+pub fn check_unlocked(year: u32, day: u32, now: SystemTime) -> Result<(), SiteError> {
+    if now < unlocked_at(year, day)? {
+        Err(SiteError::NotUnlocked { year, day })
+    } else {
+        Ok(())
+    }
+}
+
+/// How long to sleep so requests stay `interval` apart.
+fn wait_time(last: Option<SystemTime>, now: SystemTime, interval: Duration) -> Duration {
+    let elapsed = match last {
+        Some(last) => now.duration_since(last).unwrap_or_default(),
+        None => return Duration::ZERO,
+    };
+
+    interval.saturating_sub(elapsed)
+}
+
+pub struct Downloader<H> {
+    http: H,
+    session: String,
+    pub base_url: String,
+    pub interval: Duration,
+}
+
+impl<H: Http> Downloader<H> {
+    pub fn new(http: H, session: String) -> Downloader<H> {
+        Downloader { http, session, base_url: BASE_URL.to_string(), interval: MIN_INTERVAL }
+    }
+
+    fn throttle(&self, dir: &Path) -> io::Result<()> {
+        let stamp = dir.join(STAMP);
+        let last = fs::read_to_string(&stamp)
+            .ok()
+            .and_then(|text| text.trim().parse().ok())
+            .map(|seconds| UNIX_EPOCH + Duration::from_secs(seconds));
+
+        thread::sleep(wait_time(last, SystemTime::now(), self.interval));
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        fs::create_dir_all(dir)?;
+        fs::write(stamp, now.as_secs().to_string())
+    }
+
+    /// Downloads the input and stores it in `dir`, laid out like
+    /// [`Inputs::Directory`].
+    pub fn fetch(&mut self, year: u32, day: u32, dir: &Path) -> Result<String, SiteError> {
+        check_unlocked(year, day, SystemTime::now())?;
+        self.throttle(dir)?;
+
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let response = self.http.get(&url, &self.session).map_err(SiteError::Network)?;
+
+        match response.status {
+            200 => {}
+            400 | 500 => return Err(SiteError::InvalidSession),
+            404 => return Err(SiteError::NotUnlocked { year, day }),
+            429 => return Err(SiteError::RateLimited),
+            status => return Err(SiteError::Status(status)),
+        }
+
+        let path = Inputs::path(dir, year, day);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, &response.body)?;
+
+        Ok(response.body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::site::Response;
+    use std::env;
+    use std::path::PathBuf;
+
+    struct FakeHttp {
+        status: u16,
+        body: &'static str,
+        requests: Vec<(String, String)>,
+    }
+
+    impl Http for FakeHttp {
+        fn get(&mut self, url: &str, session: &str) -> Result<Response, String> {
+            self.requests.push((url.to_string(), session.to_string()));
+
+            Ok(Response { status: self.status, body: self.body.to_string() })
+        }
+
+        fn post(&mut self, _: &str, _: &str, _: &[(&str, &str)]) -> Result<Response, String> {
+            unreachable!("downloads only read")
+        }
+    }
+
+    fn downloader(status: u16, body: &'static str) -> Downloader<FakeHttp> {
+        let mut downloader = Downloader::new(FakeHttp { status, body, requests: Vec::new() }, "abc".to_string());
+        downloader.base_url = "http://fake".to_string();
+        downloader.interval = Duration::ZERO;
+
+        downloader
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("aoc-download-{}-{}", name, std::process::id()))
+    }
+
+    #[test]
+    fn fetch_caches_input() {
+        let dir = temp_dir("cache");
+        let mut downloader = downloader(200, "1\n2\n3\n");
+
+        assert_eq!("1\n2\n3\n", downloader.fetch(2022, 5, &dir).unwrap());
+        assert_eq!(
+            vec![("http://fake/2022/day/5/input".to_string(), "abc".to_string())],
+            downloader.http.requests
+        );
+        assert_eq!("1\n2\n3\n", Inputs::Directory(dir.clone()).read(2022, 5).unwrap());
+        assert!(dir.join(STAMP).is_file());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn fetch_reports_rejections() {
+        let dir = temp_dir("rejected");
+
+        assert!(matches!(downloader(400, "log in").fetch(2022, 5, &dir), Err(SiteError::InvalidSession)));
+        assert!(matches!(downloader(429, "").fetch(2022, 5, &dir), Err(SiteError::RateLimited)));
+        assert!(!Inputs::path(&dir, 2022, 5).exists());
+
+        let mut locked = downloader(200, "");
+        assert!(matches!(locked.fetch(2099, 1, &dir), Err(SiteError::NotUnlocked { year: 2099, day: 1 })));
+        assert!(locked.http.requests.is_empty());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn unlock_times() {
+        let unlock = unlocked_at(2022, 1).unwrap();
+
+        assert_eq!(UNIX_EPOCH + Duration::from_secs(1669870800), unlock);
+        assert!(check_unlocked(2022, 1, unlock).is_ok());
+        assert!(matches!(check_unlocked(2022, 1, unlock - Duration::from_secs(1)), Err(SiteError::NotUnlocked { .. })));
+        assert!(matches!(unlocked_at(2022, 26), Err(SiteError::NoSuchPuzzle { .. })));
+        assert!(matches!(unlocked_at(2025, 13), Err(SiteError::NoSuchPuzzle { .. })));
+        assert!(matches!(unlocked_at(2014, 1), Err(SiteError::NoSuchPuzzle { .. })));
+    }
+
+    #[test]
+    fn requests_are_spaced() {
+        let now = UNIX_EPOCH + Duration::from_secs(100);
+
+        assert_eq!(Duration::ZERO, wait_time(None, now, MIN_INTERVAL));
+        assert_eq!(Duration::from_secs(3), wait_time(Some(now - Duration::from_secs(2)), now, MIN_INTERVAL));
+        assert_eq!(Duration::ZERO, wait_time(Some(now - Duration::from_secs(60)), now, MIN_INTERVAL));
+    }
+}
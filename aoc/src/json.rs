@@ -0,0 +1,102 @@
+//! Machine readable output of the runner, chosen with `--json`.
+
+use std::time::Duration;
+
+use aoc_core::Answer;
+use serde_json::{json, Value};
+
+use crate::check::{Check, Outcome, Summary};
+
+/// Integers as numbers, text as a string and grids as an array of rows.
+pub fn answer(answer: &Answer) -> Value {
+    match answer {
+        Answer::Integer(n) => match i64::try_from(*n) {
+            Ok(n) => json!(n),
+            // Too large for most JSON readers to keep exact.
+            Err(_) => json!(n.to_string()),
+        },
+        Answer::Text(text) => json!(text),
+        Answer::Grid(rows) => json!(rows),
+    }
+}
+
+/// Answer of one part and how long it took, parsing included, or why there
+/// is none.
+pub fn part(part: usize, result: &Result<(Answer, Duration), String>) -> Value {
+    match result {
+        Ok((solved, time)) => json!({ "part": part, "answer": answer(solved), "time_ns": time.as_nanos() as u64, "error": null }),
+        Err(e) => json!({ "part": part, "answer": null, "time_ns": null, "error": e }),
+    }
+}
+
+pub fn check(check: &Check) -> Value {
+    let (status, expected, actual, error) = match &check.outcome {
+        Outcome::Correct => ("ok", None, None, None),
+        Outcome::Wrong { expected, actual } => ("wrong", Some(answer(expected)), Some(answer(actual)), None),
+        Outcome::Unverified(actual) => ("unverified", None, Some(answer(actual)), None),
+        Outcome::MissingInput(location) => ("missing input", None, None, Some(location.clone())),
+        Outcome::MalformedInput { error, .. } => ("malformed input", None, None, Some(error.to_string())),
+        Outcome::Panicked(message) => ("panicked", None, None, Some(message.clone())),
+        Outcome::NotSolved => ("not solved", None, None, None),
+    };
+
+    json!({
+        "year": check.year,
+        "day": check.day,
+        "part": check.part,
+        "example": check.example,
+        "status": status,
+        "expected": expected,
+        "actual": actual,
+        "error": error,
+    })
+}
+
+pub fn summary(summary: &Summary) -> Value {
+    json!({
+        "correct": summary.correct,
+        "wrong": summary.wrong,
+        "unverified": summary.unverified,
+        "missing_inputs": summary.missing_inputs,
+        "malformed_inputs": summary.malformed_inputs,
+        "not_solved": summary.not_solved,
+        "passed": summary.passed(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_and_checks() {
+        assert_eq!(json!(7162937112i64), answer(&Answer::Integer(7162937112)));
+        assert_eq!(json!("170141183460469231731687303715884105727"), answer(&Answer::Integer(i128::MAX)));
+        assert_eq!(json!(["#.", ".#"]), answer(&Answer::grid("#.\n.#")));
+        assert_eq!(
+            json!({ "part": 2, "answer": null, "time_ns": null, "error": "not yet implemented" }),
+            part(2, &Err("not yet implemented".to_string()))
+        );
+
+        let wrong = Check {
+            year: 2018,
+            day: 2,
+            part: 2,
+            example: None,
+            outcome: Outcome::Wrong { expected: Answer::from("fgij"), actual: Answer::from("fghij") },
+        };
+        assert_eq!(
+            json!({
+                "year": 2018,
+                "day": 2,
+                "part": 2,
+                "example": null,
+                "status": "wrong",
+                "expected": "fgij",
+                "actual": "fghij",
+                "error": null,
+            }),
+            check(&wrong)
+        );
+    }
+}
@@ -0,0 +1,458 @@
+mod bench;
+mod check;
+mod download;
+mod json;
+mod puzzles;
+mod scaffold;
+mod site;
+mod submit;
+mod watch;
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::Instant;
+
+use aoc_core::{Answer, Inputs, Manifest, Registry};
+use download::Downloader;
+use serde_json::json;
+use site::{Ureq, BASE_URL};
+use submit::{Attempt, Ledger};
+
+const USAGE: &str = "usage: aoc [--json] list
+       aoc [--json] [--input <file>|-] [--input-dir <dir>] <year> <day> [part]
+       aoc [--json] [--input-dir <dir>] check [--examples] [year [day]]
+       aoc [--input-dir <dir>] bench [year [day]] [--samples <n>] [--warmup <n>]
+                 [--save <file.json>] [--compare <file.json>] [--threshold <percent>]
+       aoc [--input <file>|-] [--input-dir <dir>] try <year> <day>
+       aoc [--input <file>] [--input-dir <dir>] watch <year> <day>
+       aoc [--json] new <year> <day>
+       aoc [--json] [--input <file>|-] [--input-dir <dir>] submit <year> <day> <part> [answer]";
+
+/// Why the runner failed. With `--json`, a failure not yet `reported` in the
+/// JSON document printed is printed as one of its own.
+struct Failure {
+    message: String,
+    reported: bool,
+}
+
+impl Failure {
+    fn reported(message: String) -> Failure {
+        Failure { message, reported: true }
+    }
+}
+
+impl From<String> for Failure {
+    fn from(message: String) -> Failure {
+        Failure { message, reported: false }
+    }
+}
+
+fn list(registry: &Registry, json: bool) {
+    let mut years: Vec<u32> = registry.puzzles().iter().map(|p| p.year).collect();
+    years.dedup();
+
+    let mut listed = Vec::new();
+    for year in years {
+        let days: Vec<u32> = registry.puzzles().iter().filter(|p| p.year == year).map(|p| p.day).collect();
+
+        if json {
+            listed.push(json!({ "year": year, "days": days }));
+        } else {
+            let days: Vec<String> = days.iter().map(|day| day.to_string()).collect();
+            println!("{}: {}", year, days.join(" "));
+        }
+    }
+
+    if json {
+        println!("{}", json!({ "years": listed }));
+    }
+}
+
+/// Reads the input, downloading it first when the input directory lacks it.
+fn read_input(inputs: &Inputs, year: u32, day: u32) -> Result<String, String> {
+    match (inputs.read(year, day), inputs) {
+        (Err(e), Inputs::Directory(dir)) if e.is_missing() => {
+            eprintln!("{} missing, downloading", e.location);
+
+            let session = site::session().map_err(|e| e.to_string())?;
+            Downloader::new(Ureq::new(), session).fetch(year, day, dir).map_err(|e| e.to_string())
+        }
+        (result, _) => result.map_err(|e| e.to_string()),
+    }
+}
+
+/// Solves `parts` of the day. With `json` the answers, how long each part
+/// took and any error go into one JSON document instead of text. Malformed
+/// input fails the whole day, pointing at where it is wrong.
+fn run(registry: &Registry, inputs: &Inputs, year: u32, day: u32, parts: &[usize], json: bool) -> Result<(), Failure> {
+    let found = registry
+        .get(year, day)
+        .map_err(|e| e.to_string())
+        .and_then(|puzzle| Ok((puzzle, read_input(inputs, year, day)?)));
+    let (puzzle, input) = match found {
+        Ok(found) => found,
+        Err(e) => {
+            if json {
+                println!("{}", json!({ "year": year, "day": day, "parts": [], "error": e }));
+                return Err(Failure::reported(e));
+            }
+            return Err(e.into());
+        }
+    };
+
+    let mut results = Vec::new();
+    for &part in parts {
+        let start = Instant::now();
+        let result = match watch::catch(|| puzzle.solve(part, &input)) {
+            Ok(Ok(answer)) => Ok((answer, start.elapsed())),
+            Ok(Err(e)) => {
+                let message = format!("{} day {}: malformed input\n{}", year, day, e.diagnostic(&input));
+                if json {
+                    println!("{}", json!({ "year": year, "day": day, "parts": [], "error": e.to_string() }));
+                    return Err(Failure::reported(message));
+                }
+                return Err(message.into());
+            }
+            Err(e) => Err(e),
+        };
+
+        match &result {
+            _ if json => {}
+            Ok((Answer::Grid(rows), _)) => println!("Part {}:\n{}", part, rows.join("\n")),
+            Ok((answer, _)) => println!("Part {}: {}", part, answer),
+            Err(_) => {}
+        }
+        results.push((part, result));
+    }
+
+    let failed: Vec<String> = results.iter().filter(|(_, r)| r.is_err()).map(|(part, _)| part.to_string()).collect();
+    if json {
+        let parts: Vec<_> = results.iter().map(|(part, result)| json::part(*part, result)).collect();
+        let error = (!failed.is_empty()).then(|| format!("part {} failed", failed.join(" and ")));
+        println!("{}", json!({ "year": year, "day": day, "parts": parts, "error": error }));
+    }
+
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(Failure { message: format!("{} day {} part {} failed", year, day, failed.join(" and ")), reported: json })
+    }
+}
+
+/// Prints what checks found, as text while they run or as one JSON document
+/// at the end.
+struct CheckOutput {
+    json: bool,
+    checks: Vec<serde_json::Value>,
+}
+
+impl CheckOutput {
+    fn new(json: bool) -> CheckOutput {
+        CheckOutput { json, checks: Vec::new() }
+    }
+
+    fn check(&mut self, check: &check::Check) {
+        if self.json {
+            self.checks.push(json::check(check));
+        } else {
+            println!("{}", check);
+        }
+    }
+
+    fn finish(self, result: Result<check::Summary, String>) -> Result<(), Failure> {
+        if self.json {
+            let (summary, error) = match &result {
+                Ok(summary) => (Some(json::summary(summary)), None),
+                Err(e) => (None, Some(e)),
+            };
+            println!("{}", json!({ "checks": self.checks, "summary": summary, "error": error }));
+        }
+
+        let summary = result.map_err(|message| Failure { message, reported: self.json })?;
+        if !self.json {
+            println!("{}", summary);
+        }
+
+        if summary.passed() {
+            Ok(())
+        } else {
+            Err(Failure { message: "check failed".to_string(), reported: self.json })
+        }
+    }
+}
+
+fn parse_number(arg: Option<String>, what: &str) -> Result<u32, String> {
+    let arg = arg.ok_or_else(|| USAGE.to_string())?;
+
+    arg.parse().map_err(|_| format!("invalid {} '{}'", what, arg))
+}
+
+fn parse_part(arg: Option<String>) -> Result<usize, String> {
+    match arg.ok_or_else(|| USAGE.to_string())?.as_str() {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        part => Err(format!("invalid part '{}'", part)),
+    }
+}
+
+/// Sends the answer, or the one the solver finds when none is given, unless
+/// the ledger in the input directory shows it cannot be right.
+fn submit_answer(
+    registry: &Registry,
+    inputs: &Inputs,
+    (year, day, part): (u32, u32, usize),
+    answer: Option<String>,
+    json: bool,
+) -> Result<(), String> {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let puzzle = registry.get(year, day).map_err(|e| e.to_string())?;
+            let input = read_input(inputs, year, day)?;
+
+            match puzzle.solve(part, &input).map_err(|e| format!("malformed input\n{}", e.diagnostic(&input)))? {
+                Answer::Grid(rows) => return Err(format!("read the answer and submit it as text:\n{}", rows.join("\n"))),
+                answer => answer.to_string(),
+            }
+        }
+    };
+
+    let dir = match inputs {
+        Inputs::Directory(dir) => dir.clone(),
+        _ => aoc_core::input_dir(),
+    };
+    let mut ledger = Ledger::load(&dir.join(Ledger::FILE)).map_err(|e| e.to_string())?;
+    ledger.check(year, day, part, &answer).map_err(|e| format!("not submitting {}: {}", answer, e))?;
+
+    let session = site::session().map_err(|e| e.to_string())?;
+    let verdict = submit::submit(&mut Ureq::new(), BASE_URL, &session, year, day, part, &answer).map_err(|e| e.to_string())?;
+    if json {
+        println!("{}", json!({ "year": year, "day": day, "part": part, "answer": answer, "verdict": verdict.to_token() }));
+    } else {
+        println!("{}: {}", answer, verdict);
+    }
+
+    ledger.record(Attempt { year, day, part, verdict, answer }).map_err(|e| e.to_string())
+}
+
+/// Compares the answers of every selected puzzle with the verified ones in
+/// the input directory, without downloading missing inputs.
+fn check_answers(registry: &Registry, inputs: &Inputs, year: Option<u32>, day: Option<u32>, json: bool) -> Result<(), Failure> {
+    let dir = match inputs {
+        Inputs::Directory(dir) => dir,
+        _ => return Err("check reads every input from a directory, --input cannot be used".to_string().into()),
+    };
+
+    let mut output = CheckOutput::new(json);
+    let path = dir.join(Manifest::FILE);
+    let summary = Manifest::load(&path).map_err(|e| format!("{}: {}", path.display(), e)).map(|manifest| {
+        let selected = |y, d| year.is_none_or(|year| year == y) && day.is_none_or(|day| day == d);
+        check::run(registry, inputs, &manifest, selected, |check| output.check(check))
+    });
+
+    output.finish(summary)
+}
+
+/// Benchmarks the selected puzzles. Arguments are those following `bench`.
+fn benchmark(registry: &Registry, inputs: &Inputs, mut args: impl Iterator<Item = String>) -> Result<(), String> {
+    let (mut warmup, mut samples, mut threshold) = (1, 10, 10.0);
+    let (mut save, mut baseline) = (None, None);
+    let mut positional = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--samples" => samples = parse_number(Some(option_value(args.next(), "--samples")?), "sample count")? as usize,
+            "--warmup" => warmup = parse_number(Some(option_value(args.next(), "--warmup")?), "warm-up count")? as usize,
+            "--threshold" => {
+                let value = option_value(args.next(), "--threshold")?;
+                threshold = value.parse().map_err(|_| format!("invalid threshold '{}'", value))?;
+            }
+            "--save" => save = Some(PathBuf::from(option_value(args.next(), "--save")?)),
+            "--compare" => baseline = Some(PathBuf::from(option_value(args.next(), "--compare")?)),
+            _ => positional.push(arg),
+        }
+    }
+    if samples == 0 {
+        return Err("--samples must be at least 1".to_string());
+    }
+    if let Inputs::Stdin = inputs {
+        return Err("bench reads inputs from a file or directory, not stdin".to_string());
+    }
+
+    let mut positional = positional.into_iter();
+    let year = positional.next().map(|year| parse_number(Some(year), "year")).transpose()?;
+    let day = positional.next().map(|day| parse_number(Some(day), "day")).transpose()?;
+    // Loaded first so a bad path does not waste a whole run.
+    let load = |path: &Path| bench::Report::load(path).map_err(|e| format!("{}: {}", path.display(), e));
+    let baseline = baseline.as_deref().map(load).transpose()?;
+
+    let selected = |y, d| year.is_none_or(|year| year == y) && day.is_none_or(|day| day == d);
+    let report = bench::run(registry, inputs, selected, warmup, samples);
+
+    if let Some(path) = save {
+        report.save(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    }
+
+    let baseline = match baseline {
+        Some(baseline) => baseline,
+        None => return Ok(()),
+    };
+    let changes = bench::compare(&baseline, &report, threshold / 100.0);
+    for change in &changes {
+        println!(
+            "{} day {} {}: {} -> {}{}",
+            change.year,
+            change.day,
+            change.stage,
+            bench::format_nanos(change.before_ns),
+            bench::format_nanos(change.after_ns),
+            if change.is_regression() { "  REGRESSION" } else { "" }
+        );
+    }
+
+    match changes.iter().filter(|c| c.is_regression()).count() {
+        0 => Ok(()),
+        n => Err(format!("{} steps slower by more than {}%", n, threshold)),
+    }
+}
+
+/// Compares the answers to the examples of every selected puzzle with the
+/// ones given in the puzzle text.
+fn check_examples(registry: &Registry, year: Option<u32>, day: Option<u32>, json: bool) -> Result<(), Failure> {
+    let mut output = CheckOutput::new(json);
+    let selected = |y, d| year.is_none_or(|year| year == y) && day.is_none_or(|day| day == d);
+    let summary = check::examples(registry, &aoc_core::examples_dir(), selected, |check| output.check(check));
+
+    output.finish(summary)
+}
+
+fn option_value(value: Option<String>, option: &str) -> Result<String, String> {
+    value.ok_or_else(|| format!("{} needs a value", option))
+}
+
+/// Runs the command the arguments ask for, setting `json` as soon as
+/// `--json` is seen.
+fn execute(mut args: impl Iterator<Item = String>, json: &mut bool) -> Result<(), Failure> {
+    let registry = puzzles::registry();
+
+    let mut inputs = Inputs::from_env();
+    // Kept to pass on to the runs of `watch`.
+    let mut options = Vec::new();
+    let mut positional = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => {
+                let value = option_value(args.next(), "--input")?;
+                inputs = Inputs::from_arg(&value);
+                options.extend([arg, value]);
+            }
+            "--input-dir" => {
+                let value = option_value(args.next(), "--input-dir")?;
+                inputs = Inputs::Directory(PathBuf::from(&value));
+                options.extend([arg, value]);
+            }
+            "--json" => *json = true,
+            _ => positional.push(arg),
+        }
+    }
+    let json = *json;
+    let mut args = positional.into_iter();
+
+    let first = args.next();
+    if json {
+        match first.as_deref() {
+            Some("try") => return Err("try prints a line for people, use --json check --examples and --json <year> <day>".to_string().into()),
+            Some("watch") => return Err("watch runs until stopped, --json cannot be used".to_string().into()),
+            Some("bench") => return Err("bench saves its results as JSON with --save, not --json".to_string().into()),
+            _ => {}
+        }
+    }
+    if first.as_deref() == Some("list") {
+        list(&registry, json);
+        return Ok(());
+    }
+    if first.as_deref() == Some("check") {
+        let mut args = args.peekable();
+        if args.next_if(|arg| arg == "--examples").is_some() {
+            let year = args.next().map(|year| parse_number(Some(year), "year")).transpose()?;
+            let day = args.next().map(|day| parse_number(Some(day), "day")).transpose()?;
+
+            return check_examples(&registry, year, day, json);
+        }
+
+        let year = args.next().map(|year| parse_number(Some(year), "year")).transpose()?;
+        let day = args.next().map(|day| parse_number(Some(day), "day")).transpose()?;
+
+        return check_answers(&registry, &inputs, year, day, json);
+    }
+    if first.as_deref() == Some("try") {
+        let year = parse_number(args.next(), "year")?;
+        let day = parse_number(args.next(), "day")?;
+
+        let manifest = match &inputs {
+            Inputs::Directory(dir) => Manifest::load(&dir.join(Manifest::FILE)).unwrap_or_default(),
+            _ => Manifest::new(),
+        };
+        let (line, passed) = watch::try_day(&registry, read_input(&inputs, year, day), &manifest, year, day);
+        println!("{}", line);
+
+        return if passed { Ok(()) } else { Err("try failed".to_string().into()) };
+    }
+    if first.as_deref() == Some("watch") {
+        let year = parse_number(args.next(), "year")?;
+        let day = parse_number(args.next(), "day")?;
+        if let Inputs::Stdin = inputs {
+            return Err("watch reruns the day, so it cannot read stdin".to_string().into());
+        }
+
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let paths = watch::watched_paths(&root, &inputs, year, day);
+        return Ok(watch::watch(&root, &paths, &options, year, day)?);
+    }
+    if first.as_deref() == Some("new") {
+        let year = parse_number(args.next(), "year")?;
+        let day = parse_number(args.next(), "day")?;
+
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let changed: Vec<String> = scaffold::new_day(&root, year, day)?
+            .iter()
+            .map(|path| path.strip_prefix(&root).unwrap_or(path).display().to_string())
+            .collect();
+        if json {
+            println!("{}", json!({ "year": year, "day": day, "changed": changed }));
+        } else {
+            changed.iter().for_each(|path| println!("{}", path));
+        }
+        return Ok(());
+    }
+    if first.as_deref() == Some("bench") {
+        return Ok(benchmark(&registry, &inputs, args)?);
+    }
+    if first.as_deref() == Some("submit") {
+        let year = parse_number(args.next(), "year")?;
+        let day = parse_number(args.next(), "day")?;
+        let part = parse_part(args.next())?;
+
+        return Ok(submit_answer(&registry, &inputs, (year, day, part), args.next(), json)?);
+    }
+
+    let year = parse_number(first, "year")?;
+    let day = parse_number(args.next(), "day")?;
+    let parts = match args.next() {
+        None => vec![1, 2],
+        part => vec![parse_part(part)?],
+    };
+
+    run(&registry, &inputs, year, day, &parts, json)
+}
+
+fn main() {
+    let mut json = false;
+    if let Err(failure) = execute(env::args().skip(1), &mut json) {
+        if json && !failure.reported {
+            println!("{}", json!({ "error": failure.message }));
+        }
+        eprintln!("{}", failure.message);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,38 @@
+//! Every solved puzzle.
+
+use aoc_core::{Puzzle, Registry};
+
+include!(concat!(env!("OUT_DIR"), "/single_day_crates.rs"));
+
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    registry.register(SINGLE_DAY_CRATES);
+    registry.register(year2020::SOLUTIONS);
+    registry.register(aoc_2022::year_2022::SOLUTIONS);
+    registry.register(aoc_2023::SOLUTIONS);
+    registry.register(aoc_2024::SOLUTIONS);
+
+    registry
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Inputs;
+    use std::collections::HashSet;
+    use std::path::Path;
+
+    #[test]
+    fn puzzles_are_unique_and_have_inputs() {
+        let mut seen = HashSet::new();
+
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("inputs");
+
+        for puzzle in registry().puzzles() {
+            assert!(seen.insert((puzzle.year, puzzle.day)), "{} day {} listed twice", puzzle.year, puzzle.day);
+
+            let input = Inputs::path(&dir, puzzle.year, puzzle.day);
+            assert!(input.is_file(), "missing input {}", input.display());
+        }
+    }
+}
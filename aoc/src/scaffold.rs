@@ -0,0 +1,443 @@
+//! Creating the files for a new day.
+//!
+//! Days are added to the crate already holding their year. A year without
+//! one gets a new `aoc-<year>` crate laid out like `aoc-2024`, registered in
+//! the workspace and the runner. Years kept as one crate per day get a new
+//! `<year>/day<day>` crate, which the runner only needs as a dependency.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc_core::{Example, Inputs};
+
+use crate::download::last_day;
+
+const SOLVER: &str = "use aoc_core::{ParseError, Solution};
+
+pub fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+    Ok(input.lines().collect())
+}
+
+pub fn solve_part_1(_lines: &[&str]) -> u32 {
+    0
+}
+
+pub fn solve_part_2(_lines: &[&str]) -> u32 {
+    0
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Vec<&'a str>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(lines: &Vec<&str>) -> u32 {
+        solve_part_1(lines)
+    }
+
+    fn part2(lines: &Vec<&str>) -> u32 {
+        solve_part_2(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!(\"{example}\");
+
+    #[test]
+    #[ignore = \"fill in the answer of the sample\"]
+    fn test_solve_part_1_on_sample_input() {
+        let solution = solve_part_1(&parse(EXAMPLE).unwrap());
+
+        assert_eq!(solution, 0);
+    }
+
+    #[test]
+    fn test_solve_part_1_on_full_input() {
+        let input = include_str!(\"{input}\");
+
+        let solution = solve_part_1(&parse(input).unwrap());
+
+        assert_eq!(solution, 0);
+    }
+
+    #[test]
+    #[ignore = \"fill in the answer of the sample\"]
+    fn test_solve_part_2_on_sample_input() {
+        let solution = solve_part_2(&parse(EXAMPLE).unwrap());
+
+        assert_eq!(solution, 0);
+    }
+
+    #[test]
+    fn test_solve_part_2_on_full_input() {
+        let input = include_str!(\"{input}\");
+
+        let solution = solve_part_2(&parse(input).unwrap());
+
+        assert_eq!(solution, 0);
+    }
+}
+";
+
+/// Answers of the sample, to be filled in with it.
+const EXAMPLE_ANSWERS: &str = "# example part answer
+# sample 1 <answer>
+# sample 2 <answer>
+";
+
+const BIN: &str = "use {crate}::{module}::{parse, solve_part_1, solve_part_2};
+use aoc_core::{load, parse_or_exit};
+
+fn main() {
+    let input = load({year}, {day});
+    let parsed_input = parse_or_exit(&input, parse);
+
+    println!(\"{}\", solve_part_1(&parsed_input));
+    println!(\"{}\", solve_part_2(&parsed_input));
+}
+";
+
+const SINGLE_DAY_MAIN: &str = "use aoc_core::{load, parse_or_exit};
+use {crate}::{parse, solve_part_1, solve_part_2};
+
+fn main() {
+    let input = load({year}, {day});
+    let parsed_input = parse_or_exit(&input, parse);
+
+    println!(\"Part 1: {}\", solve_part_1(&parsed_input));
+    println!(\"Part 2: {}\", solve_part_2(&parsed_input));
+}
+";
+
+const SINGLE_DAY_MANIFEST: &str = "[package]
+name = \"year{year}-day{day}\"
+version = \"0.1.0\"
+authors = [\"Jan Cybulski\"]
+edition = \"2018\"
+
+[dependencies]
+aoc-core = { path = \"../../aoc-core\" }
+";
+
+const MANIFEST: &str = "[package]
+name = \"aoc-{year}\"
+version = \"0.1.0\"
+edition = \"2021\"
+
+[dependencies]
+aoc-core = { path = \"../aoc-core\" }
+";
+
+const LIB: &str = "aoc_core::days! {
+    {year};
+}
+";
+
+/// Where the days of a year live, relative to the repository root.
+#[derive(PartialEq, Debug)]
+struct Layout {
+    crate_dir: String,
+    /// Name of the crate's package.
+    package: String,
+    /// Directory of the day modules.
+    module_dir: String,
+    /// File with the `days!` list, in `module_dir`.
+    days_file: &'static str,
+    module: String,
+    /// Whether each day also gets a binary of its own.
+    bins: bool,
+    /// No crate holds the year yet.
+    new_crate: bool,
+    /// The day is a crate of its own, with the solver as its library.
+    single_day: bool,
+}
+
+fn layout(year: u32, day: u32) -> Result<Layout, String> {
+    let layout = |crate_dir: &str, module_dir: &str, days_file, module: String, bins| Layout {
+        crate_dir: crate_dir.to_string(),
+        package: crate_dir.to_string(),
+        module_dir: module_dir.to_string(),
+        days_file,
+        module,
+        bins,
+        new_crate: false,
+        single_day: false,
+    };
+
+    match year {
+        2018 | 2019 => {
+            let crate_dir = format!("{}/day{}", year, day);
+            let module_dir = format!("{}/src", crate_dir);
+
+            Ok(Layout {
+                package: format!("year{}-day{}", year, day),
+                single_day: true,
+                ..layout(&crate_dir, &module_dir, "", "lib".to_string(), false)
+            })
+        }
+        2020 => Ok(layout("year2020", "year2020/src", "lib.rs", format!("day{}", day), false)),
+        2022 => Ok(layout("aoc-2022", "aoc-2022/src/year_2022", "mod.rs", format!("day_{:02}", day), false)),
+        2023 => Ok(layout("aoc-2023", "aoc-2023/src", "lib.rs", format!("day{:02}", day), false)),
+        2024 => Ok(layout("aoc-2024", "aoc-2024/src", "lib.rs", format!("day{}", day), true)),
+        2015.. => {
+            let crate_dir = format!("aoc-{}", year);
+            let module_dir = format!("{}/src", crate_dir);
+
+            Ok(Layout { new_crate: true, ..layout(&crate_dir, &module_dir, "lib.rs", format!("day{}", day), true) })
+        }
+        _ => Err(format!("there is no Advent of Code {}", year)),
+    }
+}
+
+/// Directories holding the code a day runs, relative to the repository root.
+pub fn source_dirs(year: u32, day: u32) -> Vec<String> {
+    let mut dirs = vec!["aoc-core/src".to_string()];
+
+    if let Ok(layout) = layout(year, day) {
+        dirs.push(format!("{}/src", layout.crate_dir));
+    }
+    if year == 2019 {
+        dirs.push("2019/intcode/src".to_string());
+    }
+
+    dirs
+}
+
+/// Adds `day => module,` to the `days!` list, keeping it ordered by day.
+fn add_to_days(text: &str, day: u32, module: &str) -> Result<String, String> {
+    let entry = format!("    {} => {},", day, module);
+    let mut lines: Vec<&str> = text.lines().collect();
+
+    let mut position = None;
+    for (i, line) in lines.iter().enumerate() {
+        let listed = line.split("=>").next().and_then(|number| number.trim().parse::<u32>().ok());
+        match listed {
+            Some(listed) if listed == day => return Err(format!("day {} is already listed", day)),
+            Some(listed) if listed > day => {
+                position = Some(i);
+                break;
+            }
+            _ if line.trim() == "}" => {
+                position = Some(i);
+                break;
+            }
+            _ => {}
+        }
+    }
+
+    let position = position.ok_or("no end of the days! list")?;
+    lines.insert(position, &entry);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Inserts `line` after the last line starting with `prefix`.
+fn insert_after_last(text: &str, prefix: &str, line: &str) -> Result<String, String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let last = lines.iter().rposition(|l| l.starts_with(prefix)).ok_or_else(|| format!("no line starting with '{}'", prefix))?;
+    lines.insert(last + 1, line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds a `[[bin]]` named after the day just before the dependencies.
+fn add_bin(manifest: &str, day: u32) -> Result<String, String> {
+    let position = manifest.find("[dependencies]").ok_or("no [dependencies] section")?;
+
+    Ok(format!("{}[[bin]]\nname = \"{}\"\n\n{}", &manifest[..position], day, &manifest[position..]))
+}
+
+fn edit(path: &Path, change: impl FnOnce(&str) -> Result<String, String>) -> Result<(), String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let text = change(&text).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+    fs::write(path, text).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn create(path: &Path, text: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("{}: {}", parent.display(), e))?;
+    }
+
+    fs::write(path, text).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Creates the solver module with tests on the sample and the input, an empty
+/// sample with its answers still to fill in and an empty input for the day
+/// under `root`, and wires the day into its crate. Returns the files created
+/// or changed.
+pub fn new_day(root: &Path, year: u32, day: u32) -> Result<Vec<PathBuf>, String> {
+    if day == 0 || day > last_day(year) {
+        return Err(format!("invalid day {}", day));
+    }
+
+    let layout = layout(year, day)?;
+    let module_dir = root.join(&layout.module_dir);
+    let module = module_dir.join(format!("{}.rs", layout.module));
+    if module.exists() {
+        return Err(format!("{} already exists", module.display()));
+    }
+
+    // The tests include the sample and the input relative to the module.
+    let up = PathBuf::from("../".repeat(Path::new(&layout.module_dir).components().count()));
+    let example = Example::dir(&up.join("examples"), year, day).join("sample.txt");
+    let input = Inputs::path(&up.join("inputs"), year, day);
+
+    let crate_name = layout.package.replace('-', "_");
+    let fill = |template: &str| {
+        template
+            .replace("{crate}", &crate_name)
+            .replace("{example}", &example.display().to_string())
+            .replace("{input}", &input.display().to_string())
+            .replace("{module}", &layout.module)
+            .replace("{year}", &year.to_string())
+            .replace("{day}", &day.to_string())
+    };
+
+    let crate_dir = root.join(&layout.crate_dir);
+    let mut changed = Vec::new();
+
+    if layout.single_day {
+        create(&crate_dir.join("Cargo.toml"), &fill(SINGLE_DAY_MANIFEST))?;
+        create(&module_dir.join("main.rs"), &fill(SINGLE_DAY_MAIN))?;
+
+        // Every `<year>/day*` crate is a workspace member and the runner's
+        // build script lists its solver, leaving only the dependency on it.
+        let dependency = format!("{} = {{ path = \"../{}\" }}", layout.package, layout.crate_dir);
+        let previous = format!("year{}-day", year);
+        edit(&root.join("aoc").join("Cargo.toml"), |text| insert_after_last(text, &previous, &dependency))?;
+
+        changed.extend([crate_dir.join("Cargo.toml"), module_dir.join("main.rs"), root.join("aoc").join("Cargo.toml")]);
+    }
+
+    if layout.new_crate {
+        create(&crate_dir.join("Cargo.toml"), &fill(MANIFEST))?;
+        create(&module_dir.join(layout.days_file), &fill(LIB))?;
+
+        let member = format!("    \"{}\",", layout.crate_dir);
+        edit(&root.join("Cargo.toml"), |text| insert_after_last(text, "    \"aoc-", &member))?;
+        let dependency = format!("{} = {{ path = \"../{}\" }}", layout.crate_dir, layout.crate_dir);
+        edit(&root.join("aoc").join("Cargo.toml"), |text| insert_after_last(text, "aoc-20", &dependency))?;
+        let registration = format!("    registry.register({}::SOLUTIONS);", crate_name);
+        edit(&root.join("aoc").join("src").join("puzzles.rs"), |text| {
+            insert_after_last(text, "    registry.register(", &registration)
+        })?;
+
+        changed.extend([root.join("Cargo.toml"), root.join("aoc").join("Cargo.toml"), root.join("aoc").join("src").join("puzzles.rs")]);
+    }
+
+    create(&module, &fill(SOLVER))?;
+    changed.push(module);
+
+    if !layout.single_day {
+        let days_file = module_dir.join(layout.days_file);
+        edit(&days_file, |text| add_to_days(text, day, &layout.module))?;
+        changed.push(days_file);
+    }
+
+    if layout.bins {
+        let manifest = crate_dir.join("Cargo.toml");
+        edit(&manifest, |text| add_bin(text, day))?;
+        changed.push(manifest);
+
+        let bin = crate_dir.join("src").join("bin").join(format!("{}.rs", day));
+        create(&bin, &fill(BIN))?;
+        changed.push(bin);
+    }
+
+    // Empty examples and inputs stand for ones still to be pasted in or
+    // downloaded.
+    let examples = Example::dir(&root.join("examples"), year, day);
+    if !examples.exists() {
+        create(&examples.join("sample.txt"), "")?;
+        create(&examples.join(Example::ANSWERS), EXAMPLE_ANSWERS)?;
+        changed.extend([examples.join("sample.txt"), examples.join(Example::ANSWERS)]);
+    }
+
+    let input = Inputs::path(&root.join("inputs"), year, day);
+    if !input.exists() {
+        create(&input, "")?;
+        changed.push(input);
+    }
+
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn edits_keep_lists_ordered() {
+        let days = "aoc_core::days! {\n    2024;\n    1 => day1,\n    3 => day3,\n}\n";
+
+        assert_eq!("aoc_core::days! {\n    2024;\n    1 => day1,\n    2 => day2,\n    3 => day3,\n}\n", add_to_days(days, 2, "day2").unwrap());
+        assert_eq!("aoc_core::days! {\n    2024;\n    1 => day1,\n    3 => day3,\n    4 => day4,\n}\n", add_to_days(days, 4, "day4").unwrap());
+        assert_eq!(Err("day 3 is already listed".to_string()), add_to_days(days, 3, "day3"));
+        assert_eq!("aoc_core::days! {\n    2025;\n    1 => day1,\n}\n", add_to_days(&LIB.replace("{year}", "2025"), 1, "day1").unwrap());
+
+        assert_eq!("[[bin]]\nname = \"1\"\n\n[[bin]]\nname = \"2\"\n\n[dependencies]\n", add_bin("[[bin]]\nname = \"1\"\n\n[dependencies]\n", 2).unwrap());
+        assert_eq!("a\n  b\n  c\n  new\nd\n", insert_after_last("a\n  b\n  c\nd\n", "  ", "  new").unwrap());
+    }
+
+    #[test]
+    fn new_day_in_existing_crate() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        create(&root.join("aoc-2024/Cargo.toml"), "[package]\n\n[[bin]]\nname = \"1\"\n\n[dependencies]\n").unwrap();
+        create(&root.join("aoc-2024/src/lib.rs"), "aoc_core::days! {\n    2024;\n    1 => day1,\n}\n").unwrap();
+
+        let changed = new_day(&root, 2024, 7).unwrap();
+
+        assert_eq!(7, changed.len());
+        let solver = fs::read_to_string(root.join("aoc-2024/src/day7.rs")).unwrap();
+        assert!(!solver.contains("todo!"));
+        assert!(solver.contains("include_str!(\"../../examples/2024/07/sample.txt\")"));
+        assert!(solver.contains("include_str!(\"../../inputs/2024/07.txt\")"));
+        assert!(root.join("aoc-2024/src/../../examples/2024/07/sample.txt").exists());
+        let bin = fs::read_to_string(root.join("aoc-2024/src/bin/7.rs")).unwrap();
+        assert!(bin.starts_with("use aoc_2024::day7::{parse, solve_part_1, solve_part_2};"));
+        assert!(bin.contains("load(2024, 7)"));
+        assert!(fs::read_to_string(root.join("aoc-2024/src/lib.rs")).unwrap().contains("    7 => day7,\n}"));
+        assert!(fs::read_to_string(root.join("aoc-2024/Cargo.toml")).unwrap().contains("name = \"7\""));
+        assert_eq!("", fs::read_to_string(root.join("inputs/2024/07.txt")).unwrap());
+        assert!(Example::load(&root.join("examples"), 2024, 7).unwrap().is_empty());
+        assert!(new_day(&root, 2024, 7).unwrap_err().ends_with("day7.rs already exists"));
+        assert!(new_day(&root, 2025, 13).is_err());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn new_day_in_own_crate() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-single-{}", std::process::id()));
+        create(&root.join("aoc/Cargo.toml"), "[dependencies]\nyear2019-day8 = { path = \"../2019/day8\" }\nyear2020 = { path = \"../year2020\" }\n").unwrap();
+
+        let changed = new_day(&root, 2019, 9).unwrap();
+
+        assert_eq!(7, changed.len());
+        let manifest = fs::read_to_string(root.join("2019/day9/Cargo.toml")).unwrap();
+        assert!(manifest.contains("name = \"year2019-day9\""));
+        let solver = fs::read_to_string(root.join("2019/day9/src/lib.rs")).unwrap();
+        assert!(solver.contains("include_str!(\"../../../inputs/2019/09.txt\")"));
+        let main = fs::read_to_string(root.join("2019/day9/src/main.rs")).unwrap();
+        assert!(main.contains("use year2019_day9::{parse, solve_part_1, solve_part_2};"));
+        assert_eq!(
+            "[dependencies]\nyear2019-day8 = { path = \"../2019/day8\" }\nyear2019-day9 = { path = \"../2019/day9\" }\nyear2020 = { path = \"../year2020\" }\n",
+            fs::read_to_string(root.join("aoc/Cargo.toml")).unwrap()
+        );
+        assert_eq!(vec!["aoc-core/src", "2019/day9/src", "2019/intcode/src"], source_dirs(2019, 9));
+        assert!(new_day(&root, 2019, 9).unwrap_err().ends_with("lib.rs already exists"));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
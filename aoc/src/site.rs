@@ -0,0 +1,188 @@
+//! Talking to the Advent of Code site.
+//!
+//! Inputs and answers belong to an account, so every request carries the
+//! session cookie of a logged in browser, read by [`session`]. The HTTP
+//! client sits behind [`Http`] so tests can replace it.
+
+use std::env;
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+const USER_AGENT: &str = concat!("aoc-runner/", env!("CARGO_PKG_VERSION"));
+
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// HTTP client used to talk to the site. Responses with any status are
+/// returned as they are; `Err` means the server could not be reached.
+pub trait Http {
+    fn get(&mut self, url: &str, session: &str) -> Result<Response, String>;
+    /// Sends `form` URL-encoded.
+    fn post(&mut self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<Response, String>;
+}
+
+pub struct Ureq {
+    agent: ureq::Agent,
+}
+
+impl Ureq {
+    pub fn new() -> Ureq {
+        Ureq { agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build() }
+    }
+}
+
+fn into_response(result: Result<ureq::Response, ureq::Error>) -> Result<Response, String> {
+    let response = match result {
+        Ok(response) => response,
+        Err(ureq::Error::Status(_, response)) => response,
+        Err(e) => return Err(e.to_string()),
+    };
+    let status = response.status();
+    let body = response.into_string().map_err(|e| e.to_string())?;
+
+    Ok(Response { status, body })
+}
+
+impl Http for Ureq {
+    fn get(&mut self, url: &str, session: &str) -> Result<Response, String> {
+        into_response(self.agent.get(url).set("Cookie", &format!("session={}", session)).call())
+    }
+
+    fn post(&mut self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<Response, String> {
+        into_response(self.agent.post(url).set("Cookie", &format!("session={}", session)).send_form(form))
+    }
+}
+
+#[derive(Debug)]
+pub enum SiteError {
+    NoSuchPuzzle { year: u32, day: u32 },
+    NotUnlocked { year: u32, day: u32 },
+    NoSession,
+    InvalidSession,
+    RateLimited,
+    Status(u16),
+    Network(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for SiteError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SiteError::NoSuchPuzzle { year, day } => write!(f, "there is no puzzle for {} day {}", year, day),
+            SiteError::NotUnlocked { year, day } => write!(f, "{} day {} is not unlocked yet", year, day),
+            SiteError::NoSession => {
+                write!(f, "no session token, set {} or write it to {}", SESSION_VAR, session_file().display())
+            }
+            SiteError::InvalidSession => write!(f, "session token was rejected, log in again and copy a new one"),
+            SiteError::RateLimited => write!(f, "too many requests, try again later"),
+            SiteError::Status(status) => write!(f, "unexpected response status {}", status),
+            SiteError::Network(reason) => write!(f, "network error: {}", reason),
+            SiteError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl error::Error for SiteError {}
+
+impl From<io::Error> for SiteError {
+    fn from(e: io::Error) -> SiteError {
+        SiteError::Io(e)
+    }
+}
+
+fn session_file() -> PathBuf {
+    let home = env::var_os("HOME").unwrap_or_default();
+
+    Path::new(&home).join(".config").join("aoc").join("session")
+}
+
+/// Session token from `$AOC_SESSION`, or else `~/.config/aoc/session`.
+pub fn session() -> Result<String, SiteError> {
+    let token = match env::var(SESSION_VAR) {
+        Ok(token) => token,
+        Err(_) => fs::read_to_string(session_file()).map_err(|_| SiteError::NoSession)?,
+    };
+    let token = token.trim();
+
+    if token.is_empty() {
+        Err(SiteError::NoSession)
+    } else {
+        Ok(token.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Serves one request with `reply` and returns its header lines and body.
+    fn stub_server(reply: &'static str) -> (String, thread::JoinHandle<(Vec<String>, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut headers = Vec::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let line = line.trim_end().to_string();
+                if line.is_empty() {
+                    break;
+                }
+                headers.push(line);
+            }
+
+            let length = headers
+                .iter()
+                .find_map(|h| h.to_ascii_lowercase().strip_prefix("content-length: ").map(|n| n.parse().unwrap()))
+                .unwrap_or(0);
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+
+            stream.write_all(reply.as_bytes()).unwrap();
+
+            (headers, String::from_utf8(body).unwrap())
+        });
+
+        (address, server)
+    }
+
+    #[test]
+    fn ureq_get_sends_session() {
+        let (address, server) = stub_server("HTTP/1.1 200 OK\r\nContent-Length: 4\r\nConnection: close\r\n\r\n42\n\n");
+
+        let response = Ureq::new().get(&format!("{}/2022/day/1/input", address), "abc").unwrap();
+        let (headers, _) = server.join().unwrap();
+
+        assert_eq!(200, response.status);
+        assert_eq!("42\n\n", response.body);
+        assert_eq!("GET /2022/day/1/input HTTP/1.1", headers[0]);
+        assert!(headers.iter().any(|h| h.eq_ignore_ascii_case("cookie: session=abc")));
+    }
+
+    #[test]
+    fn ureq_post_sends_form_and_keeps_error_statuses() {
+        let (address, server) = stub_server("HTTP/1.1 404 Not Found\r\nContent-Length: 4\r\nConnection: close\r\n\r\nnope");
+
+        let response = Ureq::new().post(&format!("{}/2022/day/1/answer", address), "abc", &[("level", "1"), ("answer", "4 2")]).unwrap();
+        let (headers, body) = server.join().unwrap();
+
+        assert_eq!(404, response.status);
+        assert_eq!("nope", response.body);
+        assert_eq!("POST /2022/day/1/answer HTTP/1.1", headers[0]);
+        assert_eq!("level=1&answer=4+2", body);
+    }
+}
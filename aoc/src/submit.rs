@@ -0,0 +1,361 @@
+//! Submitting answers and keeping a ledger of every attempt.
+//!
+//! The ledger is a text file with one attempt per line, answer last so it
+//! may contain spaces:
+//!
+//! ```text
+//! 2022 4 2 too-high 900
+//! 2022 4 2 wait-37 850
+//! 2022 4 2 correct 847
+//! ```
+//!
+//! Before anything is sent, [`Ledger::check`] refuses guesses the site
+//! already rejected, or that are known to be too high or too low.
+
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use aoc_core::Answer;
+
+use crate::download;
+use crate::site::{Http, SiteError};
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, and the site gave no hint which way.
+    Wrong,
+    /// Submitted too soon after the previous attempt.
+    Wait(Duration),
+    /// The part is already solved, or part 1 is not solved yet.
+    WrongLevel,
+    Unknown,
+}
+
+impl Verdict {
+    /// Reads the verdict out of the page the site answers with.
+    pub fn from_response(page: &str) -> Verdict {
+        if page.contains("That's the right answer") {
+            Verdict::Correct
+        } else if page.contains("You gave an answer too recently") {
+            Verdict::Wait(wait_time(page).unwrap_or_default())
+        } else if page.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if page.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if page.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else if page.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    pub fn is_rejection(&self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+
+    /// The verdict as written in the ledger.
+    pub fn to_token(self) -> String {
+        match self {
+            Verdict::Correct => "correct".to_string(),
+            Verdict::TooHigh => "too-high".to_string(),
+            Verdict::TooLow => "too-low".to_string(),
+            Verdict::Wrong => "wrong".to_string(),
+            Verdict::Wait(time) => format!("wait-{}", time.as_secs()),
+            Verdict::WrongLevel => "wrong-level".to_string(),
+            Verdict::Unknown => "unknown".to_string(),
+        }
+    }
+
+    fn from_token(token: &str) -> Option<Verdict> {
+        let verdict = match token {
+            "correct" => Verdict::Correct,
+            "too-high" => Verdict::TooHigh,
+            "too-low" => Verdict::TooLow,
+            "wrong" => Verdict::Wrong,
+            "wrong-level" => Verdict::WrongLevel,
+            "unknown" => Verdict::Unknown,
+            _ => Verdict::Wait(Duration::from_secs(token.strip_prefix("wait-")?.parse().ok()?)),
+        };
+
+        Some(verdict)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "wrong, too high"),
+            Verdict::TooLow => write!(f, "wrong, too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::Wait(time) => write!(f, "answered too recently, wait {}s", time.as_secs()),
+            Verdict::WrongLevel => write!(f, "part already solved or not open yet"),
+            Verdict::Unknown => write!(f, "unrecognized response"),
+        }
+    }
+}
+
+/// Parses "You have 4m 37s left to wait" and similar.
+fn wait_time(page: &str) -> Option<Duration> {
+    let start = page.find("You have ")? + "You have ".len();
+    let end = start + page[start..].find(" left to wait")?;
+    let mut seconds = 0;
+
+    for part in page[start..end].split_whitespace() {
+        let (number, unit) = part.split_at(part.len() - 1);
+        let number: u64 = number.parse().ok()?;
+        seconds += match unit {
+            "h" => number * 3600,
+            "m" => number * 60,
+            "s" => number,
+            _ => return None,
+        };
+    }
+
+    Some(Duration::from_secs(seconds))
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Attempt {
+    pub year: u32,
+    pub day: u32,
+    pub part: usize,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+/// Reason to not send a guess.
+#[derive(PartialEq, Debug, Clone)]
+pub enum Refusal {
+    Solved { answer: String },
+    Rejected { verdict: Verdict },
+    AboveTooHigh { bound: String },
+    BelowTooLow { bound: String },
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Refusal::Solved { answer } => write!(f, "already solved, the answer is {}", answer),
+            Refusal::Rejected { verdict } => write!(f, "already submitted, the site said {}", verdict),
+            Refusal::AboveTooHigh { bound } => write!(f, "{} was already too high", bound),
+            Refusal::BelowTooLow { bound } => write!(f, "{} was already too low", bound),
+        }
+    }
+}
+
+impl error::Error for Refusal {}
+
+fn as_integer(answer: &str) -> Option<i128> {
+    match Answer::from(answer) {
+        Answer::Integer(n) => Some(n),
+        _ => None,
+    }
+}
+
+pub struct Ledger {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl Ledger {
+    /// File name of the ledger inside the input directory.
+    pub const FILE: &'static str = "ledger.txt";
+
+    /// Reads the ledger at `path`; a missing file is an empty ledger.
+    pub fn load(path: &Path) -> io::Result<Ledger> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+
+        let mut attempts = Vec::new();
+        for (i, line) in text.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+            let invalid = || io::Error::new(io::ErrorKind::InvalidData, format!("{}:{}: invalid attempt", path.display(), i + 1));
+            let fields: Vec<&str> = line.splitn(5, ' ').collect();
+            if fields.len() != 5 {
+                return Err(invalid());
+            }
+
+            attempts.push(Attempt {
+                year: fields[0].parse().map_err(|_| invalid())?,
+                day: fields[1].parse().map_err(|_| invalid())?,
+                part: fields[2].parse().map_err(|_| invalid())?,
+                verdict: Verdict::from_token(fields[3]).ok_or_else(invalid)?,
+                answer: fields[4].to_string(),
+            });
+        }
+
+        Ok(Ledger { path: path.to_path_buf(), attempts })
+    }
+
+    pub fn attempts(&self, year: u32, day: u32, part: usize) -> impl Iterator<Item = &Attempt> {
+        self.attempts.iter().filter(move |a| a.year == year && a.day == day && a.part == part)
+    }
+
+    pub fn check(&self, year: u32, day: u32, part: usize, answer: &str) -> Result<(), Refusal> {
+        let guess = as_integer(answer);
+
+        for attempt in self.attempts(year, day, part) {
+            if attempt.verdict == Verdict::Correct {
+                return Err(Refusal::Solved { answer: attempt.answer.clone() });
+            }
+            if attempt.verdict.is_rejection() && attempt.answer == answer {
+                return Err(Refusal::Rejected { verdict: attempt.verdict });
+            }
+
+            if let (Some(guess), Some(bound)) = (guess, as_integer(&attempt.answer)) {
+                if attempt.verdict == Verdict::TooHigh && guess >= bound {
+                    return Err(Refusal::AboveTooHigh { bound: attempt.answer.clone() });
+                }
+                if attempt.verdict == Verdict::TooLow && guess <= bound {
+                    return Err(Refusal::BelowTooLow { bound: attempt.answer.clone() });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds the attempt and appends it to the file.
+    pub fn record(&mut self, attempt: Attempt) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut file = fs::OpenOptions::new().create(true).append(true).open(&self.path)?;
+        writeln!(
+            file,
+            "{} {} {} {} {}",
+            attempt.year,
+            attempt.day,
+            attempt.part,
+            attempt.verdict.to_token(),
+            attempt.answer
+        )?;
+        self.attempts.push(attempt);
+
+        Ok(())
+    }
+}
+
+/// Sends the answer to `base_url` and reads the verdict.
+pub fn submit<H: Http>(
+    http: &mut H,
+    base_url: &str,
+    session: &str,
+    year: u32,
+    day: u32,
+    part: usize,
+    answer: &str,
+) -> Result<Verdict, SiteError> {
+    download::check_unlocked(year, day, SystemTime::now())?;
+
+    let url = format!("{}/{}/day/{}/answer", base_url, year, day);
+    let level = part.to_string();
+    let response = http.post(&url, session, &[("level", &level), ("answer", answer)]).map_err(SiteError::Network)?;
+
+    match response.status {
+        200 => Ok(Verdict::from_response(&response.body)),
+        400 | 500 => Err(SiteError::InvalidSession),
+        404 => Err(SiteError::NotUnlocked { year, day }),
+        429 => Err(SiteError::RateLimited),
+        status => Err(SiteError::Status(status)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::site::Response;
+    use std::env;
+
+    const RIGHT: &str = "<article><p>That's the right answer!  You are one gold star closer to saving your vacation.</p></article>";
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high.  Please wait one minute before trying again.</p></article>";
+    const TOO_LOW: &str = "<article><p>That's not the right answer; your answer is too low.</p></article>";
+    const WRONG: &str = "<article><p>That's not the right answer.  If you're stuck, make sure you're using the full input data.</p></article>";
+    const WAIT: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 37s left to wait.</p></article>";
+    const LEVEL: &str = "<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>";
+
+    #[test]
+    fn verdicts_from_responses() {
+        assert_eq!(Verdict::Correct, Verdict::from_response(RIGHT));
+        assert_eq!(Verdict::TooHigh, Verdict::from_response(TOO_HIGH));
+        assert_eq!(Verdict::TooLow, Verdict::from_response(TOO_LOW));
+        assert_eq!(Verdict::Wrong, Verdict::from_response(WRONG));
+        assert_eq!(Verdict::Wait(Duration::from_secs(277)), Verdict::from_response(WAIT));
+        assert_eq!(Verdict::Wait(Duration::from_secs(38)), Verdict::from_response("You gave an answer too recently. You have 38s left to wait."));
+        assert_eq!(Verdict::WrongLevel, Verdict::from_response(LEVEL));
+        assert_eq!(Verdict::Unknown, Verdict::from_response("<html></html>"));
+    }
+
+    fn attempt(part: usize, verdict: Verdict, answer: &str) -> Attempt {
+        Attempt { year: 2022, day: 4, part, verdict, answer: answer.to_string() }
+    }
+
+    #[test]
+    fn ledger_refuses_known_bad_guesses() {
+        let path = env::temp_dir().join(format!("aoc-ledger-{}.txt", std::process::id()));
+        let mut ledger = Ledger::load(&path).unwrap();
+
+        ledger.record(attempt(2, Verdict::TooHigh, "900")).unwrap();
+        ledger.record(attempt(2, Verdict::TooLow, "100")).unwrap();
+        ledger.record(attempt(2, Verdict::Wait(Duration::from_secs(37)), "850")).unwrap();
+        ledger.record(attempt(2, Verdict::Wrong, "abc def")).unwrap();
+
+        let ledger = Ledger::load(&path).unwrap();
+        assert_eq!(4, ledger.attempts(2022, 4, 2).count());
+        assert_eq!(Err(Refusal::AboveTooHigh { bound: "900".to_string() }), ledger.check(2022, 4, 2, "901"));
+        assert_eq!(Err(Refusal::Rejected { verdict: Verdict::TooHigh }), ledger.check(2022, 4, 2, "900"));
+        assert_eq!(Err(Refusal::BelowTooLow { bound: "100".to_string() }), ledger.check(2022, 4, 2, "99"));
+        assert_eq!(Err(Refusal::Rejected { verdict: Verdict::Wrong }), ledger.check(2022, 4, 2, "abc def"));
+        assert_eq!(Ok(()), ledger.check(2022, 4, 2, "850"));
+        assert_eq!(Ok(()), ledger.check(2022, 4, 1, "901"));
+
+        let mut ledger = ledger;
+        ledger.record(attempt(2, Verdict::Correct, "847")).unwrap();
+        assert_eq!(Err(Refusal::Solved { answer: "847".to_string() }), ledger.check(2022, 4, 2, "500"));
+
+        fs::remove_file(path).unwrap();
+    }
+
+    struct StubHttp {
+        page: &'static str,
+        /// URL, session and form of every post.
+        posts: Vec<String>,
+    }
+
+    impl Http for StubHttp {
+        fn get(&mut self, _: &str, _: &str) -> Result<Response, String> {
+            unreachable!("submitting only posts")
+        }
+
+        fn post(&mut self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<Response, String> {
+            let form: Vec<String> = form.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+            self.posts.push(format!("{} {} {}", url, session, form.join("&")));
+
+            Ok(Response { status: 200, body: self.page.to_string() })
+        }
+    }
+
+    #[test]
+    fn submit_posts_level_and_answer() {
+        let mut http = StubHttp { page: TOO_LOW, posts: Vec::new() };
+
+        assert_eq!(Ok(Verdict::TooLow), submit(&mut http, "http://stub", "abc", 2022, 4, 2, "500").map_err(|e| e.to_string()));
+        assert_eq!(vec!["http://stub/2022/day/4/answer abc level=2&answer=500"], http.posts);
+        assert!(matches!(submit(&mut http, "http://stub", "abc", 2099, 1, 1, "1"), Err(SiteError::NotUnlocked { .. })));
+        assert_eq!(1, http.posts.len());
+    }
+}
@@ -0,0 +1,253 @@
+//! Re-running one day whenever its code, input or examples change.
+//!
+//! The runner cannot reload its own code, so each run goes through
+//! `cargo run`, which rebuilds whatever changed and then runs [`try_day`] in
+//! the new binary.
+
+use std::env;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+use aoc_core::{Answer, Example, Inputs, Manifest, Registry};
+
+use crate::bench::format_nanos;
+use crate::check::{self, Outcome};
+use crate::scaffold;
+
+/// How often files are looked at.
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Runs `f`, turning a panic into its message. The message is still printed
+/// by the default hook.
+pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+            (Some(message), _) => message.to_string(),
+            (_, Some(message)) => message.clone(),
+            _ => "panicked".to_string(),
+        }
+    })
+}
+
+/// Checks the examples of the day, then solves the real input, or says why it
+/// could not be read, and compares it with the verified answers. Returns one
+/// line telling how it went and whether everything passed.
+pub fn try_day(registry: &Registry, input: Result<String, String>, manifest: &Manifest, year: u32, day: u32) -> (String, bool) {
+    let mut line = format!("{} day {}:", year, day);
+    let mut passed = true;
+
+    let puzzle = match registry.get(year, day) {
+        Ok(puzzle) => puzzle,
+        Err(e) => return (format!("{} {}", line, e), false),
+    };
+
+    let mut failures = Vec::new();
+    let examples = catch(|| {
+        check::examples(registry, &aoc_core::examples_dir(), |y, d| (y, d) == (year, day), |check| {
+            if check.outcome != Outcome::Correct {
+                failures.push(format!("{} part {}", check.example.as_deref().unwrap_or_default(), check.part));
+            }
+        })
+    });
+    match examples {
+        Ok(Ok(summary)) if summary.correct + summary.wrong == 0 => line.push_str(" no examples,"),
+        Ok(Ok(summary)) if summary.passed() => line.push_str(&format!(" examples {} ok,", summary.correct)),
+        Ok(Ok(summary)) => {
+            passed = false;
+            line.push_str(&format!(" examples {}/{} FAILED ({}),", summary.correct, summary.correct + summary.wrong, failures.join(", ")));
+        }
+        Ok(Err(e)) => {
+            passed = false;
+            line.push_str(&format!(" examples unreadable ({}),", e));
+        }
+        Err(_) => {
+            passed = false;
+            line.push_str(" examples PANICKED,");
+        }
+    }
+
+    let input = match input {
+        Ok(input) => input,
+        Err(e) => return (format!("{} no input ({})", line, e), false),
+    };
+
+    let mut below = Vec::new();
+    for part in 1..=2 {
+        let start = Instant::now();
+        let answer = match catch(|| puzzle.solve(part, &input)) {
+            Ok(Ok(answer)) => answer,
+            Ok(Err(e)) => {
+                line.push_str(" input MALFORMED");
+                below.push(e.diagnostic(&input));
+                passed = false;
+                break;
+            }
+            Err(_) => {
+                line.push_str(&format!(" part {} PANICKED", part));
+                passed = false;
+                break;
+            }
+        };
+        let time = format_nanos(start.elapsed().as_nanos() as u64);
+
+        let shown = match &answer {
+            Answer::Grid(rows) => {
+                below.push(rows.join("\n"));
+                "(grid below)".to_string()
+            }
+            answer => answer.to_string(),
+        };
+        let verdict = match manifest.get(year, day, part) {
+            Some(expected) if *expected == answer => " ok",
+            Some(_) => {
+                passed = false;
+                " WRONG"
+            }
+            None => "",
+        };
+        line.push_str(&format!(" part {} {}{} in {}{}", part, shown, verdict, time, if part == 1 { "," } else { "" }));
+    }
+
+    for text in below {
+        line.push('\n');
+        line.push_str(&text);
+    }
+
+    (line, passed)
+}
+
+/// Every file in `paths`, which may be directories, with when it last
+/// changed. Missing paths are left out.
+fn snapshot(paths: &[PathBuf]) -> Vec<(PathBuf, SystemTime)> {
+    let mut files = Vec::new();
+    let mut pending = paths.to_vec();
+
+    while let Some(path) = pending.pop() {
+        let metadata = match fs::metadata(&path) {
+            Ok(metadata) => metadata,
+            Err(_) => continue,
+        };
+
+        if metadata.is_dir() {
+            if let Ok(entries) = fs::read_dir(&path) {
+                pending.extend(entries.flatten().map(|entry| entry.path()));
+            }
+        } else if let Ok(modified) = metadata.modified() {
+            files.push((path, modified));
+        }
+    }
+    files.sort();
+
+    files
+}
+
+/// Files and directories that affect the day: its code, input and examples.
+pub fn watched_paths(root: &Path, inputs: &Inputs, year: u32, day: u32) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = scaffold::source_dirs(year, day).iter().map(|dir| root.join(dir)).collect();
+    paths.push(Example::dir(&aoc_core::examples_dir(), year, day));
+
+    match inputs {
+        Inputs::Directory(dir) => {
+            paths.push(Inputs::path(dir, year, day));
+            paths.push(dir.join(Manifest::FILE));
+        }
+        Inputs::File(path) => paths.push(path.clone()),
+        Inputs::Stdin => {}
+    }
+
+    paths
+}
+
+/// Re-runs `aoc <options> try <year> <day>` through cargo every time the
+/// watched paths change, until interrupted.
+pub fn watch(root: &Path, paths: &[PathBuf], options: &[String], year: u32, day: u32) -> Result<(), String> {
+    let mut last = None;
+
+    loop {
+        let files = snapshot(paths);
+        if last.as_ref() == Some(&files) {
+            thread::sleep(POLL_INTERVAL);
+            continue;
+        }
+        // Editors may save in several steps, give them a moment.
+        if last.is_some() {
+            thread::sleep(POLL_INTERVAL);
+        }
+        last = Some(snapshot(paths));
+
+        let start = Instant::now();
+        let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+        let status = Command::new(cargo)
+            .current_dir(root)
+            .args(["run", "--release", "--quiet", "-p", "aoc", "--"])
+            .args(options)
+            .args(["try", &year.to_string(), &day.to_string()])
+            .status()
+            .map_err(|e| format!("cannot run cargo: {}", e))?;
+
+        let result = if status.success() { "pass" } else { "FAIL" };
+        println!("-- {} after {}, waiting for changes", result, format_nanos(start.elapsed().as_nanos() as u64));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{ParseError, Puzzle, Solution};
+
+    struct Count;
+
+    impl Solution for Count {
+        type Input<'a> = &'a str;
+        type Part1 = usize;
+        type Part2 = usize;
+
+        fn parse(input: &str) -> Result<&str, ParseError> {
+            match input.find('!') {
+                Some(offset) => Err(ParseError::at_offset(input, offset, "unexpected '!'")),
+                None => Ok(input),
+            }
+        }
+
+        fn part1(input: &&str) -> usize {
+            input.lines().count()
+        }
+
+        fn part2(_: &&str) -> usize {
+            panic!("part 2 panics")
+        }
+    }
+
+    #[test]
+    fn one_line_per_try() {
+        let dir = env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        fs::create_dir_all(dir.join("2015")).unwrap();
+        fs::write(Inputs::path(&dir, 2015, 1), "").unwrap();
+
+        let mut registry = Registry::new();
+        registry.register(&[Puzzle::new::<Count>(2015, 1)]);
+        let mut manifest = Manifest::new();
+        manifest.insert(2015, 1, 1, Answer::Integer(3));
+
+        let (line, passed) = try_day(&registry, Ok("a\nb\nc\n".to_string()), &manifest, 2015, 1);
+
+        assert!(line.starts_with("2015 day 1: no examples, part 1 3 ok in "), "{}", line);
+        assert!(line.ends_with(" part 2 PANICKED"), "{}", line);
+        assert!(!passed);
+
+        let (line, _) = try_day(&registry, Ok("a\nb!\n".to_string()), &manifest, 2015, 1);
+        assert_eq!("2015 day 1: no examples, input MALFORMED\nline 2, column 2: unexpected '!'\n  |\n2 | b!\n  |  ^", line);
+
+        let paths = vec![dir.clone()];
+        let before = snapshot(&paths);
+        assert_eq!(1, before.len());
+        fs::write(dir.join("2015").join("02.txt"), "").unwrap();
+        assert_ne!(before, snapshot(&paths));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,56 +1,89 @@
-#[cfg(test)]
-mod tests {
-    fn solve_part_1(input: &str, output: u32) -> Option<u32> {
-        let numbers: Vec<u32> = input.lines().map(|v| v.parse::<u32>().unwrap()).collect();
+use aoc_core::{parse_number, ParseError, Solution};
 
-        for i in 0..numbers.len() {
-            for j in (i + 1)..numbers.len() {
-                if numbers[i] + numbers[j] == output {
-                    return Some(numbers[i] * numbers[j]);
-                }
+pub fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+    input.lines().map(|v| parse_number(input, v)).collect()
+}
+
+pub fn solve_part_1(numbers: &[u32], output: u32) -> Option<u32> {
+    for i in 0..numbers.len() {
+        for j in (i + 1)..numbers.len() {
+            if numbers[i] + numbers[j] == output {
+                return Some(numbers[i] * numbers[j]);
             }
         }
-
-        None
     }
 
-    fn solve_part_2(input: &str, output: u32) -> Option<u32> {
-        let numbers: Vec<u32> = input.lines().map(|v| v.parse::<u32>().unwrap()).collect();
+    None
+}
 
-        for i in 0..numbers.len() {
-            for j in (i + 1)..numbers.len() {
-                for k in (j + 1)..numbers.len() {
-                    if numbers[i] + numbers[j] + numbers[k] == output {
-                        return Some(numbers[i] * numbers[j] * numbers[k]);
-                    }
+pub fn solve_part_2(numbers: &[u32], output: u32) -> Option<u32> {
+    for i in 0..numbers.len() {
+        for j in (i + 1)..numbers.len() {
+            for k in (j + 1)..numbers.len() {
+                if numbers[i] + numbers[j] + numbers[k] == output {
+                    return Some(numbers[i] * numbers[j] * numbers[k]);
                 }
             }
         }
+    }
+
+    None
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Vec<u32>;
+    type Part1 = u32;
+    type Part2 = u32;
 
-        None
+    fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+        parse(input)
     }
 
+    fn part1(input: &Vec<u32>) -> u32 {
+        solve_part_1(input, 2020).unwrap()
+    }
+
+    fn part2(input: &Vec<u32>) -> u32 {
+        solve_part_2(input, 2020).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
     #[test]
     fn should_solve_part_1_preview() {
-        let input = include_str!("../input/day1_test.txt");
-        assert_eq!(solve_part_1(input, 2020).unwrap(), 514579);
+        let input = include_str!("../../examples/2020/01/sample.txt");
+        assert_eq!(solve_part_1(&parse(input).unwrap(), 2020).unwrap(), 514579);
     }
 
     #[test]
     fn should_solve_part_1() {
-        let input = include_str!("../input/day1.txt");
-        assert_eq!(solve_part_1(input, 2020).unwrap(), 545379);
+        let input = include_str!("../../inputs/2020/01.txt");
+        assert_eq!(solve_part_1(&parse(input).unwrap(), 2020).unwrap(), 545379);
     }
 
     #[test]
     fn should_solve_part_2_preview() {
-        let input = include_str!("../input/day1_test.txt");
-        assert_eq!(solve_part_2(input, 2020).unwrap(), 241861950);
+        let input = include_str!("../../examples/2020/01/sample.txt");
+        assert_eq!(solve_part_2(&parse(input).unwrap(), 2020).unwrap(), 241861950);
     }
 
     #[test]
     fn should_solve_part_2() {
-        let input = include_str!("../input/day1.txt");
-        assert_eq!(solve_part_2(input, 2020).unwrap(), 257778836);
+        let input = include_str!("../../inputs/2020/01.txt");
+        assert_eq!(solve_part_2(&parse(input).unwrap(), 2020).unwrap(), 257778836);
+    }
+
+    #[test]
+    fn should_report_malformed_number() {
+        let input = "1721\n979\n-366\n";
+        assert_eq!(
+            parse(input).unwrap_err(),
+            ParseError::new(3, 1, "expected a number, found '-366'")
+        );
     }
 }
@@ -1,79 +1,133 @@
-#[cfg(test)]
-mod tests {
-    use regex::Regex;
-
-    fn part_1_is_password_valid(
-        min_count: usize,
-        max_count: usize,
-        c: char,
-        password: &str,
-    ) -> bool {
-        let matched_character_count = password.chars().filter(|v| *v == c).count();
-        matched_character_count >= min_count && matched_character_count <= max_count
-    }
+use aoc_core::{parse_number, ParseError, Solution};
+use regex::Regex;
 
-    fn part_2_is_password_valid(
-        first_pos: usize,
-        second_pos: usize,
-        c: char,
-        password: &str,
-    ) -> bool {
-        password
-            .chars()
-            .enumerate()
-            .filter(|&(i, v)| (i + 1 == first_pos || i + 1 == second_pos) && v == c)
-            .count()
-            == 1
-    }
+/// A password with the two numbers and the character of its policy.
+pub struct Entry<'a> {
+    first: usize,
+    second: usize,
+    c: char,
+    password: &'a str,
+}
+
+fn part_1_is_password_valid(
+    min_count: usize,
+    max_count: usize,
+    c: char,
+    password: &str,
+) -> bool {
+    let matched_character_count = password.chars().filter(|v| *v == c).count();
+    matched_character_count >= min_count && matched_character_count <= max_count
+}
 
-    fn solve_core(input: &str, password_validator: fn(usize, usize, char, &str) -> bool) -> usize {
-        let re =
-            Regex::new(r"(?P<min_count>\d+)-(?P<max_count>\d+) (?P<char>\w): (?P<password>\w+)")
-                .expect("unable to compile regex");
-        re.captures_iter(input)
-            .map(|caps| {
-                (
-                    caps["min_count"].parse::<usize>().unwrap(),
-                    caps["max_count"].parse::<usize>().unwrap(),
-                    caps["char"].parse::<char>().unwrap(),
-                    caps.name("password").unwrap().as_str(),
+fn part_2_is_password_valid(
+    first_pos: usize,
+    second_pos: usize,
+    c: char,
+    password: &str,
+) -> bool {
+    password
+        .chars()
+        .enumerate()
+        .filter(|&(i, v)| (i + 1 == first_pos || i + 1 == second_pos) && v == c)
+        .count()
+        == 1
+}
+
+pub fn parse(input: &str) -> Result<Vec<Entry<'_>>, ParseError> {
+    let re =
+        Regex::new(r"^(?P<min_count>\d+)-(?P<max_count>\d+) (?P<char>\w): (?P<password>\w+)$")
+            .expect("unable to compile regex");
+    input
+        .lines()
+        .map(|line| {
+            let caps = re.captures(line).ok_or_else(|| {
+                ParseError::at(
+                    input,
+                    line,
+                    format!("expected an entry like 1-3 a: abcde, found '{}'", line),
                 )
+            })?;
+            let password = caps.name("password").unwrap().as_str();
+
+            Ok(Entry {
+                first: parse_number(input, caps.name("min_count").unwrap().as_str())?,
+                second: parse_number(input, caps.name("max_count").unwrap().as_str())?,
+                c: caps["char"].chars().next().unwrap(),
+                password,
             })
-            .filter(|&(min_count, max_count, c, password)| {
-                password_validator(min_count, max_count, c, password)
-            })
-            .count()
+        })
+        .collect()
+}
+
+fn solve_core(entries: &[Entry], password_validator: fn(usize, usize, char, &str) -> bool) -> usize {
+    entries
+        .iter()
+        .filter(|entry| password_validator(entry.first, entry.second, entry.c, entry.password))
+        .count()
+}
+
+pub fn solve_part_1(entries: &[Entry]) -> usize {
+    solve_core(entries, part_1_is_password_valid)
+}
+
+pub fn solve_part_2(entries: &[Entry]) -> usize {
+    solve_core(entries, part_2_is_password_valid)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Vec<Entry<'a>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Entry<'_>>, ParseError> {
+        parse(input)
     }
 
-    fn solve_part_1(input: &str) -> usize {
-        solve_core(input, part_1_is_password_valid)
+    fn part1(input: &Vec<Entry>) -> usize {
+        solve_part_1(input)
     }
 
-    fn solve_part_2(input: &str) -> usize {
-        solve_core(input, part_2_is_password_valid)
+    fn part2(input: &Vec<Entry>) -> usize {
+        solve_part_2(input)
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
     #[test]
     fn should_solve_part_1_preview() {
-        let input = include_str!("../input/day2_test.txt");
-        assert_eq!(solve_part_1(input), 2);
+        let input = include_str!("../../examples/2020/02/sample.txt");
+        assert_eq!(solve_part_1(&parse(input).unwrap()), 2);
     }
 
     #[test]
     fn should_solve_part_1() {
-        let input = include_str!("../input/day2.txt");
-        assert_eq!(solve_part_1(input), 564);
+        let input = include_str!("../../inputs/2020/02.txt");
+        assert_eq!(solve_part_1(&parse(input).unwrap()), 564);
     }
 
     #[test]
     fn should_solve_part_2_preview() {
-        let input = include_str!("../input/day2_test.txt");
-        assert_eq!(solve_part_2(input), 1);
+        let input = include_str!("../../examples/2020/02/sample.txt");
+        assert_eq!(solve_part_2(&parse(input).unwrap()), 1);
     }
 
     #[test]
     fn should_solve_part_2() {
-        let input = include_str!("../input/day2.txt");
-        assert_eq!(solve_part_2(input), 325);
+        let input = include_str!("../../inputs/2020/02.txt");
+        assert_eq!(solve_part_2(&parse(input).unwrap()), 325);
+    }
+
+    #[test]
+    fn should_report_malformed_entry() {
+        let input = "1-3 a: abcde\n1-3 b cdefg\n";
+        assert_eq!(
+            parse(input).err(),
+            Some(ParseError::new(2, 1, "expected an entry like 1-3 a: abcde, found '1-3 b cdefg'"))
+        );
     }
 }
@@ -1,63 +1,123 @@
-#[cfg(test)]
-mod tests {
-    fn solve_core(biome: &[Vec<char>], slope: (usize, usize)) -> usize {
-        let height = biome.len();
-        let width = biome[0].len();
-        let mut pos = (0, 0);
-        let mut counter = 0;
-
-        loop {
-            pos.0 += slope.0;
-            pos.1 += slope.1;
-
-            if pos.0 >= height {
-                break;
-            }
-            if biome[pos.0][pos.1 % width] == '#' {
-                counter += 1;
-            }
+use aoc_core::{ParseError, Solution};
+
+fn solve_core(biome: &[Vec<char>], slope: (usize, usize)) -> usize {
+    let height = biome.len();
+    let width = biome[0].len();
+    let mut pos = (0, 0);
+    let mut counter = 0;
+
+    loop {
+        pos.0 += slope.0;
+        pos.1 += slope.1;
+
+        if pos.0 >= height {
+            break;
+        }
+        if biome[pos.0][pos.1 % width] == '#' {
+            counter += 1;
         }
+    }
+
+    counter
+}
 
-        counter
+/// Rows of open squares and trees, all as wide as the first one.
+pub fn parse(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    let width = input.lines().next().map_or(0, str::len);
+    if width == 0 {
+        return Err(ParseError::at(input, input, "expected a row of . and #"));
     }
 
-    fn solve_part_1(input: &str) -> usize {
-        let biome: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+    input
+        .lines()
+        .map(|line| {
+            if let Some(offset) = line.find(|c| c != '.' && c != '#') {
+                return Err(ParseError::at(input, &line[offset..], "expected . or #"));
+            }
+            if line.len() != width {
+                let message = format!("expected {} squares, found {}", width, line.len());
+                return Err(ParseError::at(input, line, message));
+            }
+
+            Ok(line.chars().collect())
+        })
+        .collect()
+}
+
+pub fn solve_part_1(biome: &[Vec<char>]) -> usize {
+    solve_core(biome, (1, 3))
+}
+
+pub fn solve_part_2(biome: &[Vec<char>]) -> usize {
+    let slopes = [(1, 1), (1, 3), (1, 5), (1, 7), (2, 1)];
+
+    slopes
+        .iter()
+        .map(|&slope| solve_core(biome, slope))
+        .product()
+}
+
+pub struct Solver;
 
-        solve_core(&biome, (1, 3))
+impl Solution for Solver {
+    type Input<'a> = Vec<Vec<char>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+        parse(input)
     }
 
-    fn solve_part_2(input: &str) -> usize {
-        let biome: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
-        let slopes = vec![(1, 1), (1, 3), (1, 5), (1, 7), (2, 1)];
+    fn part1(input: &Vec<Vec<char>>) -> usize {
+        solve_part_1(input)
+    }
 
-        slopes
-            .iter()
-            .map(|&slope| solve_core(&biome, slope))
-            .product()
+    fn part2(input: &Vec<Vec<char>>) -> usize {
+        solve_part_2(input)
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
     #[test]
     fn should_solve_part_1_preview() {
-        let input = include_str!("../input/day3_test.txt");
-        assert_eq!(solve_part_1(input), 7);
+        let input = include_str!("../../examples/2020/03/sample.txt");
+        assert_eq!(solve_part_1(&parse(input).unwrap()), 7);
     }
 
     #[test]
     fn should_solve_part_1() {
-        let input = include_str!("../input/day3.txt");
-        assert_eq!(solve_part_1(input), 207);
+        let input = include_str!("../../inputs/2020/03.txt");
+        assert_eq!(solve_part_1(&parse(input).unwrap()), 207);
     }
 
     #[test]
     fn should_solve_part_2_preview() {
-        let input = include_str!("../input/day3_test.txt");
-        assert_eq!(solve_part_2(input), 336);
+        let input = include_str!("../../examples/2020/03/sample.txt");
+        assert_eq!(solve_part_2(&parse(input).unwrap()), 336);
     }
 
     #[test]
     fn should_solve_part_2() {
-        let input = include_str!("../input/day3.txt");
-        assert_eq!(solve_part_2(input), 2655892800);
+        let input = include_str!("../../inputs/2020/03.txt");
+        assert_eq!(solve_part_2(&parse(input).unwrap()), 2655892800);
+    }
+
+    #[test]
+    fn should_report_malformed_row() {
+        assert_eq!(
+            parse("..##\n#..\n").unwrap_err(),
+            ParseError::new(2, 1, "expected 4 squares, found 3")
+        );
+        assert_eq!(
+            parse("..##\n#.O.\n").unwrap_err(),
+            ParseError::new(2, 3, "expected . or #")
+        );
+        assert_eq!(
+            parse("").unwrap_err(),
+            ParseError::new(1, 1, "expected a row of . and #")
+        );
     }
 }
@@ -1,163 +1,207 @@
-#[cfg(test)]
-mod tests {
-    use lazy_static::lazy_static;
-    use std::collections::{HashMap, HashSet};
-
-    lazy_static! {
-        static ref REQUIRED_FIELDS: HashSet<&'static str> =
-            ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"]
-                .iter()
-                .cloned()
-                .collect();
-        static ref HCL_POSSIBLE_LETTERS: HashSet<char> =
-            ['a', 'b', 'c', 'd', 'e', 'f'].iter().cloned().collect();
-        static ref ECL_POSSIBLE_VALUES: HashSet<&'static str> =
-            ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"]
-                .iter()
-                .cloned()
-                .collect();
-    }
+use aoc_core::{ParseError, Solution};
+use lazy_static::lazy_static;
+use std::collections::{HashMap, HashSet};
 
-    fn passport_to_properties(passport: &str) -> HashMap<&str, &str> {
-        passport
-            .split(char::is_whitespace)
-            .filter(|&field| field != "")
-            .map(|field| field.split(':').collect::<Vec<&str>>())
-            .map(|field_pair| (field_pair[0], field_pair[1]))
-            .collect::<HashMap<&str, &str>>()
-    }
+lazy_static! {
+    static ref REQUIRED_FIELDS: HashSet<&'static str> =
+        ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"]
+            .iter()
+            .cloned()
+            .collect();
+    static ref HCL_POSSIBLE_LETTERS: HashSet<char> =
+        ['a', 'b', 'c', 'd', 'e', 'f'].iter().cloned().collect();
+    static ref ECL_POSSIBLE_VALUES: HashSet<&'static str> =
+        ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"]
+            .iter()
+            .cloned()
+            .collect();
+}
 
-    fn has_all_required_fields(passport_fields: &HashSet<&str>) -> bool {
-        REQUIRED_FIELDS.is_subset(&passport_fields)
-    }
+type Passport<'a> = HashMap<&'a str, &'a str>;
 
-    fn is_in_range(n: usize, min: usize, max: usize) -> bool {
-        n >= min && n <= max
-    }
+fn passport_to_properties<'a>(input: &str, passport: &'a str) -> Result<Passport<'a>, ParseError> {
+    passport
+        .split(char::is_whitespace)
+        .filter(|&field| !field.is_empty())
+        .map(|field| {
+            field.split_once(':').ok_or_else(|| {
+                ParseError::at(input, field, format!("expected a field like key:value, found '{}'", field))
+            })
+        })
+        .collect()
+}
 
-    fn is_byr_valid(byr: &str) -> bool {
-        let byr_number: usize = byr.parse().unwrap();
-        is_in_range(byr_number, 1920, 2002)
-    }
+pub fn parse(input: &str) -> Result<Vec<Passport<'_>>, ParseError> {
+    input
+        .split("\n\n")
+        .map(|passport| passport_to_properties(input, passport))
+        .collect()
+}
 
-    fn is_iyr_valid(iyr: &str) -> bool {
-        let iyr_number: usize = iyr.parse().unwrap();
-        is_in_range(iyr_number, 2010, 2020)
-    }
+fn has_all_required_fields(passport_fields: &HashSet<&str>) -> bool {
+    REQUIRED_FIELDS.is_subset(passport_fields)
+}
 
-    fn is_eyr_valid(eyr: &str) -> bool {
-        let eyr_number: usize = eyr.parse().unwrap();
-        is_in_range(eyr_number, 2020, 2030)
-    }
+fn is_in_range(n: usize, min: usize, max: usize) -> bool {
+    n >= min && n <= max
+}
 
-    fn is_hgt_valid(hgt: &str) -> bool {
-        let hgt_number: usize = hgt
-            .chars()
-            .filter(|&c| char::is_numeric(c))
-            .collect::<String>()
-            .parse()
-            .unwrap();
-        let unit = hgt
-            .chars()
-            .filter(|&c| char::is_alphabetic(c))
-            .collect::<String>();
-        let min;
-        let max;
-
-        if unit == "cm" {
-            min = 150;
-            max = 193;
-        } else if unit == "in" {
-            min = 59;
-            max = 76;
-        } else {
-            return false;
-        }
-
-        is_in_range(hgt_number, min, max)
-    }
+fn is_byr_valid(byr: &str) -> bool {
+    byr.parse().is_ok_and(|byr_number| is_in_range(byr_number, 1920, 2002))
+}
 
-    fn is_hcl_valid(hcl: &str) -> bool {
-        let mut hcl_iter = hcl.chars();
+fn is_iyr_valid(iyr: &str) -> bool {
+    iyr.parse().is_ok_and(|iyr_number| is_in_range(iyr_number, 2010, 2020))
+}
 
-        if hcl_iter.next().unwrap() != '#' {
-            return false;
-        }
+fn is_eyr_valid(eyr: &str) -> bool {
+    eyr.parse().is_ok_and(|eyr_number| is_in_range(eyr_number, 2020, 2030))
+}
 
-        hcl_iter
-            .filter(|c| char::is_numeric(*c) || HCL_POSSIBLE_LETTERS.contains(c))
-            .count()
-            == 6
-    }
+fn is_hgt_valid(hgt: &str) -> bool {
+    let hgt_number: usize = match hgt
+        .chars()
+        .filter(|&c| char::is_numeric(c))
+        .collect::<String>()
+        .parse()
+    {
+        Ok(hgt_number) => hgt_number,
+        Err(_) => return false,
+    };
+    let unit = hgt
+        .chars()
+        .filter(|&c| char::is_alphabetic(c))
+        .collect::<String>();
+    let min;
+    let max;
+
+    if unit == "cm" {
+        min = 150;
+        max = 193;
+    } else if unit == "in" {
+        min = 59;
+        max = 76;
+    } else {
+        return false;
+    }
+
+    is_in_range(hgt_number, min, max)
+}
 
-    fn is_ecl_valid(ecl: &str) -> bool {
-        ECL_POSSIBLE_VALUES.contains(&ecl)
-    }
+fn is_hcl_valid(hcl: &str) -> bool {
+    let mut hcl_iter = hcl.chars();
 
-    fn is_pid_valid(pid: &str) -> bool {
-        pid.chars().filter(|&c| char::is_numeric(c)).count() == 9
+    if hcl_iter.next() != Some('#') {
+        return false;
     }
 
-    fn are_all_fields_valid(passport_properties: &HashMap<&str, &str>) -> bool {
-        let validators: Vec<(&str, &dyn Fn(&str) -> bool)> = vec![
-            ("byr", &is_byr_valid),
-            ("iyr", &is_iyr_valid),
-            ("eyr", &is_eyr_valid),
-            ("hgt", &is_hgt_valid),
-            ("hcl", &is_hcl_valid),
-            ("ecl", &is_ecl_valid),
-            ("pid", &is_pid_valid),
-        ];
-
-        validators
-            .iter()
-            .all(|&(field, fun)| fun(passport_properties[field]))
-    }
+    hcl_iter
+        .filter(|c| char::is_numeric(*c) || HCL_POSSIBLE_LETTERS.contains(c))
+        .count()
+        == 6
+}
+
+fn is_ecl_valid(ecl: &str) -> bool {
+    ECL_POSSIBLE_VALUES.contains(&ecl)
+}
+
+fn is_pid_valid(pid: &str) -> bool {
+    pid.chars().filter(|&c| char::is_numeric(c)).count() == 9
+}
+
+type Validator = fn(&str) -> bool;
+
+fn are_all_fields_valid(passport_properties: &Passport) -> bool {
+    let validators: [(&str, Validator); 7] = [
+        ("byr", is_byr_valid),
+        ("iyr", is_iyr_valid),
+        ("eyr", is_eyr_valid),
+        ("hgt", is_hgt_valid),
+        ("hcl", is_hcl_valid),
+        ("ecl", is_ecl_valid),
+        ("pid", is_pid_valid),
+    ];
+
+    validators
+        .iter()
+        .all(|&(field, fun)| fun(passport_properties[field]))
+}
+
+fn is_passport_valid(passport_properties: &Passport) -> bool {
+    has_all_required_fields(&passport_properties.keys().cloned().collect())
+        && are_all_fields_valid(passport_properties)
+}
+
+pub fn solve_part_1(passports: &[Passport]) -> usize {
+    passports
+        .iter()
+        .filter(|passport| {
+            has_all_required_fields(&passport.keys().cloned().collect::<HashSet<&str>>())
+        })
+        .count()
+}
 
-    fn is_passport_valid(passport_properties: &HashMap<&str, &str>) -> bool {
-        has_all_required_fields(&passport_properties.keys().cloned().collect())
-            && are_all_fields_valid(passport_properties)
+pub fn solve_part_2(passports: &[Passport]) -> usize {
+    passports
+        .iter()
+        .filter(|properties| is_passport_valid(properties))
+        .count()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Vec<Passport<'a>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Passport<'_>>, ParseError> {
+        parse(input)
     }
 
-    fn solve_part_1(input: &str) -> usize {
-        input
-            .split("\n\n")
-            .map(|passport| passport_to_properties(passport))
-            .filter(|passport| {
-                has_all_required_fields(&passport.keys().cloned().collect::<HashSet<&str>>())
-            })
-            .count()
+    fn part1(input: &Vec<Passport>) -> usize {
+        solve_part_1(input)
     }
 
-    fn solve_part_2(input: &str) -> usize {
-        input
-            .split("\n\n")
-            .map(|passport| passport_to_properties(passport))
-            .filter(|properties| is_passport_valid(properties))
-            .count()
+    fn part2(input: &Vec<Passport>) -> usize {
+        solve_part_2(input)
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
     #[test]
     fn should_solve_part_1_preview() {
-        let input = include_str!("../input/day4_test_part_1.txt");
-        assert_eq!(solve_part_1(input), 2);
+        let input = include_str!("../../examples/2020/04/part1.txt");
+        assert_eq!(solve_part_1(&parse(input).unwrap()), 2);
     }
 
     #[test]
     fn should_solve_part_1() {
-        let input = include_str!("../input/day4.txt");
-        assert_eq!(solve_part_1(input), 247);
+        let input = include_str!("../../inputs/2020/04.txt");
+        assert_eq!(solve_part_1(&parse(input).unwrap()), 247);
     }
 
     #[test]
     fn should_solve_part_2_preview() {
-        let input = include_str!("../input/day4_test_part_2.txt");
-        assert_eq!(solve_part_2(input), 4);
+        let input = include_str!("../../examples/2020/04/part2.txt");
+        assert_eq!(solve_part_2(&parse(input).unwrap()), 4);
     }
 
     #[test]
     fn should_solve_part_2() {
-        let input = include_str!("../input/day4.txt");
-        assert_eq!(solve_part_2(input), 145);
+        let input = include_str!("../../inputs/2020/04.txt");
+        assert_eq!(solve_part_2(&parse(input).unwrap()), 145);
+    }
+
+    #[test]
+    fn should_report_malformed_field() {
+        let input = "ecl:gry pid:860033327\nhcl:#fffffd byr\n";
+        assert_eq!(
+            parse(input).unwrap_err(),
+            ParseError::new(2, 13, "expected a field like key:value, found 'byr'")
+        );
     }
 }
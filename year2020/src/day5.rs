@@ -1,74 +1,131 @@
-#[cfg(test)]
-mod tests {
-    fn get_position(data: &[char], length: usize) -> usize {
-        let mut left = 0.0;
-        let mut right = length as f64 - 1.0;
-
-        for c in data {
-            if *c == 'F' || *c == 'L' {
-                right = ((right - left) / 2.0).floor() + left;
-            } else if *c == 'B' || *c == 'R' {
-                left = ((right - left) / 2.0).ceil() + left;
-            }
-        }
+use aoc_core::{ParseError, Solution};
+
+fn get_position(data: &[char], length: usize) -> usize {
+    let mut left = 0.0;
+    let mut right = length as f64 - 1.0;
 
-        left as usize
+    for c in data {
+        if *c == 'F' || *c == 'L' {
+            right = ((right - left) / 2.0).floor() + left;
+        } else if *c == 'B' || *c == 'R' {
+            left = ((right - left) / 2.0).ceil() + left;
+        }
     }
 
-    fn solve_part_1(input: &str) -> usize {
-        input
-            .lines()
-            .map(|boarding_pass| boarding_pass.chars().collect::<Vec<char>>())
-            .map(|chars| (get_position(&chars[..7], 128), get_position(&chars[7..], 8)))
-            .map(|(row, column)| row * 8 + column)
-            .max()
-            .unwrap()
+    left as usize
+}
+
+fn is_boarding_pass(chars: &[char]) -> bool {
+    chars.len() == 10
+        && chars[..7].iter().all(|c| *c == 'F' || *c == 'B')
+        && chars[7..].iter().all(|c| *c == 'L' || *c == 'R')
+}
+
+/// Seat ids of the boarding passes, of which there is at least one.
+pub fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    let ids: Vec<usize> = input
+        .lines()
+        .map(|boarding_pass| {
+            let chars: Vec<char> = boarding_pass.chars().collect();
+            if !is_boarding_pass(&chars) {
+                let message = format!(
+                    "expected a boarding pass like FBFBBFFRLR, found '{}'",
+                    boarding_pass
+                );
+                return Err(ParseError::at(input, boarding_pass, message));
+            }
+
+            Ok((get_position(&chars[..7], 128), get_position(&chars[7..], 8)))
+        })
+        .map(|seat| seat.map(|(row, column)| row * 8 + column))
+        .collect::<Result<_, _>>()?;
+
+    if ids.is_empty() {
+        return Err(ParseError::at(input, input, "expected a boarding pass"));
     }
 
-    fn solve_part_2(input: &str) -> usize {
-        let mut ids_vec: Vec<usize> = input
-            .lines()
-            .map(|boarding_pass| boarding_pass.chars().collect::<Vec<char>>())
-            .map(|chars| (get_position(&chars[..7], 128), get_position(&chars[7..], 8)))
-            .map(|(row, column)| row * 8 + column)
-            .collect();
+    Ok(ids)
+}
+
+pub fn solve_part_1(ids: &[usize]) -> usize {
+    *ids.iter().max().unwrap()
+}
+
+pub fn solve_part_2(ids: &[usize]) -> usize {
+    let mut ids_vec = ids.to_vec();
 
-        ids_vec.sort_unstable();
-        let mut ids = ids_vec.iter();
+    ids_vec.sort_unstable();
+    let mut ids = ids_vec.iter();
 
-        let mut prev_id = ids.next().unwrap();
-        let mut my_id = 0;
+    let mut prev_id = ids.next().unwrap();
+    let mut my_id = 0;
 
-        for curr_id in ids {
-            if curr_id - prev_id == 2 {
-                let id = prev_id + 1;
-                let row = id / 8;
+    for curr_id in ids {
+        if curr_id - prev_id == 2 {
+            let id = prev_id + 1;
+            let row = id / 8;
 
-                if row != 0 && row != 127 {
-                    my_id = id;
-                }
+            if row != 0 && row != 127 {
+                my_id = id;
             }
-            prev_id = curr_id;
         }
+        prev_id = curr_id;
+    }
+
+    my_id
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<usize>) -> usize {
+        solve_part_1(input)
+    }
 
-        my_id
+    fn part2(input: &Vec<usize>) -> usize {
+        solve_part_2(input)
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
     #[test]
     fn test_should_solve_part_1_preview() {
-        let input = include_str!("../input/day5_test.txt");
-        assert_eq!(solve_part_1(input), 820);
+        let input = include_str!("../../examples/2020/05/sample.txt");
+        assert_eq!(solve_part_1(&parse(input).unwrap()), 820);
     }
 
     #[test]
     fn test_should_solve_part_1() {
-        let input = include_str!("../input/day5.txt");
-        assert_eq!(solve_part_1(input), 883);
+        let input = include_str!("../../inputs/2020/05.txt");
+        assert_eq!(solve_part_1(&parse(input).unwrap()), 883);
     }
 
     #[test]
     fn test_should_solve_part_2() {
-        let input = include_str!("../input/day5.txt");
-        assert_eq!(solve_part_2(input), 532);
+        let input = include_str!("../../inputs/2020/05.txt");
+        assert_eq!(solve_part_2(&parse(input).unwrap()), 532);
+    }
+
+    #[test]
+    fn test_should_report_malformed_boarding_pass() {
+        assert_eq!(
+            parse("BFFFBBFRRR\nFFFBBBFRRL\nBBFFBBFRL\n").unwrap_err(),
+            ParseError::new(3, 1, "expected a boarding pass like FBFBBFFRLR, found 'BBFFBBFRL'")
+        );
+        assert_eq!(
+            parse("").unwrap_err(),
+            ParseError::new(1, 1, "expected a boarding pass")
+        );
     }
 }
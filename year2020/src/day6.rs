@@ -1,64 +1,114 @@
-#[cfg(test)]
-mod tests {
-    use std::collections::{HashMap, HashSet};
-
-    fn solve_part_1(input: &str) -> usize {
-        input
-            .split("\n\n")
-            .map(|group| {
-                group
-                    .replace("\n", "")
-                    .chars()
-                    .collect::<HashSet<char>>()
-                    .len()
-            })
-            .sum()
-    }
+use aoc_core::{ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+/// Answers of each person, grouped as in the input.
+pub fn parse(input: &str) -> Result<Vec<Vec<&str>>, ParseError> {
+    input
+        .split("\n\n")
+        .map(|group| {
+            group
+                .lines()
+                .map(|person| match person.find(|c: char| !c.is_ascii_lowercase()) {
+                    Some(offset) => Err(ParseError::at(
+                        input,
+                        &person[offset..],
+                        "expected a question from a to z",
+                    )),
+                    None => Ok(person),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+pub fn solve_part_1(groups: &[Vec<&str>]) -> usize {
+    groups
+        .iter()
+        .map(|group| {
+            group
+                .concat()
+                .chars()
+                .collect::<HashSet<char>>()
+                .len()
+        })
+        .sum()
+}
+
+fn get_yes_count(people_answers: &[&str]) -> usize {
+    let people_count = people_answers.len();
+    let mut yes_count = HashMap::new();
+
+    people_answers
+        .join("")
+        .replace("\n", "")
+        .chars()
+        .for_each(|c| {
+            yes_count.entry(c).and_modify(|e| *e += 1).or_insert(1);
+        });
+
+    yes_count.values().filter(|v| **v == people_count).count()
+}
 
-    fn get_yes_count(people_answers: &[&str]) -> usize {
-        let people_count = people_answers.len();
-        let mut yes_count = HashMap::new();
+pub fn solve_part_2(groups: &[Vec<&str>]) -> usize {
+    groups
+        .iter()
+        .map(|people_answers| get_yes_count(people_answers))
+        .sum()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Vec<Vec<&'a str>>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-        people_answers
-            .join("")
-            .replace("\n", "")
-            .chars()
-            .for_each(|c| {
-                yes_count.entry(c).and_modify(|e| *e += 1).or_insert(1);
-            });
+    fn parse(input: &str) -> Result<Vec<Vec<&str>>, ParseError> {
+        parse(input)
+    }
 
-        yes_count.values().filter(|v| **v == people_count).count()
+    fn part1(input: &Vec<Vec<&str>>) -> usize {
+        solve_part_1(input)
     }
 
-    fn solve_part_2(input: &str) -> usize {
-        input
-            .split("\n\n")
-            .map(|group_answers| group_answers.lines().collect::<Vec<&str>>())
-            .map(|people_answers| get_yes_count(&people_answers))
-            .sum()
+    fn part2(input: &Vec<Vec<&str>>) -> usize {
+        solve_part_2(input)
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
     #[test]
     fn test_should_solve_part_1_preview() {
-        let input = include_str!("../input/day6_test.txt");
-        assert_eq!(solve_part_1(input), 11);
+        let input = include_str!("../../examples/2020/06/sample.txt");
+        assert_eq!(solve_part_1(&parse(input).unwrap()), 11);
     }
 
     #[test]
     fn test_should_solve_part_1() {
-        let input = include_str!("../input/day6.txt");
-        assert_eq!(solve_part_1(input), 6443);
+        let input = include_str!("../../inputs/2020/06.txt");
+        assert_eq!(solve_part_1(&parse(input).unwrap()), 6443);
     }
 
     #[test]
     fn test_should_solve_part_2_preview() {
-        let input = include_str!("../input/day6_test.txt");
-        assert_eq!(solve_part_2(input), 6);
+        let input = include_str!("../../examples/2020/06/sample.txt");
+        assert_eq!(solve_part_2(&parse(input).unwrap()), 6);
     }
 
     #[test]
     fn test_should_solve_part_2() {
-        let input = include_str!("../input/day6.txt");
-        assert_eq!(solve_part_2(input), 3232);
+        let input = include_str!("../../inputs/2020/06.txt");
+        assert_eq!(solve_part_2(&parse(input).unwrap()), 3232);
+    }
+
+    #[test]
+    fn test_should_report_malformed_answers() {
+        assert_eq!(
+            parse("abc\n\na\nB\n").unwrap_err(),
+            ParseError::new(4, 1, "expected a question from a to z")
+        );
     }
 }